@@ -0,0 +1,312 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Read-only HTTP API
+//!
+//! Serves the volume tables to the frontend so it does not need database access.
+//! Started next to the indexer when `api_bind_address` is set in the config.
+//!
+//! ## Routes
+//! - `GET /api/v1/protocols` - all `apt_data` rows
+//! - `GET /api/v1/protocols/:name` - one protocol's `apt_data` row
+//! - `GET /api/v1/coins/:coin/volume` - the coin's `coin_volume_24h` row
+//! - `GET /api/v1/coins/:coin/buckets?hours=24&protocol=all` - the coin's `coin_volume_buckets` from the last
+//!   `hours`, for one protocol or summed across all of them
+//! - `GET /api/v1/health` - the last processor checkpoint, how many blocks it is behind the chain tip and
+//!   the change in APT volume between the last two complete hours
+
+use crate::{
+    common::{get_last_processed_batch_info, BatchInfo},
+    config::indexer_processor_config::IndexerProcessorConfig,
+    db::{
+        common::models::{
+            apt_models::AptData,
+            coin_volume_models::{CoinVolume24h, CoinVolumeBucket, ALL_PROTOCOLS},
+        },
+        postgres::schema::{apt_data, coin_volume_24h, coin_volume_buckets},
+    },
+    processors::tasmil_processor::load_volume_change_rate,
+    utils::{
+        database::{new_db_pool, ArcDbPool},
+        errors::ProcessorResultExt,
+    },
+};
+use anyhow::{Context, Result};
+use aptos_indexer_processor_sdk::utils::errors::ProcessorError;
+use axum::{
+    extract::{FromRef, Path, Query, State},
+    http::{header::HeaderValue, Method, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{error, info, warn};
+use url::Url;
+
+/// Connections kept for API queries, separate from the indexer's pools
+pub const API_MAX_POOL_SIZE: u32 = 10;
+/// Largest `hours` accepted by the buckets route
+pub const MAX_BUCKET_HOURS: u32 = 24 * 7;
+/// Window the health route's `volume_change_rate_pct` compares
+pub const HEALTH_VOLUME_CHANGE_WINDOW_MINUTES: u32 = 60;
+
+/// Start the API server in the background if `api_bind_address` is configured.
+/// Queries go to the read replica when one is configured.
+pub async fn spawn(config: &IndexerProcessorConfig) -> Result<()> {
+    let Some(bind_address) = config.api_bind_address else {
+        return Ok(());
+    };
+
+    let database_url = config
+        .database_read_url
+        .as_deref()
+        .unwrap_or(&config.db_config.postgres_connection_string);
+    let pool = new_db_pool(database_url, Some(API_MAX_POOL_SIZE))
+        .await
+        .context("Failed to create API connection pool")?;
+    let app = router(pool, &config.api_cors_origins, config.api_fullnode_url.clone())?;
+
+    tokio::spawn(async move {
+        if let Err(e) = serve(bind_address, app).await {
+            error!("❌ API server stopped: {:#}", e);
+        }
+    });
+    Ok(())
+}
+
+async fn serve(bind_address: SocketAddr, app: Router) -> Result<()> {
+    let listener = tokio::net::TcpListener::bind(bind_address)
+        .await
+        .with_context(|| format!("Failed to bind API server to {}", bind_address))?;
+    info!("🌐 API listening on http://{}", bind_address);
+    axum::serve(listener, app).await.context("API server error")
+}
+
+/// Shared by all routes; handlers that only query the database extract the pool alone
+#[derive(Clone)]
+pub struct ApiState {
+    pool: ArcDbPool,
+    fullnode_url: Option<Url>,
+    http_client: reqwest::Client,
+}
+
+impl FromRef<ApiState> for ArcDbPool {
+    fn from_ref(state: &ApiState) -> Self {
+        state.pool.clone()
+    }
+}
+
+/// Routes under `/api/v1`, with CORS allowed for `cors_origins` (none if empty).
+/// `fullnode_url` is the REST API used to find the chain tip for the health route.
+pub fn router(pool: ArcDbPool, cors_origins: &[String], fullnode_url: Option<Url>) -> Result<Router> {
+    let state = ApiState {
+        pool,
+        fullnode_url,
+        http_client: reqwest::Client::new(),
+    };
+    let router = Router::new()
+        .route("/api/v1/protocols", get(list_protocols))
+        .route("/api/v1/protocols/:name", get(get_protocol))
+        .route("/api/v1/coins/:coin/volume", get(get_coin_volume))
+        .route("/api/v1/coins/:coin/buckets", get(get_coin_buckets))
+        .route("/api/v1/health", get(get_health))
+        .with_state(state);
+
+    if cors_origins.is_empty() {
+        return Ok(router);
+    }
+
+    let origins = cors_origins
+        .iter()
+        .map(|origin| HeaderValue::from_str(origin).with_context(|| format!("Invalid CORS origin {:?}", origin)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(router.layer(
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(origins))
+            .allow_methods([Method::GET]),
+    ))
+}
+
+/// Error returned by a route, rendered as `{"error": "..."}`
+#[derive(Debug)]
+pub enum ApiError {
+    NotFound(String),
+    Database(ProcessorError),
+}
+
+impl From<ProcessorError> for ApiError {
+    fn from(e: ProcessorError) -> Self {
+        ApiError::Database(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+            ApiError::Database(e) => {
+                // Database errors may include connection details, so only log them
+                error!("❌ API query failed: {:?}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+            },
+        };
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+async fn list_protocols(State(pool): State<ArcDbPool>) -> Result<Json<Vec<AptData>>, ApiError> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for API")?;
+
+    let rows = apt_data::table
+        .order(apt_data::protocol_name.asc())
+        .load::<AptData>(&mut conn)
+        .await
+        .processor_err("Failed to load apt_data")?;
+    Ok(Json(rows))
+}
+
+async fn get_protocol(State(pool): State<ArcDbPool>, Path(name): Path<String>) -> Result<Json<AptData>, ApiError> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for API")?;
+
+    apt_data::table
+        .filter(apt_data::protocol_name.eq(&name))
+        .first::<AptData>(&mut conn)
+        .await
+        .optional()
+        .processor_err("Failed to load apt_data")?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("Unknown protocol {}", name)))
+}
+
+async fn get_coin_volume(State(pool): State<ArcDbPool>, Path(coin): Path<String>) -> Result<Json<CoinVolume24h>, ApiError> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for API")?;
+
+    coin_volume_24h::table
+        .filter(coin_volume_24h::coin.eq(&coin))
+        .first::<CoinVolume24h>(&mut conn)
+        .await
+        .optional()
+        .processor_err("Failed to load coin_volume_24h")?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("No volume for coin {}", coin)))
+}
+
+#[derive(Debug, Deserialize)]
+struct BucketsQuery {
+    #[serde(default = "BucketsQuery::default_hours")]
+    hours: u32,
+    #[serde(default = "BucketsQuery::default_protocol")]
+    protocol: String,
+}
+
+impl BucketsQuery {
+    const fn default_hours() -> u32 {
+        24
+    }
+
+    fn default_protocol() -> String {
+        ALL_PROTOCOLS.to_string()
+    }
+}
+
+async fn get_coin_buckets(
+    State(pool): State<ArcDbPool>,
+    Path(coin): Path<String>,
+    Query(query): Query<BucketsQuery>,
+) -> Result<Json<Vec<CoinVolumeBucket>>, ApiError> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for API")?;
+
+    let hours = query.hours.min(MAX_BUCKET_HOURS);
+    let since = (Utc::now() - Duration::hours(hours as i64)).naive_utc();
+    let rows = coin_volume_buckets::table
+        .filter(coin_volume_buckets::coin.eq(&coin))
+        .filter(coin_volume_buckets::protocol.eq(&query.protocol))
+        .filter(coin_volume_buckets::bucket_start.ge(since))
+        .order(coin_volume_buckets::bucket_start.asc())
+        .load::<CoinVolumeBucket>(&mut conn)
+        .await
+        .processor_err("Failed to load coin_volume_buckets")?;
+    Ok(Json(rows))
+}
+
+#[derive(Debug, Serialize)]
+struct HealthResponse {
+    last_batch: Option<BatchInfo>,
+    /// Blocks between the last checkpoint and the chain tip; None if the tip is unknown
+    lag_blocks: Option<u64>,
+    /// APT volume change between the last two complete windows; None if the earlier one had no volume
+    volume_change_rate_pct: Option<BigDecimal>,
+}
+
+async fn get_health(State(state): State<ApiState>) -> Result<Json<HealthResponse>, ApiError> {
+    let last_batch = get_last_processed_batch_info(&state.pool).await.map_err(|e| {
+        ApiError::Database(ProcessorError::ProcessError {
+            message: format!("{:#}", e),
+        })
+    })?;
+
+    let lag_blocks = match (&state.fullnode_url, &last_batch) {
+        (Some(url), Some(batch)) => match fetch_lag_blocks(&state.http_client, url, batch.end_version).await {
+            Ok(lag) => Some(lag),
+            Err(e) => {
+                warn!("⚠️ Failed to get chain tip from fullnode: {:#}", e);
+                None
+            },
+        },
+        _ => None,
+    };
+
+    let volume_change_rate_pct = load_volume_change_rate(
+        &state.pool,
+        "APT",
+        ALL_PROTOCOLS,
+        HEALTH_VOLUME_CHANGE_WINDOW_MINUTES,
+        Utc::now().naive_utc(),
+    )
+    .await?;
+
+    Ok(Json(HealthResponse {
+        last_batch,
+        lag_blocks,
+        volume_change_rate_pct,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockHeight {
+    block_height: String,
+}
+
+/// Blocks produced since the block containing `version`, using the fullnode REST API
+async fn fetch_lag_blocks(client: &reqwest::Client, fullnode_url: &Url, version: u64) -> Result<u64> {
+    let tip_height = fetch_block_height(client, fullnode_url, "v1").await?;
+    let indexed_height = fetch_block_height(client, fullnode_url, &format!("v1/blocks/by_version/{}", version)).await?;
+    Ok(tip_height.saturating_sub(indexed_height))
+}
+
+async fn fetch_block_height(client: &reqwest::Client, fullnode_url: &Url, path: &str) -> Result<u64> {
+    let url = fullnode_url.join(path).with_context(|| format!("Invalid fullnode path {}", path))?;
+    let response: BlockHeight = client.get(url).send().await?.error_for_status()?.json().await?;
+    response
+        .block_height
+        .parse()
+        .with_context(|| format!("Invalid block height {:?}", response.block_height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found_is_404() {
+        let response = ApiError::NotFound("Unknown protocol foo".to_string()).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -17,8 +17,10 @@
 //! The processor status saver maintains the current processing state
 //! in the database, allowing the indexer to resume from the last
 //! successfully processed transaction in case of restarts or failures.
+//! Each checkpoint records the version range it covers and how long that
+//! range took, so dashboards can compute versions per second.
 
 /// Processor status tracking and checkpoint management for reliable processing
 pub mod processor_status_saver;
 
-pub use processor_status_saver::get_processor_status_saver;
+pub use processor_status_saver::{get_last_processed_batch_info, get_processor_status_saver, BatchInfo};
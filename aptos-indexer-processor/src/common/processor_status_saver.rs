@@ -1,6 +1,10 @@
 use crate::{
     config::indexer_processor_config::IndexerProcessorConfig,
-    utils::database::ArcDbPool,
+    db::{
+        common::models::processor_status_models::{NewProcessorStatus, ProcessorStatus},
+        postgres::schema::processor_status,
+    },
+    utils::{database::ArcDbPool, errors::ProcessorResultExt},
 };
 use anyhow::Result;
 use aptos_indexer_processor_sdk::{
@@ -9,36 +13,152 @@ use aptos_indexer_processor_sdk::{
     utils::errors::ProcessorError,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime};
+use diesel::{upsert::excluded, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use serde::Serialize;
+use std::{sync::Mutex, time::Instant};
 use tracing::info;
 
-/// Get a simplified processor status saver that only logs versions.
+/// Version range covered by the latest checkpoint and how long it took to process
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchInfo {
+    pub start_version: u64,
+    pub end_version: u64,
+    pub batch_processing_time_ms: Option<u64>,
+    pub last_updated: NaiveDateTime,
+}
+
+impl From<ProcessorStatus> for BatchInfo {
+    fn from(status: ProcessorStatus) -> Self {
+        let end_version = status.last_success_version as u64;
+        Self {
+            start_version: status.start_version.map_or(end_version, |v| v as u64),
+            end_version,
+            batch_processing_time_ms: status.batch_processing_time_ms.map(|ms| ms as u64),
+            last_updated: status.last_updated,
+        }
+    }
+}
+
+/// Get the processor status saver that checkpoints versions to `processor_status`.
 pub fn get_processor_status_saver(
-    _db_pool: ArcDbPool,
+    db_pool: ArcDbPool,
     config: IndexerProcessorConfig,
-) -> SimpleTasmilProcessorStatusSaver {
-    SimpleTasmilProcessorStatusSaver {
+) -> TasmilProcessorStatusSaver {
+    TasmilProcessorStatusSaver {
+        db_pool,
         processor_name: config.processor_config.name().to_string(),
+        last_checkpoint: Mutex::new(None),
     }
 }
 
-pub struct SimpleTasmilProcessorStatusSaver {
+/// The most recently written checkpoint, or None before the first one
+pub async fn get_last_processed_batch_info(pool: &ArcDbPool) -> Result<Option<BatchInfo>> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for processor status")?;
+
+    let status = processor_status::table
+        .order(processor_status::last_updated.desc())
+        .first::<ProcessorStatus>(&mut conn)
+        .await
+        .optional()
+        .processor_err("Failed to load processor status")?;
+
+    Ok(status.map(BatchInfo::from))
+}
+
+pub struct TasmilProcessorStatusSaver {
+    db_pool: ArcDbPool,
     processor_name: String,
+    // End version and time of the previous checkpoint
+    last_checkpoint: Mutex<Option<(u64, Instant)>>,
+}
+
+impl TasmilProcessorStatusSaver {
+    /// Versions since the previous checkpoint and the time spent on them.
+    /// The version tracker saves periodically, so one checkpoint can span several batches.
+    fn checkpoint_range(&self, start_version: u64, end_version: u64) -> (u64, Option<u64>) {
+        let mut last_checkpoint = self.last_checkpoint.lock().unwrap();
+        let now = Instant::now();
+        let range = match *last_checkpoint {
+            Some((previous_end, at)) => (previous_end + 1, Some(now.duration_since(at).as_millis() as u64)),
+            None => (start_version, None),
+        };
+        *last_checkpoint = Some((end_version, now));
+        range
+    }
 }
 
 #[async_trait]
-impl ProcessorStatusSaver for SimpleTasmilProcessorStatusSaver {
+impl ProcessorStatusSaver for TasmilProcessorStatusSaver {
     async fn save_processor_status(
         &self,
         last_success_batch: &TransactionContext<()>,
     ) -> Result<(), ProcessorError> {
-        // Simple logging-based status tracking for Tasmil project
+        let metadata = &last_success_batch.metadata;
+        if matches!(*self.last_checkpoint.lock().unwrap(), Some((end, _)) if end == metadata.end_version) {
+            return Ok(());
+        }
+
+        let (start_version, processing_time_ms) = self.checkpoint_range(metadata.start_version, metadata.end_version);
+        let last_transaction_timestamp = metadata
+            .end_transaction_timestamp
+            .as_ref()
+            .and_then(|ts| DateTime::from_timestamp(ts.seconds, ts.nanos as u32))
+            .map(|ts| ts.naive_utc());
+
+        let mut conn = self.db_pool.get().await.processor_err("Failed to get database connection for processor status")?;
+        diesel::insert_into(processor_status::table)
+            .values(&NewProcessorStatus {
+                processor_name: self.processor_name.clone(),
+                last_success_version: metadata.end_version as i64,
+                last_transaction_timestamp,
+                start_version: Some(start_version as i64),
+                batch_processing_time_ms: processing_time_ms.map(|ms| ms as i64),
+            })
+            .on_conflict(processor_status::processor_name)
+            .do_update()
+            .set((
+                processor_status::last_success_version.eq(excluded(processor_status::last_success_version)),
+                processor_status::last_transaction_timestamp.eq(excluded(processor_status::last_transaction_timestamp)),
+                processor_status::start_version.eq(excluded(processor_status::start_version)),
+                processor_status::batch_processing_time_ms.eq(excluded(processor_status::batch_processing_time_ms)),
+                processor_status::last_updated.eq(diesel::dsl::now),
+            ))
+            .execute(&mut conn)
+            .await
+            .processor_err("Failed to save processor status")?;
+
         info!(
-            "🔄 {} processed successfully up to version: {} (batch size: {})",
+            "🔄 {} processed successfully up to version: {} (versions {}-{}, {} ms)",
             self.processor_name,
-            last_success_batch.metadata.end_version,
-            last_success_batch.metadata.end_version - last_success_batch.metadata.start_version + 1
+            metadata.end_version,
+            start_version,
+            metadata.end_version,
+            processing_time_ms.map_or("-".to_string(), |ms| ms.to_string())
         );
-        
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_info_falls_back_to_end_version() {
+        let status = ProcessorStatus {
+            processor_name: "tasmil_processor".to_string(),
+            last_success_version: 2_000_000,
+            last_updated: NaiveDateTime::default(),
+            last_transaction_timestamp: None,
+            start_version: None,
+            batch_processing_time_ms: None,
+        };
+
+        let info = BatchInfo::from(status);
+        assert_eq!(info.start_version, 2_000_000);
+        assert_eq!(info.end_version, 2_000_000);
+    }
+}
@@ -2,22 +2,260 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::processor_config::ProcessorConfig;
-use crate::processors::events::swap_processor::SwapProcessor;
+use crate::{
+    processors::{
+        events::{
+            event_cache::DEFAULT_EVENT_CACHE_CAPACITY, swap_processor::SwapProcessor,
+            volume_calculator::DEFAULT_EVENT_PROCESSING_TIMEOUT_SECS,
+        },
+        tasmil_processor::DEFAULT_MAX_BATCH_SIZE,
+    },
+    utils::database::{DbPoolOptions, DEFAULT_CONNECTION_TIMEOUT_SECS},
+};
 use anyhow::Result;
 use aptos_indexer_processor_sdk::aptos_indexer_transaction_stream::TransactionStreamConfig;
 use aptos_indexer_processor_sdk_server_framework::RunnableConfig;
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, net::SocketAddr, time::Duration};
+use url::Url;
 
 pub const QUERY_DEFAULT_RETRIES: u32 = 5;
 pub const QUERY_DEFAULT_RETRY_DELAY_MS: u64 = 500;
 
+/// Protocol names accepted as keys of the `protocols` map
+pub const SUPPORTED_PROTOCOLS: [&str; 5] = ["cellana", "thala", "sushiswap", "liquidswap", "hyperion"];
+/// Bucket sizes that divide a day evenly and keep chart data readable
+pub const SUPPORTED_BUCKET_DURATIONS_MINUTES: [u32; 5] = [30, 60, 120, 240, 360];
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "IndexerProcessorConfigRaw")]
 pub struct IndexerProcessorConfig {
     pub processor_config: ProcessorConfig,
     pub transaction_stream_config: TransactionStreamConfig,
     pub db_config: DbConfig,
+    // Chain the indexer must be connected to (1 = mainnet, 2 = testnet); checked before anything is written
+    pub expected_chain_id: u64,
+    // Optional read replica for queries; writes always go to db_config
+    #[serde(default)]
+    pub database_read_url: Option<String>,
     pub backfill_config: Option<BackfillConfig>,
+    // Per-protocol switches keyed by protocol name ("cellana", "sushiswap", ...).
+    // Protocols not listed here are enabled.
+    #[serde(default)]
+    pub protocols: HashMap<String, ProtocolConfig>,
+    // Optional YAML file to watch for `protocols` changes at runtime
+    #[serde(default)]
+    pub protocols_reload_path: Option<String>,
+    // Per-coin maximum single-swap amount (normalized), e.g. `APT: 1000000.0`.
+    // Overrides the built-in defaults; larger swaps are stored as anomalies instead of volume.
+    #[serde(default)]
+    pub anomaly_thresholds: HashMap<String, f64>,
+    // Size of the coin_volume_buckets chart buckets (GMT+7 aligned)
+    #[serde(default = "IndexerProcessorConfig::default_bucket_duration_minutes")]
+    pub bucket_duration_minutes: u32,
+    // Days of per-swap history (implied prices, anomalous swaps) to keep; None keeps everything
+    #[serde(default)]
+    pub data_retention_days: Option<u32>,
+    // File the swap event dedup filter is saved to on shutdown and loaded from on startup
+    #[serde(default)]
+    pub dedup_state_path: Option<String>,
+    // Decoded swap event payloads kept for reuse by the VolumeCalculator
+    #[serde(default = "IndexerProcessorConfig::default_event_cache_capacity")]
+    pub event_cache_capacity: usize,
+    // Seconds one swap may wait on an `.await` in its protocol processor before it is skipped
+    #[serde(default = "IndexerProcessorConfig::default_event_processing_timeout_secs")]
+    pub event_processing_timeout_secs: u64,
+    // Write each protocol's apt_data row as soon as it is computed instead of after the batch
+    #[serde(default)]
+    pub streaming: bool,
+    // Batches with more transactions than this are processed in chunks to bound memory use
+    #[serde(default = "IndexerProcessorConfig::default_max_batch_size")]
+    pub max_batch_size: usize,
+    // Compute volumes without writing them or checkpointing processor_status
+    #[serde(default)]
+    pub dry_run: bool,
+    // Address for the read-only HTTP API; the API is not started when unset
+    #[serde(default)]
+    pub api_bind_address: Option<SocketAddr>,
+    // Origins allowed to call the API from a browser
+    #[serde(default)]
+    pub api_cors_origins: Vec<String>,
+    // Fullnode REST API used by the health route to report how far behind the chain tip we are
+    #[serde(default)]
+    pub api_fullnode_url: Option<Url>,
+    // Addresses whose DEX module deployments after genesis are trusted; anyone else can publish
+    // modules with the same names, so only genesis modules are learned when this is empty
+    #[serde(default)]
+    pub trusted_deployer_addresses: Vec<String>,
+}
+
+impl IndexerProcessorConfig {
+    pub const fn default_bucket_duration_minutes() -> u32 {
+        120
+    }
+
+    pub const fn default_event_cache_capacity() -> usize {
+        DEFAULT_EVENT_CACHE_CAPACITY
+    }
+
+    pub const fn default_max_batch_size() -> usize {
+        DEFAULT_MAX_BATCH_SIZE
+    }
+
+    pub const fn default_event_processing_timeout_secs() -> u64 {
+        DEFAULT_EVENT_PROCESSING_TIMEOUT_SECS
+    }
+
+    pub fn event_processing_timeout(&self) -> Duration {
+        Duration::from_secs(self.event_processing_timeout_secs)
+    }
+}
+
+/// Unvalidated form of `IndexerProcessorConfig` as it appears in the YAML file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IndexerProcessorConfigRaw {
+    processor_config: ProcessorConfig,
+    transaction_stream_config: TransactionStreamConfig,
+    db_config: DbConfig,
+    expected_chain_id: u64,
+    #[serde(default)]
+    database_read_url: Option<String>,
+    backfill_config: Option<BackfillConfig>,
+    #[serde(default)]
+    protocols: HashMap<String, ProtocolConfig>,
+    #[serde(default)]
+    protocols_reload_path: Option<String>,
+    #[serde(default)]
+    anomaly_thresholds: HashMap<String, f64>,
+    #[serde(default = "IndexerProcessorConfig::default_bucket_duration_minutes")]
+    bucket_duration_minutes: u32,
+    #[serde(default)]
+    data_retention_days: Option<u32>,
+    #[serde(default)]
+    dedup_state_path: Option<String>,
+    #[serde(default = "IndexerProcessorConfig::default_event_cache_capacity")]
+    event_cache_capacity: usize,
+    #[serde(default = "IndexerProcessorConfig::default_event_processing_timeout_secs")]
+    event_processing_timeout_secs: u64,
+    #[serde(default)]
+    streaming: bool,
+    #[serde(default = "IndexerProcessorConfig::default_max_batch_size")]
+    max_batch_size: usize,
+    #[serde(default)]
+    dry_run: bool,
+    #[serde(default)]
+    api_bind_address: Option<SocketAddr>,
+    #[serde(default)]
+    api_cors_origins: Vec<String>,
+    #[serde(default)]
+    api_fullnode_url: Option<Url>,
+    #[serde(default)]
+    trusted_deployer_addresses: Vec<String>,
+}
+
+/// A configuration value that would make the indexer misbehave once started
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    InvalidDatabaseUrl { scheme: String },
+    InvalidBucketDuration(u32),
+    UnknownProtocol(String),
+    InvalidDataRetentionDays,
+    InvalidMaxBatchSize,
+    InvalidEventProcessingTimeout,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidDatabaseUrl { scheme } => write!(
+                f,
+                "database URLs must start with postgres:// or postgresql:// (got scheme {:?})",
+                scheme
+            ),
+            ConfigError::InvalidBucketDuration(minutes) => write!(
+                f,
+                "bucket_duration_minutes must be one of {:?} (got {})",
+                SUPPORTED_BUCKET_DURATIONS_MINUTES, minutes
+            ),
+            ConfigError::UnknownProtocol(name) => write!(
+                f,
+                "unknown protocol {:?} in protocols (expected one of {:?})",
+                name, SUPPORTED_PROTOCOLS
+            ),
+            ConfigError::InvalidDataRetentionDays => write!(
+                f,
+                "data_retention_days must be at least 1 (omit it to keep all history)"
+            ),
+            ConfigError::InvalidMaxBatchSize => write!(f, "max_batch_size must be at least 1"),
+            ConfigError::InvalidEventProcessingTimeout => write!(f, "event_processing_timeout_secs must be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl TryFrom<IndexerProcessorConfigRaw> for IndexerProcessorConfig {
+    type Error = ConfigError;
+
+    fn try_from(raw: IndexerProcessorConfigRaw) -> Result<Self, Self::Error> {
+        let urls = std::iter::once(&raw.db_config.postgres_connection_string).chain(raw.database_read_url.as_ref());
+        for url in urls {
+            if !url.starts_with("postgres://") && !url.starts_with("postgresql://") {
+                // Only report the scheme; the rest of the URL may contain credentials
+                let scheme = url.split("://").next().unwrap_or_default().to_string();
+                return Err(ConfigError::InvalidDatabaseUrl { scheme });
+            }
+        }
+
+        if !SUPPORTED_BUCKET_DURATIONS_MINUTES.contains(&raw.bucket_duration_minutes) {
+            return Err(ConfigError::InvalidBucketDuration(raw.bucket_duration_minutes));
+        }
+
+        if let Some(name) = raw
+            .protocols
+            .keys()
+            .find(|name| !SUPPORTED_PROTOCOLS.contains(&name.as_str()))
+        {
+            return Err(ConfigError::UnknownProtocol(name.clone()));
+        }
+
+        if raw.data_retention_days == Some(0) {
+            return Err(ConfigError::InvalidDataRetentionDays);
+        }
+
+        if raw.max_batch_size == 0 {
+            return Err(ConfigError::InvalidMaxBatchSize);
+        }
+
+        if raw.event_processing_timeout_secs == 0 {
+            return Err(ConfigError::InvalidEventProcessingTimeout);
+        }
+
+        Ok(Self {
+            processor_config: raw.processor_config,
+            transaction_stream_config: raw.transaction_stream_config,
+            db_config: raw.db_config,
+            expected_chain_id: raw.expected_chain_id,
+            database_read_url: raw.database_read_url,
+            backfill_config: raw.backfill_config,
+            protocols: raw.protocols,
+            protocols_reload_path: raw.protocols_reload_path,
+            anomaly_thresholds: raw.anomaly_thresholds,
+            bucket_duration_minutes: raw.bucket_duration_minutes,
+            data_retention_days: raw.data_retention_days,
+            dedup_state_path: raw.dedup_state_path,
+            event_cache_capacity: raw.event_cache_capacity,
+            event_processing_timeout_secs: raw.event_processing_timeout_secs,
+            streaming: raw.streaming,
+            max_batch_size: raw.max_batch_size,
+            dry_run: raw.dry_run,
+            api_bind_address: raw.api_bind_address,
+            api_cors_origins: raw.api_cors_origins,
+            api_fullnode_url: raw.api_fullnode_url,
+            trusted_deployer_addresses: raw.trusted_deployer_addresses,
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -50,11 +288,36 @@ pub struct DbConfig {
     // Size of the pool for writes/reads to the DB. Limits maximum number of queries in flight
     #[serde(default = "DbConfig::default_db_pool_size")]
     pub db_pool_size: u32,
+    // Minimum number of idle connections kept open (bb8 default: none)
+    #[serde(default)]
+    pub min_idle: Option<u32>,
+    // How long to wait for a connection from the pool before failing
+    #[serde(default = "DbConfig::default_connection_timeout_seconds")]
+    pub connection_timeout_seconds: u64,
+    // Close connections idle for longer than this (bb8 default: never)
+    #[serde(default)]
+    pub idle_timeout_seconds: Option<u64>,
 }
 
 impl DbConfig {
-    pub const fn default_db_pool_size() -> u32 {
-        150
+    /// Each batch needs several connections (pool volumes, coin volumes, buckets), so size
+    /// the pool to the runtime's worker threads (at least 16, see main.rs) with a floor of 32.
+    pub fn default_db_pool_size() -> u32 {
+        let worker_threads = num_cpus::get().max(16) as u32;
+        (worker_threads * 2).max(32)
+    }
+
+    pub const fn default_connection_timeout_seconds() -> u64 {
+        DEFAULT_CONNECTION_TIMEOUT_SECS
+    }
+
+    pub fn pool_options(&self) -> DbPoolOptions {
+        DbPoolOptions {
+            max_pool_size: self.db_pool_size,
+            min_idle: self.min_idle,
+            connection_timeout: Duration::from_secs(self.connection_timeout_seconds),
+            idle_timeout: self.idle_timeout_seconds.map(Duration::from_secs),
+        }
     }
 }
 
@@ -63,3 +326,16 @@ impl DbConfig {
 pub struct BackfillConfig {
     pub backfill_alias: String,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProtocolConfig {
+    #[serde(default = "ProtocolConfig::default_enabled")]
+    pub enabled: bool,
+}
+
+impl ProtocolConfig {
+    pub const fn default_enabled() -> bool {
+        true
+    }
+}
@@ -13,6 +13,7 @@
 //! - **ProcessorConfig**: Processor-specific settings and type definitions
 //! - **DatabaseConfig**: Database connection and pooling parameters
 //! - **TransactionStreamConfig**: Blockchain data stream configuration
+//! - **ProtocolConfig**: Per-protocol enable/disable switches (hot-reloadable)
 //! 
 //! ## Configuration Sources
 //! 
@@ -34,3 +35,6 @@ pub mod indexer_processor_config;
 
 /// Processor type definitions and protocol-specific configuration options
 pub mod processor_config;
+
+/// Hot-reloadable protocol enable/disable switches
+pub mod protocol_toggles;
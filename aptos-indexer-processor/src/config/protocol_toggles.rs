@@ -0,0 +1,110 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime enable/disable switches for individual DEX protocols.
+//!
+//! The initial state comes from the `protocols` section of `IndexerProcessorConfig`.
+//! When `protocols_reload_path` is set, the file is watched and the `protocols`
+//! section is re-read on every change, so a protocol can be turned off (e.g. during
+//! a contract upgrade) without restarting the indexer.
+
+use super::indexer_processor_config::ProtocolConfig;
+use anyhow::{Context, Result};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+use tracing::{error, info, warn};
+
+/// Shared, hot-reloadable view of which protocols are enabled.
+///
+/// Protocols missing from the map are treated as enabled.
+#[derive(Clone, Debug, Default)]
+pub struct ProtocolToggles {
+    protocols: Arc<RwLock<HashMap<String, ProtocolConfig>>>,
+}
+
+impl ProtocolToggles {
+    pub fn new(protocols: HashMap<String, ProtocolConfig>) -> Self {
+        Self {
+            protocols: Arc::new(RwLock::new(protocols)),
+        }
+    }
+
+    pub fn is_enabled(&self, protocol_name: &str) -> bool {
+        self.protocols
+            .read()
+            .map(|protocols| protocols.get(protocol_name).map(|c| c.enabled).unwrap_or(true))
+            .unwrap_or(true)
+    }
+
+    pub fn replace(&self, protocols: HashMap<String, ProtocolConfig>) {
+        match self.protocols.write() {
+            Ok(mut current) => {
+                for (name, config) in &protocols {
+                    let was_enabled = current.get(name).map(|c| c.enabled).unwrap_or(true);
+                    if was_enabled != config.enabled {
+                        info!("🔀 Protocol {} is now {}", name, if config.enabled { "enabled" } else { "disabled" });
+                    }
+                }
+                *current = protocols;
+            },
+            Err(e) => error!("❌ Failed to update protocol toggles: {}", e),
+        }
+    }
+
+    /// Watches `path` and reloads the `protocols` section whenever the file changes.
+    /// The returned watcher must be kept alive for reloads to keep happening.
+    pub fn watch(&self, path: impl AsRef<Path>) -> Result<RecommendedWatcher> {
+        let path: PathBuf = path.as_ref().to_path_buf();
+        let toggles = self.clone();
+        let reload_path = path.clone();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            match res {
+                Ok(event) if event.kind.is_modify() || event.kind.is_create() => {
+                    match load_protocols_from_file(&reload_path) {
+                        Ok(protocols) => {
+                            info!("🔄 Reloaded protocol config from {}", reload_path.display());
+                            toggles.replace(protocols);
+                        },
+                        Err(e) => warn!("⚠️ Ignoring invalid protocol config reload: {:#}", e),
+                    }
+                },
+                Ok(_) => {},
+                Err(e) => error!("❌ Protocol config watcher error: {}", e),
+            }
+        })
+        .context("Failed to create protocol config watcher")?;
+
+        watcher
+            .watch(&path, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Failed to watch {}", path.display()))?;
+        info!("👀 Watching {} for protocol config changes", path.display());
+
+        Ok(watcher)
+    }
+}
+
+/// Reads the `protocols` section from a YAML config file, either nested under
+/// `server_config` (the main indexer config) or at the top level. A file without
+/// the section yields an empty map, i.e. every protocol enabled.
+fn load_protocols_from_file(path: &Path) -> Result<HashMap<String, ProtocolConfig>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let value: serde_yaml::Value = serde_yaml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    let protocols = value
+        .get("server_config")
+        .and_then(|server_config| server_config.get("protocols"))
+        .or_else(|| value.get("protocols"));
+
+    match protocols {
+        Some(protocols) => serde_yaml::from_value(protocols.clone())
+            .context("Invalid `protocols` section"),
+        None => Ok(HashMap::new()),
+    }
+}
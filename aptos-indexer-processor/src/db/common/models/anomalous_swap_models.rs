@@ -0,0 +1,38 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::anomalous_swaps;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = anomalous_swaps)]
+pub struct AnomalousSwap {
+    pub id: i64,
+    pub protocol_name: String,
+    pub transaction_version: i64,
+    pub event_type: String,
+    pub coin: String,
+    pub amount: BigDecimal,
+    pub event_data: serde_json::Value,
+    pub transaction_timestamp: NaiveDateTime,
+    pub inserted_at: NaiveDateTime,
+    pub user_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = anomalous_swaps)]
+pub struct NewAnomalousSwap {
+    pub protocol_name: String,
+    pub transaction_version: i64,
+    pub event_type: String,
+    pub coin: String,
+    pub amount: BigDecimal,
+    pub event_data: serde_json::Value,
+    pub transaction_timestamp: NaiveDateTime,
+    pub user_address: Option<String>,
+}
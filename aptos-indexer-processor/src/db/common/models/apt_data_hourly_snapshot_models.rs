@@ -0,0 +1,35 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::apt_data_hourly_snapshots;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A protocol's `apt_data` row as it was at `snapshot_hour`. Volumes and fees are rolling 24h values.
+/// Rows are inserted by SQL copying `apt_data`, so there is no insertable counterpart.
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = apt_data_hourly_snapshots)]
+pub struct HourlySnapshot {
+    pub id: i64,
+    pub protocol: String,
+    pub snapshot_hour: DateTime<Utc>,
+    pub apt_volume: Option<BigDecimal>,
+    pub usdc_volume: Option<BigDecimal>,
+    pub usdt_volume: Option<BigDecimal>,
+    pub weth_volume: Option<BigDecimal>,
+    pub btc_volume: Option<BigDecimal>,
+    pub bnb_volume: Option<BigDecimal>,
+    pub move_volume: Option<BigDecimal>,
+    pub apt_fee: Option<BigDecimal>,
+    pub usdc_fee: Option<BigDecimal>,
+    pub usdt_fee: Option<BigDecimal>,
+    pub weth_fee: Option<BigDecimal>,
+    pub lp_fee: Option<BigDecimal>,
+    pub protocol_fee: Option<BigDecimal>,
+    pub trade_count: i64,
+    pub inserted_at: DateTime<Utc>,
+}
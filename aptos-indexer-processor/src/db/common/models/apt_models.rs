@@ -3,7 +3,7 @@
 
 #![allow(clippy::extra_unused_lifetimes)]
 
-use crate::db::postgres::schema::apt_data;
+use crate::db::postgres::schema::{apt_data, apt_data_usd};
 use bigdecimal::BigDecimal;
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
@@ -22,6 +22,13 @@ pub struct AptData {
     pub usdt_fee_24h: Option<BigDecimal>,
     pub weth_volume_24h: Option<BigDecimal>,
     pub weth_fee_24h: Option<BigDecimal>,
+    pub btc_volume_24h: Option<BigDecimal>,
+    pub trade_count: i64,
+    pub bnb_volume_24h: Option<BigDecimal>,
+    // USD LP and protocol fees, only split out for Cellana and Thala
+    pub lp_fee_24h: Option<BigDecimal>,
+    pub protocol_fee_24h: Option<BigDecimal>,
+    pub move_volume_24h: Option<BigDecimal>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
@@ -36,6 +43,168 @@ pub struct NewAptData {
     pub usdt_fee_24h: Option<BigDecimal>,
     pub weth_volume_24h: Option<BigDecimal>,
     pub weth_fee_24h: Option<BigDecimal>,
+    pub btc_volume_24h: Option<BigDecimal>,
+    pub trade_count: i64,
+    pub bnb_volume_24h: Option<BigDecimal>,
+    // USD LP and protocol fees, only split out for Cellana and Thala
+    pub lp_fee_24h: Option<BigDecimal>,
+    pub protocol_fee_24h: Option<BigDecimal>,
+    pub move_volume_24h: Option<BigDecimal>,
+}
+
+impl From<AptData> for NewAptData {
+    fn from(data: AptData) -> Self {
+        Self {
+            protocol_name: data.protocol_name,
+            apt_volume_24h: data.apt_volume_24h,
+            usdc_volume_24h: data.usdc_volume_24h,
+            apt_fee_24h: data.apt_fee_24h,
+            usdc_fee_24h: data.usdc_fee_24h,
+            usdt_volume_24h: data.usdt_volume_24h,
+            usdt_fee_24h: data.usdt_fee_24h,
+            weth_volume_24h: data.weth_volume_24h,
+            weth_fee_24h: data.weth_fee_24h,
+            btc_volume_24h: data.btc_volume_24h,
+            trade_count: data.trade_count,
+            bnb_volume_24h: data.bnb_volume_24h,
+            lp_fee_24h: data.lp_fee_24h,
+            protocol_fee_24h: data.protocol_fee_24h,
+            move_volume_24h: data.move_volume_24h,
+        }
+    }
+}
+
+/// Builds a `NewAptData`; volumes and fees that are not set stay None
+#[derive(Debug, Clone)]
+pub struct NewAptDataBuilder {
+    data: NewAptData,
+}
+
+impl Default for NewAptDataBuilder {
+    fn default() -> Self {
+        Self {
+            data: NewAptData {
+                protocol_name: String::new(),
+                apt_volume_24h: None,
+                usdc_volume_24h: None,
+                apt_fee_24h: None,
+                usdc_fee_24h: None,
+                usdt_volume_24h: None,
+                usdt_fee_24h: None,
+                weth_volume_24h: None,
+                weth_fee_24h: None,
+                btc_volume_24h: None,
+                trade_count: 0,
+                bnb_volume_24h: None,
+                lp_fee_24h: None,
+                protocol_fee_24h: None,
+                move_volume_24h: None,
+            },
+        }
+    }
+}
+
+impl NewAptDataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn protocol(mut self, name: &str) -> Self {
+        self.data.protocol_name = name.to_string();
+        self
+    }
+
+    pub fn apt_volume(mut self, v: BigDecimal) -> Self {
+        self.data.apt_volume_24h = Some(v);
+        self
+    }
+
+    pub fn usdc_volume(mut self, v: BigDecimal) -> Self {
+        self.data.usdc_volume_24h = Some(v);
+        self
+    }
+
+    pub fn usdt_volume(mut self, v: BigDecimal) -> Self {
+        self.data.usdt_volume_24h = Some(v);
+        self
+    }
+
+    pub fn weth_volume(mut self, v: BigDecimal) -> Self {
+        self.data.weth_volume_24h = Some(v);
+        self
+    }
+
+    pub fn btc_volume(mut self, v: BigDecimal) -> Self {
+        self.data.btc_volume_24h = Some(v);
+        self
+    }
+
+    pub fn bnb_volume(mut self, v: BigDecimal) -> Self {
+        self.data.bnb_volume_24h = Some(v);
+        self
+    }
+
+    pub fn move_volume(mut self, v: BigDecimal) -> Self {
+        self.data.move_volume_24h = Some(v);
+        self
+    }
+
+    pub fn apt_fee(mut self, v: BigDecimal) -> Self {
+        self.data.apt_fee_24h = Some(v);
+        self
+    }
+
+    pub fn usdc_fee(mut self, v: BigDecimal) -> Self {
+        self.data.usdc_fee_24h = Some(v);
+        self
+    }
+
+    pub fn usdt_fee(mut self, v: BigDecimal) -> Self {
+        self.data.usdt_fee_24h = Some(v);
+        self
+    }
+
+    pub fn weth_fee(mut self, v: BigDecimal) -> Self {
+        self.data.weth_fee_24h = Some(v);
+        self
+    }
+
+    pub fn lp_fee(mut self, v: BigDecimal) -> Self {
+        self.data.lp_fee_24h = Some(v);
+        self
+    }
+
+    pub fn protocol_fee(mut self, v: BigDecimal) -> Self {
+        self.data.protocol_fee_24h = Some(v);
+        self
+    }
+
+    pub fn trade_count(mut self, count: i64) -> Self {
+        self.data.trade_count = count;
+        self
+    }
+
+    pub fn build(self) -> NewAptData {
+        self.data
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = apt_data_usd)]
+pub struct AptDataUsd {
+    pub protocol_name: String,
+    pub total_usd_volume: BigDecimal,
+    pub total_usd_fee: BigDecimal,
+    pub inserted_at: NaiveDateTime,
+}
+
+/// A protocol's 24h volume and fees summed across coins in USD
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = apt_data_usd)]
+pub struct NewAptDataUsd {
+    pub protocol_name: String,
+    pub total_usd_volume: BigDecimal,
+    pub total_usd_fee: BigDecimal,
 }
 
 // Prevent conflicts with other things named `AptData` 
\ No newline at end of file
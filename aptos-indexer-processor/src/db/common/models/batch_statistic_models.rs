@@ -0,0 +1,34 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::batch_statistics;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = batch_statistics)]
+pub struct BatchStatistic {
+    pub id: i64,
+    pub start_version: i64,
+    pub end_version: i64,
+    pub transaction_count: i32,
+    pub swap_event_count: i32,
+    pub processing_time_ms: i32,
+    pub db_write_time_ms: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Size and timings of one processed batch
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = batch_statistics)]
+pub struct NewBatchStatistic {
+    pub start_version: i64,
+    pub end_version: i64,
+    pub transaction_count: i32,
+    pub swap_event_count: i32,
+    pub processing_time_ms: i32,
+    pub db_write_time_ms: i32,
+}
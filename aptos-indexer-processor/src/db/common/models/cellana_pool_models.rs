@@ -0,0 +1,29 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::cellana_pools;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = cellana_pools)]
+pub struct CellanaPool {
+    pub pool_address: String,
+    pub token_x: String,
+    pub token_y: String,
+    pub fee_bps: i32,
+    pub created_at_version: i64,
+}
+
+/// A Cellana V2 pool from its `PoolCreatedEvent`
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = cellana_pools)]
+pub struct NewCellanaPool {
+    pub pool_address: String,
+    pub token_x: String,
+    pub token_y: String,
+    pub fee_bps: i32,
+    pub created_at_version: i64,
+}
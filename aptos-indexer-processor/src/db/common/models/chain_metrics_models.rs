@@ -0,0 +1,19 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::chain_metrics;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Clone, PartialEq)]
+#[diesel(table_name = chain_metrics)]
+pub struct ChainMetric {
+    pub txn_version: i64,
+    pub epoch: i64,
+    pub round: i64,
+    pub timestamp: DateTime<Utc>,
+    pub proposer: String,
+}
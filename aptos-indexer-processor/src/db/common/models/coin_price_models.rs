@@ -0,0 +1,34 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::coin_price_usd;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = coin_price_usd)]
+pub struct CoinPriceUsd {
+    pub id: i64,
+    pub coin: String,
+    pub quote_coin: String,
+    pub price_usd: BigDecimal,
+    pub source_protocol: String,
+    pub transaction_version: i64,
+    pub transaction_timestamp: NaiveDateTime,
+    pub inserted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = coin_price_usd)]
+pub struct NewCoinPriceUsd {
+    pub coin: String,
+    pub quote_coin: String,
+    pub price_usd: BigDecimal,
+    pub source_protocol: String,
+    pub transaction_version: i64,
+    pub transaction_timestamp: NaiveDateTime,
+}
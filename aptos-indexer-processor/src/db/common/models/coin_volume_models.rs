@@ -1,9 +1,12 @@
 use bigdecimal::BigDecimal;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::db::postgres::schema::{coin_volume_24h, coin_volume_buckets};
+use crate::db::postgres::schema::{coin_volume_24h, coin_volume_buckets, coin_volume_daily_buckets};
+
+/// `coin_volume_buckets.protocol` value for buckets summed across every protocol
+pub const ALL_PROTOCOLS: &str = "all";
 
 #[derive(Debug, Deserialize, Serialize, Clone, Queryable, Insertable)]
 #[diesel(table_name = coin_volume_24h)]
@@ -30,6 +33,7 @@ pub struct CoinVolumeBucket {
     pub bucket_end: NaiveDateTime,
     pub volume: Option<BigDecimal>,
     pub inserted_at: NaiveDateTime,
+    pub protocol: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Insertable)]
@@ -39,4 +43,23 @@ pub struct NewCoinVolumeBucket {
     pub bucket_start: NaiveDateTime,
     pub bucket_end: NaiveDateTime,
     pub volume: Option<BigDecimal>,
+    /// Protocol name, or `ALL_PROTOCOLS` for the cross-protocol total
+    pub protocol: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Queryable)]
+#[diesel(table_name = coin_volume_daily_buckets)]
+pub struct CoinVolumeDailyBucket {
+    pub coin: String,
+    pub bucket_date: NaiveDate,
+    pub volume: BigDecimal,
+    pub inserted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Insertable)]
+#[diesel(table_name = coin_volume_daily_buckets)]
+pub struct NewCoinVolumeDailyBucket {
+    pub coin: String,
+    pub bucket_date: NaiveDate,
+    pub volume: BigDecimal,
 } 
\ No newline at end of file
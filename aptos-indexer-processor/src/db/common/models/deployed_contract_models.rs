@@ -0,0 +1,27 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::deployed_contracts;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = deployed_contracts)]
+pub struct DeployedContract {
+    pub address: String,
+    pub module_name: String,
+    pub protocol: String,
+    pub inserted_at: NaiveDateTime,
+}
+
+/// A tracked DEX module published at `address`
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = deployed_contracts)]
+pub struct NewDeployedContract {
+    pub address: String,
+    pub module_name: String,
+    pub protocol: String,
+}
@@ -0,0 +1,30 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::hyperion_pool_fee_growth;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = hyperion_pool_fee_growth)]
+pub struct HyperionPoolFeeGrowth {
+    pub id: i64,
+    pub pool_address: String,
+    pub fee_growth_x: BigDecimal,
+    pub fee_growth_y: BigDecimal,
+    pub txn_version: i64,
+    pub inserted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = hyperion_pool_fee_growth)]
+pub struct NewHyperionPoolFeeGrowth {
+    pub pool_address: String,
+    pub fee_growth_x: BigDecimal,
+    pub fee_growth_y: BigDecimal,
+    pub txn_version: i64,
+}
@@ -0,0 +1,29 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::hyperion_pools;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = hyperion_pools)]
+pub struct HyperionPool {
+    pub pool_address: String,
+    pub token0: String,
+    pub token1: String,
+    pub fee_tier: i32,
+    pub created_at_version: i64,
+}
+
+/// A Hyperion V3 pool from its `InitializePoolEvent`
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = hyperion_pools)]
+pub struct NewHyperionPool {
+    pub pool_address: String,
+    pub token0: String,
+    pub token1: String,
+    pub fee_tier: i32,
+    pub created_at_version: i64,
+}
@@ -1,2 +1,18 @@
+pub mod anomalous_swap_models;
+pub mod apt_data_hourly_snapshot_models;
 pub mod apt_models;
+pub mod batch_statistic_models;
+pub mod cellana_pool_models;
+pub mod chain_metrics_models;
+pub mod coin_price_models;
 pub mod coin_volume_models;
+pub mod deployed_contract_models;
+pub mod hyperion_fee_growth_models;
+pub mod hyperion_pool_models;
+pub mod pair_volume_models;
+pub mod pool_reserve_models;
+pub mod processor_status_models;
+pub mod protocol_config_models;
+pub mod protocol_fee_models;
+pub mod undiscovered_pool_models;
+pub mod vwap_bucket_models;
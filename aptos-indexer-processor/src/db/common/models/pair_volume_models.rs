@@ -0,0 +1,34 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::pair_volume_by_protocol;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = pair_volume_by_protocol)]
+pub struct PairProtocolVolume {
+    pub protocol: String,
+    pub pair: String,
+    pub apt_volume: Option<BigDecimal>,
+    pub usdc_volume: Option<BigDecimal>,
+    pub usdt_volume: Option<BigDecimal>,
+    pub weth_volume: Option<BigDecimal>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A pair's volume on one protocol, either for one batch or accumulated over the 24h window
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = pair_volume_by_protocol)]
+pub struct NewPairProtocolVolume {
+    pub protocol: String,
+    pub pair: String,
+    pub apt_volume: Option<BigDecimal>,
+    pub usdc_volume: Option<BigDecimal>,
+    pub usdt_volume: Option<BigDecimal>,
+    pub weth_volume: Option<BigDecimal>,
+}
@@ -0,0 +1,35 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::pool_reserves;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = pool_reserves)]
+pub struct PoolReserve {
+    pub pool_address: String,
+    pub protocol: String,
+    pub reserve_x: BigDecimal,
+    pub reserve_y: BigDecimal,
+    pub txn_version: i64,
+    pub updated_at: DateTime<Utc>,
+    pub current_tick: Option<i32>,
+}
+
+/// Raw (not decimal-normalized) reserves of a pool as of `txn_version`, plus the active tick
+/// for concentrated liquidity pools
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = pool_reserves)]
+pub struct NewPoolReserve {
+    pub pool_address: String,
+    pub protocol: String,
+    pub reserve_x: BigDecimal,
+    pub reserve_y: BigDecimal,
+    pub txn_version: i64,
+    pub current_tick: Option<i32>,
+}
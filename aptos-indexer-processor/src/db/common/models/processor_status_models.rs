@@ -0,0 +1,30 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::processor_status;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = processor_status)]
+pub struct ProcessorStatus {
+    pub processor_name: String,
+    pub last_success_version: i64,
+    pub last_updated: NaiveDateTime,
+    pub last_transaction_timestamp: Option<NaiveDateTime>,
+    pub start_version: Option<i64>,
+    pub batch_processing_time_ms: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = processor_status)]
+pub struct NewProcessorStatus {
+    pub processor_name: String,
+    pub last_success_version: i64,
+    pub last_transaction_timestamp: Option<NaiveDateTime>,
+    pub start_version: Option<i64>,
+    pub batch_processing_time_ms: Option<i64>,
+}
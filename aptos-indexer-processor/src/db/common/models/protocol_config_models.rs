@@ -0,0 +1,28 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::protocol_config;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = protocol_config)]
+pub struct ProtocolConfigEntry {
+    pub protocol_name: String,
+    pub pool_address: String,
+    pub fee_bps: i32,
+    pub effective_from_version: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone)]
+#[diesel(table_name = protocol_config)]
+pub struct NewProtocolConfigEntry {
+    pub protocol_name: String,
+    pub pool_address: String,
+    pub fee_bps: i32,
+    pub effective_from_version: i64,
+}
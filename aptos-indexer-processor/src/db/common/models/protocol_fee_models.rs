@@ -0,0 +1,45 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use super::apt_models::NewAptData;
+use crate::db::postgres::schema::protocol_fees_24h;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = protocol_fees_24h)]
+pub struct ProtocolFee {
+    pub protocol_name: String,
+    pub apt_fee: Option<BigDecimal>,
+    pub usdc_fee: Option<BigDecimal>,
+    pub usdt_fee: Option<BigDecimal>,
+    pub weth_fee: Option<BigDecimal>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A protocol's swap fees, either for one batch or accumulated over the 24h window
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = protocol_fees_24h)]
+pub struct NewProtocolFee {
+    pub protocol_name: String,
+    pub apt_fee: Option<BigDecimal>,
+    pub usdc_fee: Option<BigDecimal>,
+    pub usdt_fee: Option<BigDecimal>,
+    pub weth_fee: Option<BigDecimal>,
+}
+
+impl From<&NewAptData> for NewProtocolFee {
+    fn from(data: &NewAptData) -> Self {
+        Self {
+            protocol_name: data.protocol_name.clone(),
+            apt_fee: data.apt_fee_24h.clone(),
+            usdc_fee: data.usdc_fee_24h.clone(),
+            usdt_fee: data.usdt_fee_24h.clone(),
+            weth_fee: data.weth_fee_24h.clone(),
+        }
+    }
+}
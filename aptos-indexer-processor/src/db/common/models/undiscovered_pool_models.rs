@@ -0,0 +1,33 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::undiscovered_pools;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = undiscovered_pools)]
+pub struct UndiscoveredPool {
+    pub protocol: String,
+    pub token_x: String,
+    pub token_y: String,
+    pub first_seen_version: i64,
+    pub last_seen_version: i64,
+    pub swap_count: i64,
+    pub inserted_at: NaiveDateTime,
+}
+
+/// Swaps seen in a batch for a pair that is not in the pool registry
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = undiscovered_pools)]
+pub struct NewUndiscoveredPool {
+    pub protocol: String,
+    pub token_x: String,
+    pub token_y: String,
+    pub first_seen_version: i64,
+    pub last_seen_version: i64,
+    pub swap_count: i64,
+}
@@ -0,0 +1,32 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::db::postgres::schema::vwap_buckets;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Clone)]
+#[diesel(table_name = vwap_buckets)]
+pub struct VwapBucket {
+    pub protocol: String,
+    pub pair: String,
+    pub bucket_start: DateTime<Utc>,
+    pub vwap: BigDecimal,
+    pub volume: BigDecimal,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A pair's VWAP on one protocol over a bucket, weighted by base coin `volume`
+#[derive(Debug, Deserialize, Serialize, Insertable, Clone, PartialEq)]
+#[diesel(table_name = vwap_buckets)]
+pub struct NewVwapBucket {
+    pub protocol: String,
+    pub pair: String,
+    pub bucket_start: DateTime<Utc>,
+    pub vwap: BigDecimal,
+    pub volume: BigDecimal,
+}
@@ -19,6 +19,10 @@
 //! - `apt_data`: Protocol-specific volume and fee aggregations
 //! - `coin_volume_24h`: 24-hour rolling volumes by coin type
 //! - `coin_volume_buckets`: Time-bucketed volume data for analytics
+//! - `coin_volume_daily_buckets`: Daily (GMT+7) volume per coin for candles
+//! - `protocol_config`: Per-pool fee rates that can change at runtime
+//! - `anomalous_swaps`: Swaps excluded from volume as implausibly large
+//! - `coin_price_usd`: Swap-implied coin prices per source protocol
 //! - `events`: Raw event data from blockchain transactions
 //! - `ledger_infos`: Chain metadata and validation information
 //! 
@@ -0,0 +1,2 @@
+-- Track BTC volume (LiquidSwap APT/izBTC pool) alongside the existing per-coin columns
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS btc_volume_24h NUMERIC DEFAULT 0;
@@ -0,0 +1,12 @@
+-- Per-pool fee rates that can be changed at runtime (e.g. after a governance vote).
+-- The row with the highest effective_from_version is the current rate for a pool.
+CREATE TABLE IF NOT EXISTS protocol_config (
+    protocol_name TEXT NOT NULL,
+    pool_address TEXT NOT NULL,
+    fee_bps INTEGER NOT NULL,
+    effective_from_version BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (protocol_name, pool_address, effective_from_version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_protocol_config_pool_version ON protocol_config(pool_address, effective_from_version DESC);
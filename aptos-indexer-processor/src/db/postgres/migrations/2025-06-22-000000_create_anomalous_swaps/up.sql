@@ -0,0 +1,16 @@
+-- Swaps excluded from volume because a single-swap amount exceeded its anomaly threshold.
+-- Kept for operator review (parsing bugs, wash trades).
+CREATE TABLE IF NOT EXISTS anomalous_swaps (
+    id BIGSERIAL PRIMARY KEY,
+    protocol_name VARCHAR NOT NULL,
+    transaction_version BIGINT NOT NULL,
+    event_type TEXT NOT NULL,
+    coin VARCHAR NOT NULL,
+    amount NUMERIC NOT NULL,
+    event_data JSONB NOT NULL,
+    transaction_timestamp TIMESTAMP NOT NULL,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_anomalous_swaps_protocol_name ON anomalous_swaps(protocol_name);
+CREATE INDEX IF NOT EXISTS idx_anomalous_swaps_transaction_version ON anomalous_swaps(transaction_version);
@@ -0,0 +1,15 @@
+-- USD prices implied by individual swaps against a stablecoin, one row per swap and source protocol.
+-- Keeping the source protocol allows comparing prices reported by different DEXes.
+CREATE TABLE IF NOT EXISTS coin_price_usd (
+    id BIGSERIAL PRIMARY KEY,
+    coin VARCHAR NOT NULL,
+    quote_coin VARCHAR NOT NULL,
+    price_usd NUMERIC NOT NULL,
+    source_protocol VARCHAR NOT NULL,
+    transaction_version BIGINT NOT NULL,
+    transaction_timestamp TIMESTAMP NOT NULL,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_coin_price_usd_coin_timestamp ON coin_price_usd(coin, transaction_timestamp);
+CREATE INDEX IF NOT EXISTS idx_coin_price_usd_source_protocol ON coin_price_usd(source_protocol);
@@ -0,0 +1,11 @@
+-- Daily per-coin volume for chart candles. bucket_date is the GMT+7 calendar day,
+-- i.e. the bucket starts at 17:00 UTC on the previous day.
+CREATE TABLE IF NOT EXISTS coin_volume_daily_buckets (
+    coin VARCHAR NOT NULL,
+    bucket_date DATE NOT NULL,
+    volume NUMERIC NOT NULL DEFAULT 0,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (coin, bucket_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_coin_volume_daily_buckets_bucket_date ON coin_volume_daily_buckets(bucket_date);
@@ -0,0 +1,2 @@
+-- Number of swaps behind each protocol's rolling 24h volume
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS trade_count BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- Track BNB volume (LiquidSwap APT/BNB pool) alongside the existing per-coin columns
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS bnb_volume_24h NUMERIC DEFAULT 0;
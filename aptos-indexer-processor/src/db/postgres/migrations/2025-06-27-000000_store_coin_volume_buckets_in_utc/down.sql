@@ -0,0 +1,3 @@
+UPDATE coin_volume_buckets
+SET bucket_start = bucket_start + INTERVAL '7 hours',
+    bucket_end = bucket_end + INTERVAL '7 hours';
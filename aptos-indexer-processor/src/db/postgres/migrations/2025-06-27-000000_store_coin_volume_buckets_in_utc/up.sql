@@ -0,0 +1,5 @@
+-- Bucket boundaries were written as GMT+7 wall-clock times; store them as UTC like every
+-- other timestamp so the 24h cleanup cutoff compares correctly.
+UPDATE coin_volume_buckets
+SET bucket_start = bucket_start - INTERVAL '7 hours',
+    bucket_end = bucket_end - INTERVAL '7 hours';
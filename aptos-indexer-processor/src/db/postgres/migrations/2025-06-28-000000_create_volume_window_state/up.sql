@@ -0,0 +1,7 @@
+-- Start of the current 24h accumulation window for apt_data / coin_volume_24h.
+-- Single row; the window ends at window_start + 24h.
+CREATE TABLE IF NOT EXISTS volume_window_state (
+    id INTEGER PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    window_start TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,6 @@
+DELETE FROM coin_volume_buckets WHERE protocol <> 'all';
+
+ALTER TABLE coin_volume_buckets DROP CONSTRAINT IF EXISTS coin_volume_buckets_pkey;
+ALTER TABLE coin_volume_buckets ADD PRIMARY KEY (coin, bucket_start);
+
+ALTER TABLE coin_volume_buckets DROP COLUMN IF EXISTS protocol;
@@ -0,0 +1,6 @@
+-- Tag buckets with the protocol they were computed for. Existing rows are the
+-- cross-protocol totals, which keep the 'all' tag.
+ALTER TABLE coin_volume_buckets ADD COLUMN IF NOT EXISTS protocol TEXT NOT NULL DEFAULT 'all';
+
+ALTER TABLE coin_volume_buckets DROP CONSTRAINT IF EXISTS coin_volume_buckets_pkey;
+ALTER TABLE coin_volume_buckets ADD PRIMARY KEY (coin, protocol, bucket_start);
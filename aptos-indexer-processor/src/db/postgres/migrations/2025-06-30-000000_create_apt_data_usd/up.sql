@@ -0,0 +1,7 @@
+-- apt_data volumes and fees converted to USD with the latest coin_price_usd prices
+CREATE TABLE IF NOT EXISTS apt_data_usd (
+    protocol_name VARCHAR PRIMARY KEY,
+    total_usd_volume NUMERIC NOT NULL DEFAULT 0,
+    total_usd_fee NUMERIC NOT NULL DEFAULT 0,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
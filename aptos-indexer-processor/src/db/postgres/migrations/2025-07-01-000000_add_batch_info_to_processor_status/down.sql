@@ -0,0 +1,2 @@
+ALTER TABLE processor_status DROP COLUMN IF EXISTS batch_processing_time_ms;
+ALTER TABLE processor_status DROP COLUMN IF EXISTS start_version;
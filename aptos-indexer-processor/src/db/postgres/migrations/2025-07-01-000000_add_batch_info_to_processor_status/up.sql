@@ -0,0 +1,4 @@
+-- Record the version range and processing time of each checkpoint, not just its end.
+-- last_success_version remains the end version of the range.
+ALTER TABLE processor_status ADD COLUMN IF NOT EXISTS start_version BIGINT;
+ALTER TABLE processor_status ADD COLUMN IF NOT EXISTS batch_processing_time_ms BIGINT;
@@ -0,0 +1,12 @@
+-- Per-swap fee_growth_global deltas from Hyperion V3 swaps, converted from Q128.128.
+-- Fees credited to all in-range LPs of the pool, for LP analytics.
+CREATE TABLE IF NOT EXISTS hyperion_pool_fee_growth (
+    id BIGSERIAL PRIMARY KEY,
+    pool_address TEXT NOT NULL,
+    fee_growth_x NUMERIC NOT NULL,
+    fee_growth_y NUMERIC NOT NULL,
+    txn_version BIGINT NOT NULL,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_hyperion_pool_fee_growth_pool_version ON hyperion_pool_fee_growth(pool_address, txn_version);
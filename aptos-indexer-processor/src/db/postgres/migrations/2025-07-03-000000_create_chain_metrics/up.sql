@@ -0,0 +1,11 @@
+-- One row per block metadata transaction, for monitoring block production and epoch changes
+CREATE TABLE IF NOT EXISTS chain_metrics (
+    txn_version BIGINT PRIMARY KEY,
+    epoch BIGINT NOT NULL,
+    round BIGINT NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL,
+    proposer TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_chain_metrics_epoch ON chain_metrics(epoch);
+CREATE INDEX IF NOT EXISTS idx_chain_metrics_timestamp ON chain_metrics(timestamp);
@@ -0,0 +1,16 @@
+-- Rolling 24h swap fees per protocol; apt_data's fee columns are copied from here
+CREATE TABLE IF NOT EXISTS protocol_fees_24h (
+    protocol_name TEXT PRIMARY KEY,
+    apt_fee NUMERIC,
+    usdc_fee NUMERIC,
+    usdt_fee NUMERIC,
+    weth_fee NUMERIC,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Start from the fees already accumulated in apt_data
+INSERT INTO protocol_fees_24h (protocol_name, apt_fee, usdc_fee, usdt_fee, weth_fee)
+SELECT protocol_name, apt_fee_24h, usdc_fee_24h, usdt_fee_24h, weth_fee_24h
+FROM apt_data
+WHERE protocol_name <> 'aptos'
+ON CONFLICT (protocol_name) DO NOTHING;
@@ -0,0 +1,8 @@
+-- DEX modules found in the genesis write set, so a new chain's contract addresses need no configuration
+CREATE TABLE IF NOT EXISTS deployed_contracts (
+    address TEXT NOT NULL,
+    module_name TEXT NOT NULL,
+    protocol TEXT NOT NULL,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (address, module_name)
+);
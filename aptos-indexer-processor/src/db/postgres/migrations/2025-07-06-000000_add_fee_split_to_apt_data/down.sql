@@ -0,0 +1,2 @@
+ALTER TABLE apt_data DROP COLUMN IF EXISTS protocol_fee_24h;
+ALTER TABLE apt_data DROP COLUMN IF EXISTS lp_fee_24h;
@@ -0,0 +1,3 @@
+-- Cellana V2 pools charge a protocol (treasury) fee on top of the LP fee; both in USD
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS lp_fee_24h NUMERIC DEFAULT 0;
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS protocol_fee_24h NUMERIC DEFAULT 0;
@@ -0,0 +1 @@
+ALTER TABLE anomalous_swaps DROP COLUMN IF EXISTS user_address;
@@ -0,0 +1,2 @@
+-- Primary signer of the swap transaction; fee payers and secondary signers are not the trader
+ALTER TABLE anomalous_swaps ADD COLUMN IF NOT EXISTS user_address TEXT;
@@ -0,0 +1,13 @@
+-- Rolling 24h volume for each pair on each protocol, for comparing protocols on the same pair
+CREATE TABLE IF NOT EXISTS pair_volume_by_protocol (
+    protocol TEXT NOT NULL,
+    pair TEXT NOT NULL,
+    apt_volume NUMERIC,
+    usdc_volume NUMERIC,
+    usdt_volume NUMERIC,
+    weth_volume NUMERIC,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (protocol, pair)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pair_volume_by_protocol_pair ON pair_volume_by_protocol (pair);
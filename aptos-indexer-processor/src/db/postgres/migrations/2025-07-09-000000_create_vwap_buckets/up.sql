@@ -0,0 +1,10 @@
+-- Volume-weighted average price of each pair per protocol and chart bucket
+CREATE TABLE IF NOT EXISTS vwap_buckets (
+    protocol TEXT NOT NULL,
+    pair TEXT NOT NULL,
+    bucket_start TIMESTAMPTZ NOT NULL,
+    vwap NUMERIC NOT NULL,
+    volume NUMERIC NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (protocol, pair, bucket_start)
+);
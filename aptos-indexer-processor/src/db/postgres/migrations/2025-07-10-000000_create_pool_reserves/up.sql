@@ -0,0 +1,10 @@
+-- Latest reserves seen for each pool, for depth and slippage estimates
+CREATE TABLE IF NOT EXISTS pool_reserves (
+    pool_address TEXT NOT NULL,
+    protocol TEXT NOT NULL,
+    reserve_x NUMERIC NOT NULL,
+    reserve_y NUMERIC NOT NULL,
+    txn_version BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (protocol, pool_address)
+);
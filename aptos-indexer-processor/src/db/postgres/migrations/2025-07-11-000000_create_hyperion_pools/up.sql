@@ -0,0 +1,8 @@
+-- Hyperion V3 pools, registered from their InitializePoolEvent
+CREATE TABLE IF NOT EXISTS hyperion_pools (
+    pool_address TEXT PRIMARY KEY,
+    token0 TEXT NOT NULL,
+    token1 TEXT NOT NULL,
+    fee_tier INTEGER NOT NULL,
+    created_at_version BIGINT NOT NULL
+);
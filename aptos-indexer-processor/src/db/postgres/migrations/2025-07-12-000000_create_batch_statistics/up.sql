@@ -0,0 +1,13 @@
+-- Per-batch sizes and timings for tracking down latency spikes; rows are kept for 7 days
+CREATE TABLE IF NOT EXISTS batch_statistics (
+    id BIGSERIAL PRIMARY KEY,
+    start_version BIGINT NOT NULL,
+    end_version BIGINT NOT NULL,
+    transaction_count INTEGER NOT NULL,
+    swap_event_count INTEGER NOT NULL,
+    processing_time_ms INTEGER NOT NULL,
+    db_write_time_ms INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_batch_statistics_created_at ON batch_statistics (created_at);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_apt_data_active;
+DROP INDEX IF EXISTS idx_coin_volume_24h_coin_inserted_at;
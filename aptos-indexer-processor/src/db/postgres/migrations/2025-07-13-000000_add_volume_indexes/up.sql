@@ -0,0 +1,5 @@
+-- Per-coin lookups by age, for rolling windows keyed by time
+CREATE INDEX IF NOT EXISTS idx_coin_volume_24h_coin_inserted_at ON coin_volume_24h(coin, inserted_at DESC);
+
+-- Protocols with APT volume in the current window
+CREATE INDEX IF NOT EXISTS idx_apt_data_active ON apt_data(protocol_name) WHERE apt_volume_24h > 0;
@@ -0,0 +1 @@
+ALTER TABLE pool_reserves DROP COLUMN IF EXISTS current_tick;
@@ -0,0 +1,2 @@
+-- Active tick of concentrated liquidity pools, for TVL in the active tick range
+ALTER TABLE pool_reserves ADD COLUMN IF NOT EXISTS current_tick INTEGER;
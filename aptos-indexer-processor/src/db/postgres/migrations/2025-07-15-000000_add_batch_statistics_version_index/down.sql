@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_batch_statistics_versions;
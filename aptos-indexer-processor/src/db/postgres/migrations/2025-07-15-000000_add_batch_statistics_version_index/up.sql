@@ -0,0 +1,2 @@
+-- Duplicate batch check on each batch's version range
+CREATE INDEX IF NOT EXISTS idx_batch_statistics_versions ON batch_statistics(start_version, end_version);
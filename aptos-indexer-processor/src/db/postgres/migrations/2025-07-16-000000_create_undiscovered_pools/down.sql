@@ -0,0 +1 @@
+DROP TABLE IF EXISTS undiscovered_pools;
@@ -0,0 +1,11 @@
+-- LiquidSwap pairs seen on-chain but absent from the pool registry, kept for operator review
+CREATE TABLE IF NOT EXISTS undiscovered_pools (
+    protocol TEXT NOT NULL,
+    token_x TEXT NOT NULL,
+    token_y TEXT NOT NULL,
+    first_seen_version BIGINT NOT NULL,
+    last_seen_version BIGINT NOT NULL,
+    swap_count BIGINT NOT NULL DEFAULT 0,
+    inserted_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (protocol, token_x, token_y)
+);
@@ -0,0 +1 @@
+ALTER TABLE apt_data DROP COLUMN IF EXISTS move_volume_24h;
@@ -0,0 +1,2 @@
+-- Track MOVE volume (LiquidSwap APT/MOVE pool) alongside the existing per-coin columns
+ALTER TABLE apt_data ADD COLUMN IF NOT EXISTS move_volume_24h NUMERIC DEFAULT 0;
@@ -0,0 +1,13 @@
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_apt_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdc_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdt_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_weth_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_btc_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_bnb_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_move_volume_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_apt_fee_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdc_fee_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdt_fee_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_weth_fee_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_lp_fee_24h_non_negative;
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_protocol_fee_24h_non_negative;
@@ -0,0 +1,27 @@
+-- Rolling volumes and fees only ever grow from zero; a negative value means an accumulation bug
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_apt_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_apt_volume_24h_non_negative CHECK (apt_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdc_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_usdc_volume_24h_non_negative CHECK (usdc_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdt_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_usdt_volume_24h_non_negative CHECK (usdt_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_weth_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_weth_volume_24h_non_negative CHECK (weth_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_btc_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_btc_volume_24h_non_negative CHECK (btc_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_bnb_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_bnb_volume_24h_non_negative CHECK (bnb_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_move_volume_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_move_volume_24h_non_negative CHECK (move_volume_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_apt_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_apt_fee_24h_non_negative CHECK (apt_fee_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdc_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_usdc_fee_24h_non_negative CHECK (usdc_fee_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_usdt_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_usdt_fee_24h_non_negative CHECK (usdt_fee_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_weth_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_weth_fee_24h_non_negative CHECK (weth_fee_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_lp_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_lp_fee_24h_non_negative CHECK (lp_fee_24h >= 0);
+ALTER TABLE apt_data DROP CONSTRAINT IF EXISTS apt_data_protocol_fee_24h_non_negative;
+ALTER TABLE apt_data ADD CONSTRAINT apt_data_protocol_fee_24h_non_negative CHECK (protocol_fee_24h >= 0);
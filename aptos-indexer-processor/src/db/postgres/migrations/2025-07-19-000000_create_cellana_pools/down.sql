@@ -0,0 +1 @@
+DROP TABLE IF EXISTS cellana_pools;
@@ -0,0 +1,8 @@
+-- Cellana V2 pools, registered from their PoolCreatedEvent
+CREATE TABLE IF NOT EXISTS cellana_pools (
+    pool_address TEXT PRIMARY KEY,
+    token_x TEXT NOT NULL,
+    token_y TEXT NOT NULL,
+    fee_bps INTEGER NOT NULL,
+    created_at_version BIGINT NOT NULL
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS apt_data_hourly_snapshots;
@@ -0,0 +1,24 @@
+-- apt_data's rolling 24h values copied at the top of every UTC hour; 168 rows (7 days) are kept per protocol
+CREATE TABLE IF NOT EXISTS apt_data_hourly_snapshots (
+    id BIGSERIAL PRIMARY KEY,
+    protocol TEXT NOT NULL,
+    snapshot_hour TIMESTAMPTZ NOT NULL,
+    apt_volume NUMERIC,
+    usdc_volume NUMERIC,
+    usdt_volume NUMERIC,
+    weth_volume NUMERIC,
+    btc_volume NUMERIC,
+    bnb_volume NUMERIC,
+    move_volume NUMERIC,
+    apt_fee NUMERIC,
+    usdc_fee NUMERIC,
+    usdt_fee NUMERIC,
+    weth_fee NUMERIC,
+    lp_fee NUMERIC,
+    protocol_fee NUMERIC,
+    trade_count BIGINT NOT NULL,
+    inserted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One snapshot per protocol and hour, even if the task runs twice in an hour after a restart
+CREATE UNIQUE INDEX IF NOT EXISTS idx_apt_data_hourly_snapshots_protocol_hour ON apt_data_hourly_snapshots (protocol, snapshot_hour);
@@ -1,5 +1,20 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    anomalous_swaps (id) {
+        id -> Int8,
+        protocol_name -> Varchar,
+        transaction_version -> Int8,
+        event_type -> Text,
+        coin -> Varchar,
+        amount -> Numeric,
+        event_data -> Jsonb,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+        user_address -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     apt_data (protocol_name) {
         protocol_name -> Varchar,
@@ -12,6 +27,44 @@ diesel::table! {
         usdt_fee_24h -> Nullable<Numeric>,
         weth_volume_24h -> Nullable<Numeric>,
         weth_fee_24h -> Nullable<Numeric>,
+        btc_volume_24h -> Nullable<Numeric>,
+        trade_count -> Int8,
+        bnb_volume_24h -> Nullable<Numeric>,
+        lp_fee_24h -> Nullable<Numeric>,
+        protocol_fee_24h -> Nullable<Numeric>,
+        move_volume_24h -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    apt_data_hourly_snapshots (id) {
+        id -> Int8,
+        protocol -> Text,
+        snapshot_hour -> Timestamptz,
+        apt_volume -> Nullable<Numeric>,
+        usdc_volume -> Nullable<Numeric>,
+        usdt_volume -> Nullable<Numeric>,
+        weth_volume -> Nullable<Numeric>,
+        btc_volume -> Nullable<Numeric>,
+        bnb_volume -> Nullable<Numeric>,
+        move_volume -> Nullable<Numeric>,
+        apt_fee -> Nullable<Numeric>,
+        usdc_fee -> Nullable<Numeric>,
+        usdt_fee -> Nullable<Numeric>,
+        weth_fee -> Nullable<Numeric>,
+        lp_fee -> Nullable<Numeric>,
+        protocol_fee -> Nullable<Numeric>,
+        trade_count -> Int8,
+        inserted_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    apt_data_usd (protocol_name) {
+        protocol_name -> Varchar,
+        total_usd_volume -> Numeric,
+        total_usd_fee -> Numeric,
+        inserted_at -> Timestamp,
     }
 }
 
@@ -29,6 +82,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    batch_statistics (id) {
+        id -> Int8,
+        start_version -> Int8,
+        end_version -> Int8,
+        transaction_count -> Int4,
+        swap_event_count -> Int4,
+        processing_time_ms -> Int4,
+        db_write_time_ms -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    cellana_pools (pool_address) {
+        pool_address -> Text,
+        token_x -> Text,
+        token_y -> Text,
+        fee_bps -> Int4,
+        created_at_version -> Int8,
+    }
+}
+
 diesel::table! {
     cetus_add_liquidity_events (id) {
         id -> Varchar,
@@ -57,6 +133,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    chain_metrics (txn_version) {
+        txn_version -> Int8,
+        epoch -> Int8,
+        round -> Int8,
+        timestamp -> Timestamptz,
+        proposer -> Text,
+    }
+}
+
+diesel::table! {
+    coin_price_usd (id) {
+        id -> Int8,
+        coin -> Varchar,
+        quote_coin -> Varchar,
+        price_usd -> Numeric,
+        source_protocol -> Varchar,
+        transaction_version -> Int8,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     coin_volume_24h (coin) {
         coin -> Varchar,
@@ -67,12 +166,22 @@ diesel::table! {
 }
 
 diesel::table! {
-    coin_volume_buckets (coin, bucket_start) {
+    coin_volume_buckets (coin, protocol, bucket_start) {
         coin -> Varchar,
         bucket_start -> Timestamp,
         bucket_end -> Timestamp,
         volume -> Nullable<Numeric>,
         inserted_at -> Timestamp,
+        protocol -> Text,
+    }
+}
+
+diesel::table! {
+    coin_volume_daily_buckets (coin, bucket_date) {
+        coin -> Varchar,
+        bucket_date -> Date,
+        volume -> Numeric,
+        inserted_at -> Timestamp,
     }
 }
 
@@ -102,6 +211,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    deployed_contracts (address, module_name) {
+        address -> Text,
+        module_name -> Text,
+        protocol -> Text,
+        inserted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     events (transaction_version, event_index) {
         sequence_number -> Int8,
@@ -133,6 +251,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    hyperion_pool_fee_growth (id) {
+        id -> Int8,
+        pool_address -> Text,
+        fee_growth_x -> Numeric,
+        fee_growth_y -> Numeric,
+        txn_version -> Int8,
+        inserted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    hyperion_pools (pool_address) {
+        pool_address -> Text,
+        token0 -> Text,
+        token1 -> Text,
+        fee_tier -> Int4,
+        created_at_version -> Int8,
+    }
+}
+
 diesel::table! {
     ledger_infos (chain_id) {
         chain_id -> Int8,
@@ -152,18 +291,125 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    pair_volume_by_protocol (protocol, pair) {
+        protocol -> Text,
+        pair -> Text,
+        apt_volume -> Nullable<Numeric>,
+        usdc_volume -> Nullable<Numeric>,
+        usdt_volume -> Nullable<Numeric>,
+        weth_volume -> Nullable<Numeric>,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    pool_reserves (protocol, pool_address) {
+        pool_address -> Text,
+        protocol -> Text,
+        reserve_x -> Numeric,
+        reserve_y -> Numeric,
+        txn_version -> Int8,
+        updated_at -> Timestamptz,
+        current_tick -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    processor_status (processor_name) {
+        #[max_length = 50]
+        processor_name -> Varchar,
+        last_success_version -> Int8,
+        last_updated -> Timestamp,
+        last_transaction_timestamp -> Nullable<Timestamp>,
+        start_version -> Nullable<Int8>,
+        batch_processing_time_ms -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    protocol_config (protocol_name, pool_address, effective_from_version) {
+        protocol_name -> Text,
+        pool_address -> Text,
+        fee_bps -> Int4,
+        effective_from_version -> Int8,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    protocol_fees_24h (protocol_name) {
+        protocol_name -> Text,
+        apt_fee -> Nullable<Numeric>,
+        usdc_fee -> Nullable<Numeric>,
+        usdt_fee -> Nullable<Numeric>,
+        weth_fee -> Nullable<Numeric>,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    undiscovered_pools (protocol, token_x, token_y) {
+        protocol -> Text,
+        token_x -> Text,
+        token_y -> Text,
+        first_seen_version -> Int8,
+        last_seen_version -> Int8,
+        swap_count -> Int8,
+        inserted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    volume_window_state (id) {
+        id -> Int4,
+        window_start -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    vwap_buckets (protocol, pair, bucket_start) {
+        protocol -> Text,
+        pair -> Text,
+        bucket_start -> Timestamptz,
+        vwap -> Numeric,
+        volume -> Numeric,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
+    anomalous_swaps,
     apt_data,
+    apt_data_hourly_snapshots,
+    apt_data_usd,
     backfill_processor_status,
+    batch_statistics,
+    cellana_pools,
     cetus_add_liquidity_events,
     cetus_remove_liquidity_events,
     cetus_swap_events,
+    chain_metrics,
+    coin_price_usd,
     coin_volume_24h,
     coin_volume_buckets,
+    coin_volume_daily_buckets,
     daily_statistics,
     dapp_rankings,
+    deployed_contracts,
     events,
     hourly_statistics,
+    hyperion_pool_fee_growth,
+    hyperion_pools,
     ledger_infos,
     liquidity_events,
+    pair_volume_by_protocol,
+    pool_reserves,
+    processor_status,
+    protocol_config,
+    protocol_fees_24h,
+    undiscovered_pools,
+    volume_window_state,
+    vwap_buckets,
 );
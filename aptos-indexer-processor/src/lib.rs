@@ -20,6 +20,9 @@
 //! - Protocol-specific fee tracking and aggregation
 //! - High-performance PostgreSQL storage with connection pooling
 
+/// Read-only HTTP API over the volume tables
+pub mod api;
+
 /// Common utilities and shared components used across the indexer
 pub mod common;
 
@@ -8,10 +8,56 @@
 //! 
 //! Calculates real-time 24h rolling volumes, fees, and time-bucketed data.
 
-use anyhow::Result;
-use aptos_indexer_processor::config::indexer_processor_config::IndexerProcessorConfig;
-use aptos_indexer_processor_sdk_server_framework::ServerArgs;
+use anyhow::{Context, Result};
+use aptos_indexer_processor::{
+    api,
+    config::indexer_processor_config::IndexerProcessorConfig,
+    db::common::models::protocol_config_models::NewProtocolConfigEntry,
+    utils::{
+        database::new_db_pool,
+        logging::{init_logging, LogFormat, DEFAULT_LOG_LEVEL},
+        protocol_config_loader::ProtocolConfigLoader,
+    },
+};
+use aptos_indexer_processor_sdk_server_framework::{
+    load, run_server_with_config, setup_panic_handler, GenericConfig, ServerArgs,
+};
 use clap::Parser;
+use std::path::PathBuf;
+
+/// `update-fee` subcommand: records a new fee rate in the `protocol_config` table
+///
+/// Example: `update-fee --config-path config.yaml --protocol cellana --pool 0xabc... --fee-bps 25 --from-version 123456789`
+#[derive(Parser)]
+#[clap(name = "update-fee")]
+struct UpdateFeeArgs {
+    /// Indexer config file, used for the database connection string
+    #[clap(long, default_value = "config.yaml")]
+    config_path: PathBuf,
+    #[clap(long)]
+    protocol: String,
+    #[clap(long)]
+    pool: String,
+    #[clap(long)]
+    fee_bps: u32,
+    #[clap(long)]
+    from_version: i64,
+}
+
+async fn update_fee(args: UpdateFeeArgs) -> Result<()> {
+    let config = load::<GenericConfig<IndexerProcessorConfig>>(&args.config_path)?;
+    let db_pool = new_db_pool(&config.server_config.db_config.postgres_connection_string, Some(1))
+        .await
+        .context("Failed to create connection pool")?;
+
+    ProtocolConfigLoader::insert(&db_pool, NewProtocolConfigEntry {
+        protocol_name: args.protocol,
+        pool_address: args.pool,
+        fee_bps: i32::try_from(args.fee_bps).context("fee_bps is too large")?,
+        effective_from_version: args.from_version,
+    })
+    .await
+}
 
 /// Configure jemalloc as the global allocator for better memory management
 #[cfg(unix)]
@@ -36,9 +82,20 @@ fn main() -> Result<()> {
         .build()
         .expect("Failed to build async runtime")
         .block_on(async {
-            // Parse command line arguments and run the indexer server
+            // `update-fee` is handled here instead of starting the indexer
+            if std::env::args().nth(1).as_deref() == Some("update-fee") {
+                init_logging(DEFAULT_LOG_LEVEL, LogFormat::from_env());
+                return update_fee(UpdateFeeArgs::parse_from(std::env::args().skip(1))).await;
+            }
+
+            // Parse command line arguments and run the indexer server.
+            // `ServerArgs::run` would install the framework's own subscriber, so the
+            // steps are done here to use our logging setup (see `LOG_FORMAT`).
             let args = ServerArgs::parse();
-            args.run::<IndexerProcessorConfig>(tokio::runtime::Handle::current())
-                .await
+            init_logging(DEFAULT_LOG_LEVEL, LogFormat::from_env());
+            setup_panic_handler();
+            let config = load::<GenericConfig<IndexerProcessorConfig>>(&args.config_path)?;
+            api::spawn(&config.server_config).await?;
+            run_server_with_config(config, tokio::runtime::Handle::current()).await
         })
 }
@@ -0,0 +1,78 @@
+use bigdecimal::{BigDecimal, FromPrimitive};
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+/// Default maximum plausible single-swap amount per coin (normalized units).
+/// Anything above is far beyond on-chain liquidity and is most likely a parsing bug or wash trade.
+const DEFAULT_MAX_SINGLE_SWAP: &[(&str, f64)] = &[
+    ("APT", 1_000_000.0),
+    ("USDC", 50_000_000.0),
+    ("USDT", 50_000_000.0),
+    ("WETH", 20_000.0),
+    ("BTC", 1_000.0),
+];
+
+/// VolumeAnomalyDetector flags single swaps whose normalized amount is implausibly large
+#[derive(Clone, Debug)]
+pub struct VolumeAnomalyDetector {
+    thresholds: HashMap<String, BigDecimal>,
+}
+
+impl Default for VolumeAnomalyDetector {
+    fn default() -> Self {
+        Self::new(&HashMap::new())
+    }
+}
+
+impl VolumeAnomalyDetector {
+    /// Build from the built-in defaults, overridden per coin by `overrides` (e.g. `APT: 1000000.0`)
+    pub fn new(overrides: &HashMap<String, f64>) -> Self {
+        let mut thresholds = HashMap::new();
+        let defaults = DEFAULT_MAX_SINGLE_SWAP.iter().map(|(coin, max)| (coin.to_string(), *max));
+        let configured = overrides.iter().map(|(coin, max)| (coin.to_uppercase(), *max));
+
+        for (coin, max) in defaults.chain(configured) {
+            match BigDecimal::from_f64(max) {
+                Some(threshold) => {
+                    thresholds.insert(coin, threshold);
+                },
+                None => warn!("⚠️ Ignoring invalid anomaly threshold {} for {}", max, coin),
+            }
+        }
+
+        info!("🚨 Anomaly detection thresholds: {:?}", thresholds);
+        Self { thresholds }
+    }
+
+    /// Returns true if `amount` of `coin` exceeds the configured single-swap maximum.
+    /// Coins without a threshold are never anomalous.
+    pub fn is_anomalous(&self, coin: &str, amount: &BigDecimal) -> bool {
+        self.thresholds
+            .get(coin)
+            .map(|threshold| amount > threshold)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_is_anomalous_with_overrides() {
+        let mut overrides = HashMap::new();
+        overrides.insert("apt".to_string(), 500.0);
+        let detector = VolumeAnomalyDetector::new(&overrides);
+
+        // Override replaces the APT default (case-insensitive coin key)
+        assert!(detector.is_anomalous("APT", &BigDecimal::from_str("500.5").unwrap()));
+        assert!(!detector.is_anomalous("APT", &BigDecimal::from_str("500").unwrap()));
+
+        // Defaults still apply to other coins
+        assert!(detector.is_anomalous("USDC", &BigDecimal::from_str("50000001").unwrap()));
+
+        // Coins without a threshold are never anomalous
+        assert!(!detector.is_anomalous("DOGE", &BigDecimal::from_str("1e20").unwrap()));
+    }
+}
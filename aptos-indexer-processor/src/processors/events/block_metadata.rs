@@ -0,0 +1,102 @@
+use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{transaction::TxnData, Transaction};
+use chrono::DateTime;
+use std::collections::VecDeque;
+use tracing::info;
+
+use crate::{db::common::models::chain_metrics_models::ChainMetric, utils::metrics::BLOCKS_PER_SECOND};
+
+/// Blocks the rolling `blocks_per_second` average is taken over
+pub const BLOCK_RATE_WINDOW: usize = 100;
+
+/// BlockMetadataProcessor records block metadata transactions and tracks the block rate
+#[derive(Clone, Debug, Default)]
+pub struct BlockMetadataProcessor {
+    // Timestamps (microseconds) of the most recent blocks, oldest first
+    recent_block_timestamps: VecDeque<i64>,
+    last_epoch: Option<i64>,
+}
+
+impl BlockMetadataProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chain metrics for `txn` if it is a block metadata transaction
+    pub fn extract_chain_metric(txn: &Transaction) -> Option<ChainMetric> {
+        let Some(TxnData::BlockMetadata(block_metadata)) = &txn.txn_data else {
+            return None;
+        };
+        let timestamp = txn.timestamp.as_ref()?;
+
+        Some(ChainMetric {
+            txn_version: txn.version as i64,
+            epoch: txn.epoch as i64,
+            round: block_metadata.round as i64,
+            timestamp: DateTime::from_timestamp(timestamp.seconds, timestamp.nanos as u32)?,
+            proposer: block_metadata.proposer.clone(),
+        })
+    }
+
+    /// Record a block, logging epoch changes and updating the `blocks_per_second` gauge
+    pub fn record(&mut self, metric: &ChainMetric) {
+        if let Some(last_epoch) = self.last_epoch {
+            if metric.epoch > last_epoch {
+                info!("🗓️ Epoch changed {} → {} at version {}", last_epoch, metric.epoch, metric.txn_version);
+            }
+        }
+        self.last_epoch = Some(metric.epoch);
+
+        self.recent_block_timestamps.push_back(metric.timestamp.timestamp_micros());
+        if self.recent_block_timestamps.len() > BLOCK_RATE_WINDOW {
+            self.recent_block_timestamps.pop_front();
+        }
+        if let Some(rate) = self.blocks_per_second() {
+            BLOCKS_PER_SECOND.set(rate);
+        }
+    }
+
+    /// Average block rate over the last `BLOCK_RATE_WINDOW` blocks, or None until two blocks are seen
+    pub fn blocks_per_second(&self) -> Option<f64> {
+        let (first, last) = (self.recent_block_timestamps.front()?, self.recent_block_timestamps.back()?);
+        let elapsed_micros = last - first;
+        if elapsed_micros <= 0 {
+            return None;
+        }
+        let intervals = (self.recent_block_timestamps.len() - 1) as f64;
+        Some(intervals * 1_000_000.0 / elapsed_micros as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(version: i64, epoch: i64, micros: i64) -> ChainMetric {
+        ChainMetric {
+            txn_version: version,
+            epoch,
+            round: version,
+            timestamp: DateTime::from_timestamp_micros(micros).unwrap(),
+            proposer: "0x1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_blocks_per_second_is_rolling() {
+        let mut processor = BlockMetadataProcessor::new();
+        processor.record(&block(1, 10, 1_000_000));
+        assert_eq!(processor.blocks_per_second(), None);
+
+        // Four blocks 250ms apart
+        for (i, version) in (2..=4).enumerate() {
+            processor.record(&block(version, 10, 1_000_000 + 250_000 * (i as i64 + 1)));
+        }
+        assert_eq!(processor.blocks_per_second(), Some(4.0));
+
+        // Only the last BLOCK_RATE_WINDOW blocks count once the window is full
+        for version in 5..(5 + BLOCK_RATE_WINDOW as i64) {
+            processor.record(&block(version, 11, 2_000_000 + 500_000 * (version - 5)));
+        }
+        assert_eq!(processor.blocks_per_second(), Some(2.0));
+    }
+}
@@ -1,13 +1,20 @@
 // Cellana constants
 pub const CELLANA_SWAP_EVENT_TYPE: &str = "0x4bf51972879e3b95c4781a5cdcb9e1ee24ef483e7d22f2d903626f126df62bd1::liquidity_pool::SwapEvent";
 pub const CELLANA_LIQUIDITY_POOL_TYPE: &str = "0x4bf51972879e3b95c4781a5cdcb9e1ee24ef483e7d22f2d903626f126df62bd1::liquidity_pool::LiquidityPool";
+pub const CELLANA_MIGRATE_POOL_EVENT_TYPE: &str = "0x4bf51972879e3b95c4781a5cdcb9e1ee24ef483e7d22f2d903626f126df62bd1::liquidity_pool::MigratePoolEvent";
+pub const CELLANA_POOL_CREATED_EVENT_TYPE: &str = "0x4bf51972879e3b95c4781a5cdcb9e1ee24ef483e7d22f2d903626f126df62bd1::liquidity_pool::PoolCreatedEvent";
 
 // Coin types for Cellana
 pub const APT_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
 pub const USDC_COIN_TYPE: &str = "0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b";
 pub const USDT_COIN_TYPE: &str = "0x357b0b74bc833e95a115ad22604854d6b0fca151cecd94111770e5d6ffc9dc2b";
+pub const ABTC_COIN_TYPE: &str = "0x4e1854f6d332c9525e258fb6e66f84b6af8aba687bbcb832a24768c4e175feec";
 
 // Decimal places
 pub const APT_DECIMALS: u8 = 8;
 pub const USDC_DECIMALS: u8 = 6;
-pub const USDT_DECIMALS: u8 = 6; 
\ No newline at end of file
+pub const USDT_DECIMALS: u8 = 6;
+pub const ABTC_DECIMALS: u8 = 8; // Same as APT, not 6 like the stablecoins
+
+// A wrong value would scale BTC volume by powers of 100
+const _: () = assert!(ABTC_DECIMALS == 8); 
\ No newline at end of file
@@ -1,45 +1,72 @@
 use super::constants::*;
+use crate::{
+    db::common::models::cellana_pool_models::NewCellanaPool,
+    utils::{
+        math::{clamp_precision, is_positive, MAX_VOLUME_SCALE},
+        pool_registry::{PoolInfo, PoolRegistry},
+        protocol_config_loader::ProtocolFeeConfig,
+    },
+};
 use anyhow::Result;
 use aptos_indexer_processor_sdk::{
-    aptos_protos::transaction::v1::{Transaction, WriteSetChange},
+    aptos_protos::transaction::v1::{write_set_change::Change, Transaction, WriteSetChange},
 };
 use bigdecimal::{BigDecimal, Zero, FromPrimitive};
 use serde_json;
 use std::{collections::HashMap, str::FromStr};
-use tracing::{info, debug};
+use tracing::{info, debug, warn};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SwapData {
     pub amount_in: String,
     pub amount_out: String,
     pub from_token: String,
     pub to_token: String,
     pub pool: String,
+    // LP fee; V1 pools only have this one
     pub swap_fee_bps: u32,
+    // Treasury fee charged on top of the LP fee by V2 pools
+    pub protocol_fee_bps: u32,
+}
+
+/// A pool moved to a new address by a Cellana contract upgrade
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolMigration {
+    pub old_pool_address: String,
+    pub new_pool_address: String,
 }
 
 #[derive(Debug)]
 pub struct PoolVolume {
     pub pool: String,
+    pub trade_count: i64,
     pub apt_volume_24h: BigDecimal,
     pub usdc_volume_24h: BigDecimal,
     pub usdt_volume_24h: BigDecimal,
+    pub abtc_volume_24h: BigDecimal,
     pub apt_fee_24h: BigDecimal,
     pub usdc_fee_24h: BigDecimal,
     pub usdt_fee_24h: BigDecimal,
+    // LP and protocol (treasury) fees in USD, valued on the swap's stablecoin side
+    pub lp_fee_24h: BigDecimal,
+    pub protocol_fee_24h: BigDecimal,
     pub apt_buy_volume_24h: BigDecimal,
     pub apt_sell_volume_24h: BigDecimal,
     pub usdc_buy_volume_24h: BigDecimal,
     pub usdc_sell_volume_24h: BigDecimal,
     pub usdt_buy_volume_24h: BigDecimal,
     pub usdt_sell_volume_24h: BigDecimal,
+    pub abtc_buy_volume_24h: BigDecimal,
+    pub abtc_sell_volume_24h: BigDecimal,
 }
 
 // Cached decimal divisors for performance
+#[derive(Clone)]
 struct DecimalDivisors {
     apt: BigDecimal,
     usdc: BigDecimal,
     usdt: BigDecimal,
+    abtc: BigDecimal,
 }
 
 impl DecimalDivisors {
@@ -48,21 +75,36 @@ impl DecimalDivisors {
             apt: BigDecimal::from_u64(10_u64.pow(APT_DECIMALS as u32)).unwrap(),
             usdc: BigDecimal::from_u64(10_u64.pow(USDC_DECIMALS as u32)).unwrap(),
             usdt: BigDecimal::from_u64(10_u64.pow(USDT_DECIMALS as u32)).unwrap(),
+            abtc: BigDecimal::from_u64(10_u64.pow(ABTC_DECIMALS as u32)).unwrap(),
         }
     }
 }
 
+#[derive(Clone)]
 pub struct CellanaProcessor {
     divisors: DecimalDivisors,
+    // Pool address -> fee bps from the protocol_config table
+    fee_overrides: ProtocolFeeConfig,
 }
 
 impl CellanaProcessor {
     pub fn new() -> Self {
         Self {
             divisors: DecimalDivisors::new(),
+            fee_overrides: ProtocolFeeConfig::default(),
         }
     }
 
+    pub fn set_fee_overrides(&mut self, fee_overrides: ProtocolFeeConfig) {
+        self.fee_overrides = fee_overrides;
+    }
+
+    /// Configured fee for a pool, if any. Pools with a fixed fee (e.g. USDT/USDC at 5 bps)
+    /// can be listed here so their WriteResource changes are not parsed for every swap.
+    pub fn check_fee_override(pool_address: &str, config: &ProtocolFeeConfig) -> Option<u32> {
+        config.fee_bps(pool_address)
+    }
+
     pub fn extract_swap_data(&self, event_data: &serde_json::Value) -> Result<SwapData> {
         debug!("🔍 Extracting Cellana swap data from event");
         
@@ -101,14 +143,22 @@ impl CellanaProcessor {
             to_token: to_token.to_string(),
             pool: pool.to_string(),
             swap_fee_bps: 0, // Will be filled from transaction changes
+            protocol_fee_bps: 0,
         })
     }
 
-    pub fn extract_swap_fee_bps(&self, txn: &Transaction, pool_address: &str) -> u32 {
+    /// `(lp_fee_bps, protocol_fee_bps)` for a pool. V1 pools have no protocol fee.
+    pub fn extract_fee_bps(&self, txn: &Transaction, pool_address: &str) -> (u32, u32) {
+        // Fee rates configured at runtime take precedence over the on-chain pool resource
+        if let Some(fee_bps) = Self::check_fee_override(pool_address, &self.fee_overrides) {
+            debug!("🔧 Using configured fee {} bps for pool {}", fee_bps, pool_address);
+            return (fee_bps, 0);
+        }
+
         // Try to get changes from the transaction info field
         let changes = match &txn.info {
             Some(info) => &info.changes,
-            None => return 30, // Default fee for Cellana (0.3%)
+            None => return (30, 0), // Default fee for Cellana (0.3%)
         };
 
         for change in changes {
@@ -118,17 +168,17 @@ impl CellanaProcessor {
             } = change {
                 if resource.address == pool_address && resource.type_str.contains("liquidity_pool::LiquidityPool") {
                     if let Ok(pool_data) = serde_json::from_str::<serde_json::Value>(&resource.data) {
-                        if let Some(swap_fee_bps) = pool_data.get("swap_fee_bps")
+                        let bps_field = |name: &str| pool_data.get(name)
                             .and_then(|v| v.as_str())
-                            .and_then(|v| v.parse::<u32>().ok()) {
-                            debug!("🔧 Found swap_fee_bps: {} for pool {}", swap_fee_bps, pool_address);
-                            return swap_fee_bps;
+                            .and_then(|v| v.parse::<u32>().ok());
+                        let protocol_fee_bps = bps_field("protocol_fee_bps").unwrap_or(0);
+                        if let Some(swap_fee_bps) = bps_field("swap_fee_bps") {
+                            debug!("🔧 Found swap_fee_bps: {} (protocol_fee_bps: {}) for pool {}", swap_fee_bps, protocol_fee_bps, pool_address);
+                            return (swap_fee_bps, protocol_fee_bps);
                         }
-                        if let Some(fee_rate) = pool_data.get("fee_rate")
-                            .and_then(|v| v.as_str())
-                            .and_then(|v| v.parse::<u32>().ok()) {
+                        if let Some(fee_rate) = bps_field("fee_rate") {
                             debug!("🔧 Found fee_rate: {} for pool {}", fee_rate, pool_address);
-                            return fee_rate;
+                            return (fee_rate, protocol_fee_bps);
                         }
                     }
                 }
@@ -136,7 +186,127 @@ impl CellanaProcessor {
         }
 
         debug!("⚠️ No fee_rate found in transaction for pool {}, using default 30 bps", pool_address);
-        30 // Default fee for Cellana (0.3%)
+        (30, 0) // Default fee for Cellana (0.3%)
+    }
+
+    /// Raw `(reserve_x, reserve_y)` from the pool's `LiquidityPool` resource, if the transaction wrote it
+    pub fn extract_pool_reserves(txn: &Transaction, pool_address: &str) -> Option<(BigDecimal, BigDecimal)> {
+        txn.info.as_ref()?.changes.iter().find_map(|change| {
+            let Some(Change::WriteResource(resource)) = &change.change else {
+                return None;
+            };
+            if resource.address != pool_address || !resource.type_str.contains("liquidity_pool::LiquidityPool") {
+                return None;
+            }
+            let pool_data = serde_json::from_str::<serde_json::Value>(&resource.data).ok()?;
+            let reserve = |name: &str| pool_data.get(name)?.as_str().and_then(|v| BigDecimal::from_str(v).ok());
+            Some((reserve("reserve_x")?, reserve("reserve_y")?))
+        })
+    }
+
+    /// Active tick from the pool's `LiquidityPool` resource, if the transaction wrote it and the
+    /// pool has one. Move's `I32` is serialized as `{"bits": <u32>}` in two's complement.
+    pub fn extract_current_tick(txn: &Transaction, pool_address: &str) -> Option<i32> {
+        txn.info.as_ref()?.changes.iter().find_map(|change| {
+            let Some(Change::WriteResource(resource)) = &change.change else {
+                return None;
+            };
+            if resource.address != pool_address || !resource.type_str.contains("liquidity_pool::LiquidityPool") {
+                return None;
+            }
+            let pool_data = serde_json::from_str::<serde_json::Value>(&resource.data).ok()?;
+            let tick = pool_data.get("current_tick")?;
+            match tick.get("bits") {
+                Some(bits) => {
+                    let bits = match bits.as_str() {
+                        Some(bits) => bits.parse::<u32>().ok()?,
+                        None => u32::try_from(bits.as_u64()?).ok()?,
+                    };
+                    Some(bits as i32)
+                },
+                None => match tick.as_str() {
+                    Some(tick) => tick.parse::<i32>().ok(),
+                    None => i32::try_from(tick.as_i64()?).ok(),
+                },
+            }
+        })
+    }
+
+    pub fn is_migrate_pool_event(type_str: &str) -> bool {
+        type_str == CELLANA_MIGRATE_POOL_EVENT_TYPE
+    }
+
+    /// Move `pool_registry`'s entry for the pool in a `MigratePoolEvent` to its new address.
+    /// Returns the migration so stored rows can be moved too.
+    pub fn handle_pool_migration(event_data: &serde_json::Value, pool_registry: &mut PoolRegistry) -> Result<PoolMigration> {
+        let address = |field: &str| -> Result<String> {
+            event_data
+                .get(field)
+                .and_then(|v| v.as_str().or_else(|| v.get("inner")?.as_str()))
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Missing {}", field))
+        };
+        let migration = PoolMigration {
+            old_pool_address: address("old_pool_address")?,
+            new_pool_address: address("new_pool_address")?,
+        };
+
+        warn!(
+            "🚚 Cellana pool migrated: {} → {}",
+            migration.old_pool_address, migration.new_pool_address
+        );
+        if !pool_registry.migrate_pool(&migration.old_pool_address, &migration.new_pool_address) {
+            debug!("Migrated Cellana pool {} was not in the pool registry", migration.old_pool_address);
+        }
+        Ok(migration)
+    }
+
+    pub fn is_pool_created_event(type_str: &str) -> bool {
+        type_str == CELLANA_POOL_CREATED_EVENT_TYPE
+    }
+
+    /// Pool created by a V2 `PoolCreatedEvent`. `created_at_version` is left 0 for the caller to set.
+    pub fn handle_pool_created(event_data: &serde_json::Value) -> Result<NewCellanaPool> {
+        let address = |field: &str| -> Result<String> {
+            event_data
+                .get(field)
+                .and_then(|v| v.as_str().or_else(|| v.get("inner")?.as_str()))
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Missing {}", field))
+        };
+        let fee_bps = event_data
+            .get("fee_bps")
+            .and_then(|v| v.as_str().and_then(|raw| raw.parse::<i32>().ok()).or_else(|| v.as_i64()?.try_into().ok()))
+            .ok_or_else(|| anyhow::anyhow!("Missing fee_bps"))?;
+        let pool = NewCellanaPool {
+            pool_address: address("pool_address")?,
+            token_x: address("token_x")?,
+            token_y: address("token_y")?,
+            fee_bps,
+            created_at_version: 0,
+        };
+
+        info!(
+            "🆕 Cellana pool {} created: {} / {}, fee {} bps",
+            pool.pool_address, pool.token_x, pool.token_y, fee_bps
+        );
+        Ok(pool)
+    }
+
+    /// Map `pool_address` to its pair in `pool_registry` when the pair is one Cellana tracks.
+    /// Returns false for pairs without a canonical name.
+    pub fn register_pool(pool_registry: &mut PoolRegistry, pool_address: &str, token_x: &str, token_y: &str) -> bool {
+        let Some(canonical_pair) = pool_registry.get_pair_name("cellana", token_x, token_y).map(str::to_string) else {
+            debug!("Cellana pool {} has no tracked pair: {} / {}", pool_address, token_x, token_y);
+            return false;
+        };
+        pool_registry.register_pool(pool_address, PoolInfo {
+            protocol: "cellana".to_string(),
+            canonical_pair,
+            token_x: token_x.to_string(),
+            token_y: token_y.to_string(),
+        });
+        true
     }
 
     pub async fn process_swap(&self, pool_volumes: &mut HashMap<String, PoolVolume>, swap_data: SwapData) {
@@ -147,31 +317,56 @@ impl CellanaProcessor {
                 ..Default::default()
             }
         });
+        pool_entry.trade_count += 1;
 
         let raw_amount_in = BigDecimal::from_str(&swap_data.amount_in).unwrap_or_else(|_| BigDecimal::zero());
         let raw_amount_out = BigDecimal::from_str(&swap_data.amount_out).unwrap_or_else(|_| BigDecimal::zero());
-        let fee_rate = BigDecimal::from(swap_data.swap_fee_bps) / BigDecimal::from(10000);
+        // The user pays the LP fee and the protocol fee
+        let fee_rate = BigDecimal::from(swap_data.swap_fee_bps + swap_data.protocol_fee_bps) / BigDecimal::from(10000);
 
         // Process swaps dynamically based on token types instead of hardcoded pool addresses
         match (swap_data.from_token.as_str(), swap_data.to_token.as_str()) {
             // APT/USDC pairs
             (APT_COIN_TYPE, USDC_COIN_TYPE) | (USDC_COIN_TYPE, APT_COIN_TYPE) => {
+                self.record_fee_split(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out);
                 self.process_apt_usdc_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &fee_rate).await;
             }
             // USDT/USDC pairs  
             (USDT_COIN_TYPE, USDC_COIN_TYPE) | (USDC_COIN_TYPE, USDT_COIN_TYPE) => {
+                self.record_fee_split(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out);
                 self.process_usdt_usdc_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &fee_rate).await;
             }
             // APT/USDT pairs
             (APT_COIN_TYPE, USDT_COIN_TYPE) | (USDT_COIN_TYPE, APT_COIN_TYPE) => {
+                self.record_fee_split(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out);
                 self.process_apt_usdt_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &fee_rate).await;
             }
+            // aBTC/APT pairs; no stablecoin side, so no USD fee split
+            (ABTC_COIN_TYPE, APT_COIN_TYPE) | (APT_COIN_TYPE, ABTC_COIN_TYPE) => {
+                self.process_abtc_apt_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &fee_rate).await;
+            }
             // For other token pairs, log and skip for now
             _ => {
                 debug!("🚫 Unsupported Cellana token pair: {} -> {} (pool: {})", 
                     swap_data.from_token, swap_data.to_token, swap_data.pool);
             }
         }
+        pool_entry.clamp_precision();
+    }
+
+    /// Add the swap's LP and protocol fees in USD. Every tracked pair has a USDC or USDT
+    /// side, which is used as the swap's dollar amount.
+    fn record_fee_split(&self, pool_entry: &mut PoolVolume, swap_data: &SwapData, raw_amount_in: &BigDecimal, raw_amount_out: &BigDecimal) {
+        let stable_amount = match (swap_data.from_token.as_str(), swap_data.to_token.as_str()) {
+            (USDC_COIN_TYPE, _) => raw_amount_in / &self.divisors.usdc,
+            (USDT_COIN_TYPE, _) => raw_amount_in / &self.divisors.usdt,
+            (_, USDC_COIN_TYPE) => raw_amount_out / &self.divisors.usdc,
+            (_, USDT_COIN_TYPE) => raw_amount_out / &self.divisors.usdt,
+            _ => return,
+        };
+        let (lp_fee, protocol_fee) = split_fee(&stable_amount, swap_data.swap_fee_bps, swap_data.protocol_fee_bps);
+        pool_entry.lp_fee_24h += lp_fee;
+        pool_entry.protocol_fee_24h += protocol_fee;
     }
 
     async fn process_apt_usdc_swap(
@@ -203,10 +398,11 @@ impl CellanaProcessor {
                 
         } else if swap_data.from_token == USDC_COIN_TYPE && swap_data.to_token == APT_COIN_TYPE {
             // USDC -> APT: User sells USDC, buys APT
+            // amount_in is already net of the fee, which was deducted from the USDC paid in
             let usdc_amount = raw_amount_in / &self.divisors.usdc;
             let apt_amount = raw_amount_out / &self.divisors.apt;
-            let usdc_fee = &usdc_amount * fee_rate;
-            let usdc_net_volume = &usdc_amount - &usdc_fee;
+            let usdc_fee = fee_from_net_amount_in(&usdc_amount, fee_rate);
+            let usdc_net_volume = usdc_amount.clone();
             
             // Update total volumes (for backward compatibility)
             pool_entry.apt_volume_24h += apt_amount.clone();
@@ -317,24 +513,358 @@ impl CellanaProcessor {
                 usdt_amount, apt_amount, usdt_fee, swap_data.swap_fee_bps);
         }
     }
+
+    async fn process_abtc_apt_swap(
+        &self,
+        pool_entry: &mut PoolVolume,
+        swap_data: &SwapData,
+        raw_amount_in: &BigDecimal,
+        raw_amount_out: &BigDecimal,
+        fee_rate: &BigDecimal,
+    ) {
+        if swap_data.from_token == APT_COIN_TYPE && swap_data.to_token == ABTC_COIN_TYPE {
+            // APT -> aBTC: User sells APT, buys aBTC
+            let apt_amount = raw_amount_in / &self.divisors.apt;
+            let abtc_amount = raw_amount_out / &self.divisors.abtc;
+            let apt_fee = &apt_amount * fee_rate;
+            let apt_net_volume = &apt_amount - &apt_fee;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += apt_net_volume.clone();
+            pool_entry.abtc_volume_24h += abtc_amount.clone();
+            pool_entry.apt_fee_24h += apt_fee.clone();
+            
+            // Update buy/sell volumes based on actual transaction direction
+            pool_entry.apt_sell_volume_24h += apt_net_volume.clone(); // APT is being sold
+            pool_entry.abtc_buy_volume_24h += abtc_amount.clone();    // aBTC is being bought
+            
+            info!("📈 Cellana APT->aBTC: {} APT sold, {} aBTC bought, {} APT fee ({}bps)", 
+                apt_amount, abtc_amount, apt_fee, swap_data.swap_fee_bps);
+                
+        } else if swap_data.from_token == ABTC_COIN_TYPE && swap_data.to_token == APT_COIN_TYPE {
+            // aBTC -> APT: User sells aBTC, buys APT. There is no aBTC fee column, so the fee
+            // only reduces the sold volume.
+            let abtc_amount = raw_amount_in / &self.divisors.abtc;
+            let apt_amount = raw_amount_out / &self.divisors.apt;
+            let abtc_fee = &abtc_amount * fee_rate;
+            let abtc_net_volume = &abtc_amount - &abtc_fee;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += apt_amount.clone();
+            pool_entry.abtc_volume_24h += abtc_net_volume.clone();
+            
+            // Update buy/sell volumes based on actual transaction direction
+            pool_entry.abtc_sell_volume_24h += abtc_net_volume.clone(); // aBTC is being sold
+            pool_entry.apt_buy_volume_24h += apt_amount.clone();        // APT is being bought
+            
+            info!("📉 Cellana aBTC->APT: {} aBTC sold, {} APT bought, {} aBTC fee ({}bps)", 
+                abtc_amount, apt_amount, abtc_fee, swap_data.swap_fee_bps);
+        }
+    }
+}
+
+/// Fee charged on an input amount that is reported net of that fee.
+/// With gross = net + fee and fee = gross * rate, fee = net * rate / (1 - rate).
+fn fee_from_net_amount_in(net_amount_in: &BigDecimal, fee_rate: &BigDecimal) -> BigDecimal {
+    let remaining_rate = BigDecimal::from(1) - fee_rate;
+    if !is_positive(&remaining_rate) {
+        return BigDecimal::zero();
+    }
+    net_amount_in * fee_rate / remaining_rate
+}
+
+/// `(amount * lp_fee_bps / 10_000, amount * protocol_fee_bps / 10_000)`
+fn split_fee(amount: &BigDecimal, lp_fee_bps: u32, protocol_fee_bps: u32) -> (BigDecimal, BigDecimal) {
+    let bps = BigDecimal::from(10_000);
+    (
+        amount * BigDecimal::from(lp_fee_bps) / &bps,
+        amount * BigDecimal::from(protocol_fee_bps) / &bps,
+    )
 }
 
 impl Default for PoolVolume {
     fn default() -> Self {
         Self {
             pool: String::new(),
+            trade_count: 0,
             apt_volume_24h: BigDecimal::from(0),
             usdc_volume_24h: BigDecimal::from(0),
             usdt_volume_24h: BigDecimal::from(0),
+            abtc_volume_24h: BigDecimal::from(0),
             apt_fee_24h: BigDecimal::from(0),
             usdc_fee_24h: BigDecimal::from(0),
             usdt_fee_24h: BigDecimal::from(0),
+            lp_fee_24h: BigDecimal::from(0),
+            protocol_fee_24h: BigDecimal::from(0),
             apt_buy_volume_24h: BigDecimal::from(0),
             apt_sell_volume_24h: BigDecimal::from(0),
             usdc_buy_volume_24h: BigDecimal::from(0),
             usdc_sell_volume_24h: BigDecimal::from(0),
             usdt_buy_volume_24h: BigDecimal::from(0),
             usdt_sell_volume_24h: BigDecimal::from(0),
+            abtc_buy_volume_24h: BigDecimal::from(0),
+            abtc_sell_volume_24h: BigDecimal::from(0),
         }
     }
-} 
\ No newline at end of file
+}
+
+impl PoolVolume {
+    /// Round every accumulated amount to `MAX_VOLUME_SCALE` decimal places
+    pub fn clamp_precision(&mut self) {
+        for amount in [
+            &mut self.apt_volume_24h,
+            &mut self.usdc_volume_24h,
+            &mut self.usdt_volume_24h,
+            &mut self.abtc_volume_24h,
+            &mut self.apt_fee_24h,
+            &mut self.usdc_fee_24h,
+            &mut self.usdt_fee_24h,
+            &mut self.lp_fee_24h,
+            &mut self.protocol_fee_24h,
+            &mut self.apt_buy_volume_24h,
+            &mut self.apt_sell_volume_24h,
+            &mut self.usdc_buy_volume_24h,
+            &mut self.usdc_sell_volume_24h,
+            &mut self.usdt_buy_volume_24h,
+            &mut self.usdt_sell_volume_24h,
+            &mut self.abtc_buy_volume_24h,
+            &mut self.abtc_sell_volume_24h,
+        ] {
+            *amount = clamp_precision(std::mem::take(amount), MAX_VOLUME_SCALE);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_from_net_amount_in() {
+        // USDC -> APT swap of 642 USDC (amount_in = 642_000000) in a 30 bps pool
+        let fee_rate = BigDecimal::from(30) / BigDecimal::from(10000);
+        let usdc_amount = BigDecimal::from(642_000_000u64) / BigDecimal::from(1_000_000u64);
+
+        // Previous formula treated amount_in as gross and undercounted the fee
+        let previous_fee = &usdc_amount * &fee_rate;
+        let corrected_fee = fee_from_net_amount_in(&usdc_amount, &fee_rate);
+        assert_eq!(previous_fee, BigDecimal::from_str("1.926").unwrap());
+        assert_eq!(corrected_fee.round(8), BigDecimal::from_str("1.93179539").unwrap());
+        assert!(corrected_fee > previous_fee);
+
+        // The corrected fee is exactly the fee rate applied to the gross amount paid in
+        let gross = &usdc_amount + &corrected_fee;
+        assert_eq!((&gross * &fee_rate).round(12), corrected_fee.round(12));
+
+        // No fee configured -> no fee
+        assert_eq!(fee_from_net_amount_in(&usdc_amount, &BigDecimal::zero()), BigDecimal::zero());
+    }
+
+    #[test]
+    fn test_fee_override_skips_write_resources() {
+        let mut processor = CellanaProcessor::new();
+        let config = ProtocolFeeConfig::new(HashMap::from([("0xstable".to_string(), 5)]));
+        assert_eq!(CellanaProcessor::check_fee_override("0xstable", &config), Some(5));
+        assert_eq!(CellanaProcessor::check_fee_override("0xother", &config), None);
+
+        // The transaction has no changes at all, so 5 bps can only come from the override
+        processor.set_fee_overrides(config);
+        let txn = Transaction::default();
+        assert_eq!(processor.extract_fee_bps(&txn, "0xstable"), (5, 0));
+        assert_eq!(processor.extract_fee_bps(&txn, "0xother"), (30, 0));
+    }
+
+    #[tokio::test]
+    async fn test_cellana_fee_accounting() {
+        // 10 APT -> 64.2 USDC in a 30 bps pool; the fee is taken from the APT paid in
+        let processor = CellanaProcessor::new();
+        let swap_data = SwapData {
+            amount_in: "1000000000".to_string(),
+            amount_out: "64200000".to_string(),
+            from_token: APT_COIN_TYPE.to_string(),
+            to_token: USDC_COIN_TYPE.to_string(),
+            pool: "0xpool".to_string(),
+            swap_fee_bps: 30,
+            protocol_fee_bps: 0,
+        };
+        let fee_rate = BigDecimal::from(30) / BigDecimal::from(10000);
+        let mut pool_entry = PoolVolume::default();
+
+        processor
+            .process_apt_usdc_swap(
+                &mut pool_entry,
+                &swap_data,
+                &BigDecimal::from(1_000_000_000u64),
+                &BigDecimal::from(64_200_000u64),
+                &fee_rate,
+            )
+            .await;
+
+        // Expected: fee = 10 * 0.003 = 0.03 APT, net = 10 - 0.03 = 9.97 APT
+        assert_eq!(pool_entry.apt_fee_24h, BigDecimal::from_str("0.03").unwrap());
+        assert_eq!(pool_entry.apt_volume_24h, BigDecimal::from_str("9.97").unwrap());
+        assert_eq!(pool_entry.apt_sell_volume_24h, BigDecimal::from_str("9.97").unwrap());
+        assert_eq!(pool_entry.usdc_volume_24h, BigDecimal::from_str("64.2").unwrap());
+        assert_eq!(pool_entry.usdc_buy_volume_24h, BigDecimal::from_str("64.2").unwrap());
+        assert_eq!(pool_entry.usdc_fee_24h, BigDecimal::zero());
+    }
+
+    #[tokio::test]
+    async fn test_abtc_apt_swaps_track_btc_buy_and_sell() {
+        let processor = CellanaProcessor::new();
+        let mut pool_volumes = HashMap::new();
+        let swap = |amount_in: &str, amount_out: &str, from_token: &str, to_token: &str| SwapData {
+            amount_in: amount_in.to_string(),
+            amount_out: amount_out.to_string(),
+            from_token: from_token.to_string(),
+            to_token: to_token.to_string(),
+            pool: "0xab7c".to_string(),
+            swap_fee_bps: 0,
+            protocol_fee_bps: 0,
+        };
+
+        // 1000 APT for 0.05 aBTC, then 0.02 aBTC for 400 APT
+        processor.process_swap(&mut pool_volumes, swap("100000000000", "5000000", APT_COIN_TYPE, ABTC_COIN_TYPE)).await;
+        processor.process_swap(&mut pool_volumes, swap("2000000", "40000000000", ABTC_COIN_TYPE, APT_COIN_TYPE)).await;
+
+        let pool = &pool_volumes["0xab7c"];
+        assert_eq!(pool.abtc_buy_volume_24h, BigDecimal::from_str("0.05").unwrap());
+        assert_eq!(pool.abtc_sell_volume_24h, BigDecimal::from_str("0.02").unwrap());
+        assert_eq!(pool.abtc_volume_24h, BigDecimal::from_str("0.07").unwrap());
+        assert_eq!(pool.apt_sell_volume_24h, BigDecimal::from(1000));
+        assert_eq!(pool.apt_buy_volume_24h, BigDecimal::from(400));
+    }
+
+    /// Checks ABTC_DECIMALS against the fungible asset metadata on chain. Run it with `--ignored`
+    /// and APTOS_NODE_URL pointing at a fullnode REST API (e.g. https://fullnode.mainnet.aptoslabs.com/v1).
+    #[tokio::test]
+    #[ignore = "needs network access and APTOS_NODE_URL set to a fullnode REST API"]
+    async fn test_abtc_decimals_match_chain() {
+        let node_url = std::env::var("APTOS_NODE_URL").expect("APTOS_NODE_URL must be set for the on-chain decimals check");
+
+        let url = format!(
+            "{}/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            node_url.trim_end_matches('/'),
+            ABTC_COIN_TYPE
+        );
+        let resource: serde_json::Value = reqwest::get(&url).await.unwrap().json().await.unwrap();
+        let decimals = resource["data"]["decimals"].as_u64().expect("metadata has decimals");
+
+        assert_eq!(decimals, ABTC_DECIMALS as u64, "ABTC_DECIMALS does not match on-chain metadata");
+    }
+
+    #[test]
+    fn test_v2_fees_are_split_into_lp_and_protocol() {
+        // 1,000 USDC swapped through a V2 pool with a 25 bps LP fee and a 5 bps protocol fee
+        let (lp_fee, protocol_fee) = split_fee(&BigDecimal::from(1000), 25, 5);
+        assert_eq!(lp_fee, BigDecimal::from_str("2.5").unwrap());
+        assert_eq!(protocol_fee, BigDecimal::from_str("0.5").unwrap());
+
+        // V1 pools have no protocol fee
+        let (_, protocol_fee) = split_fee(&BigDecimal::from(1000), 30, 0);
+        assert!(protocol_fee.is_zero());
+    }
+
+    #[test]
+    fn test_pool_reserves_from_liquidity_pool_resource() {
+        use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{TransactionInfo, WriteResource};
+
+        let write_resource = |address: &str| WriteSetChange {
+            change: Some(Change::WriteResource(WriteResource {
+                address: address.to_string(),
+                type_str: CELLANA_LIQUIDITY_POOL_TYPE.to_string(),
+                data: r#"{"reserve_x":"150000000000","reserve_y":"963000000","swap_fee_bps":"30"}"#.to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let txn = Transaction {
+            info: Some(TransactionInfo {
+                changes: vec![write_resource("0xother"), write_resource("0xpool")],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            CellanaProcessor::extract_pool_reserves(&txn, "0xpool"),
+            Some((BigDecimal::from(150_000_000_000u64), BigDecimal::from(963_000_000u64)))
+        );
+        assert_eq!(CellanaProcessor::extract_pool_reserves(&txn, "0xmissing"), None);
+    }
+
+    #[test]
+    fn test_current_tick_from_liquidity_pool_resource() {
+        use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{TransactionInfo, WriteResource};
+
+        let txn = |data: &str| Transaction {
+            info: Some(TransactionInfo {
+                changes: vec![WriteSetChange {
+                    change: Some(Change::WriteResource(WriteResource {
+                        address: "0xpool".to_string(),
+                        type_str: CELLANA_LIQUIDITY_POOL_TYPE.to_string(),
+                        data: data.to_string(),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // -10 as a Move I32
+        let v3_pool = txn(r#"{"reserve_x":"1","reserve_y":"1","current_tick":{"bits":"4294967286"}}"#);
+        assert_eq!(CellanaProcessor::extract_current_tick(&v3_pool, "0xpool"), Some(-10));
+        assert_eq!(CellanaProcessor::extract_current_tick(&txn(r#"{"current_tick":"887272"}"#), "0xpool"), Some(887272));
+
+        // Constant-product pools have no tick
+        let v2_pool = txn(r#"{"reserve_x":"1","reserve_y":"1"}"#);
+        assert_eq!(CellanaProcessor::extract_current_tick(&v2_pool, "0xpool"), None);
+    }
+
+    #[test]
+    fn test_pool_migration_moves_registry_entry() {
+        const OLD_POOL: &str = "0x71c6ae634bd3c36470eb7e7f4fb0912973bb31543dfdb7d7fb6863d886d81d67";
+        const NEW_POOL: &str = "0x9a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+        let mut registry = PoolRegistry::default();
+        registry.register_pool(OLD_POOL, PoolInfo {
+            protocol: "cellana".to_string(),
+            canonical_pair: "APT/USDC".to_string(),
+            token_x: APT_COIN_TYPE.to_string(),
+            token_y: USDC_COIN_TYPE.to_string(),
+        });
+
+        let event_data = serde_json::json!({ "old_pool_address": OLD_POOL, "new_pool_address": { "inner": NEW_POOL } });
+        let migration = CellanaProcessor::handle_pool_migration(&event_data, &mut registry).unwrap();
+
+        assert_eq!(migration.new_pool_address, NEW_POOL);
+        assert_eq!(registry.get_pair_name_by_address(NEW_POOL), Some("APT/USDC"));
+        assert_eq!(registry.get_by_address(OLD_POOL), None);
+        assert!(CellanaProcessor::handle_pool_migration(&serde_json::json!({}), &mut registry).is_err());
+    }
+
+    #[test]
+    fn test_pool_created_registers_tracked_pair() {
+        const POOL: &str = "0x5c3f1e2d4b6a79880a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f6071";
+        let event_data = serde_json::json!({
+            "pool_address": { "inner": POOL },
+            "token_x": APT_COIN_TYPE,
+            "token_y": { "inner": USDT_COIN_TYPE },
+            "fee_bps": "5",
+        });
+        assert!(CellanaProcessor::is_pool_created_event(CELLANA_POOL_CREATED_EVENT_TYPE));
+
+        let pool = CellanaProcessor::handle_pool_created(&event_data).unwrap();
+        assert_eq!(pool.pool_address, POOL);
+        assert_eq!((pool.token_x.as_str(), pool.token_y.as_str(), pool.fee_bps), (APT_COIN_TYPE, USDT_COIN_TYPE, 5));
+
+        let mut registry = PoolRegistry::global().clone();
+        assert!(CellanaProcessor::register_pool(&mut registry, &pool.pool_address, &pool.token_x, &pool.token_y));
+        assert_eq!(registry.get_pair_name_by_address(POOL), Some("APT/USDT"));
+
+        // Untracked pairs are stored but never named
+        assert!(!CellanaProcessor::register_pool(&mut registry, "0xother", APT_COIN_TYPE, "0xunknown"));
+        assert!(CellanaProcessor::handle_pool_created(&serde_json::json!({ "pool_address": POOL })).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{transaction::TxnData, Transaction};
+use std::time::Duration;
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    task::JoinHandle,
+};
+use tracing::{debug, info, warn};
+
+use crate::utils::metrics::CHAIN_STALLED;
+
+/// Checkpoint events buffered for the monitor before the oldest are dropped
+pub const CHECKPOINT_CHANNEL_CAPACITY: usize = 64;
+/// State checkpoints arrive every ~20 blocks, so this long without one means the chain or stream stalled
+pub const CHECKPOINT_STALL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A state checkpoint transaction, used as a chain heartbeat
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckpointEvent {
+    pub txn_version: u64,
+    pub txn_timestamp: i64,
+    pub state_change_hash: String,
+}
+
+impl CheckpointEvent {
+    /// Checkpoint event for `txn` if it is a state checkpoint transaction
+    pub fn from_transaction(txn: &Transaction) -> Option<Self> {
+        let Some(TxnData::StateCheckpoint(_)) = &txn.txn_data else {
+            return None;
+        };
+        let state_change_hash = txn.info.as_ref().map_or_else(String::new, |info| {
+            let hex: String = info.state_change_hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+            format!("0x{}", hex)
+        });
+
+        Some(Self {
+            txn_version: txn.version,
+            txn_timestamp: txn.timestamp.as_ref().map_or(0, |ts| ts.seconds),
+            state_change_hash,
+        })
+    }
+}
+
+/// Watch checkpoint events and set the `chain_stalled` gauge while none has arrived for
+/// `CHECKPOINT_STALL_TIMEOUT`
+pub fn spawn_checkpoint_monitor(mut receiver: broadcast::Receiver<CheckpointEvent>) -> JoinHandle<()> {
+    info!("💓 Watching state checkpoints, alerting after {}s without one", CHECKPOINT_STALL_TIMEOUT.as_secs());
+
+    tokio::spawn(async move {
+        CHAIN_STALLED.set(0);
+        loop {
+            match tokio::time::timeout(CHECKPOINT_STALL_TIMEOUT, receiver.recv()).await {
+                Ok(Ok(event)) => {
+                    CHAIN_STALLED.set(0);
+                    debug!("💓 State checkpoint at version {} ({})", event.txn_version, event.state_change_hash);
+                },
+                // Falling behind still means checkpoints are arriving
+                Ok(Err(RecvError::Lagged(skipped))) => {
+                    CHAIN_STALLED.set(0);
+                    debug!("💓 Checkpoint monitor skipped {} events", skipped);
+                },
+                Ok(Err(RecvError::Closed)) => {
+                    info!("🛑 Checkpoint channel closed, stopping checkpoint monitor");
+                    return;
+                },
+                Err(_) => {
+                    if CHAIN_STALLED.get() == 0 {
+                        warn!("🚨 No state checkpoint for {}s, chain or stream may be stalled", CHECKPOINT_STALL_TIMEOUT.as_secs());
+                    }
+                    CHAIN_STALLED.set(1);
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_indexer_processor_sdk::aptos_protos::{
+        transaction::v1::{StateCheckpointTransaction, TransactionInfo},
+        util::timestamp::Timestamp,
+    };
+
+    #[test]
+    fn test_checkpoint_event_from_state_checkpoint() {
+        let txn = Transaction {
+            version: 42,
+            timestamp: Some(Timestamp { seconds: 1_700_000_000, nanos: 0 }),
+            info: Some(TransactionInfo {
+                state_change_hash: vec![0xab, 0x01],
+                ..Default::default()
+            }),
+            txn_data: Some(TxnData::StateCheckpoint(StateCheckpointTransaction {})),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            CheckpointEvent::from_transaction(&txn),
+            Some(CheckpointEvent {
+                txn_version: 42,
+                txn_timestamp: 1_700_000_000,
+                state_change_hash: "0xab01".to_string(),
+            })
+        );
+        assert_eq!(CheckpointEvent::from_transaction(&Transaction::default()), None);
+    }
+}
@@ -0,0 +1,107 @@
+use lru::LruCache;
+use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+
+use super::cellana::processor::SwapData as CellanaSwapData;
+use super::hyperion::processor::SwapData as HyperionSwapData;
+use super::liquidswap::processor::LiquidSwapData;
+use super::sushiswap::processor::SushiSwapData;
+use super::thala::processor::SwapData as ThalaSwapData;
+use crate::utils::metrics::EVENT_CACHE_HIT_RATE;
+
+/// Number of decoded events kept when no capacity is configured
+pub const DEFAULT_EVENT_CACHE_CAPACITY: usize = 10_000;
+
+/// Swap data decoded from one event, before any per-transaction details are filled in
+#[derive(Debug, Clone)]
+pub enum ProcessedEventResult {
+    Cellana(CellanaSwapData),
+    Thala(ThalaSwapData),
+    SushiSwap(SushiSwapData),
+    LiquidSwap(LiquidSwapData),
+    Hyperion(HyperionSwapData),
+}
+
+/// EventCache remembers recently decoded event payloads so identical payloads emitted by
+/// batch routers are only decoded once
+pub struct EventCache {
+    entries: LruCache<[u8; 32], ProcessedEventResult>,
+    hits: u64,
+    lookups: u64,
+}
+
+impl Default for EventCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_CACHE_CAPACITY)
+    }
+}
+
+impl EventCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+            hits: 0,
+            lookups: 0,
+        }
+    }
+
+    /// Key for an event payload. The type string is included because LiquidSwap and
+    /// SushiSwap take the coin types from it rather than from the data.
+    pub fn key(type_str: &str, data: &str) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(type_str.as_bytes());
+        hasher.update([0]);
+        hasher.update(data.as_bytes());
+        hasher.finalize().into()
+    }
+
+    pub fn get(&mut self, key: &[u8; 32]) -> Option<ProcessedEventResult> {
+        self.lookups += 1;
+        let cached = self.entries.get(key).cloned();
+        if cached.is_some() {
+            self.hits += 1;
+        }
+        EVENT_CACHE_HIT_RATE.set(self.hit_rate());
+        cached
+    }
+
+    pub fn insert(&mut self, key: [u8; 32], result: ProcessedEventResult) {
+        self.entries.put(key, result);
+    }
+
+    /// Share of lookups answered from the cache since startup
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.lookups as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_payload_hits_and_type_str_is_part_of_key() {
+        let mut cache = EventCache::new(2);
+        let data = r#"{"x_in":"100","x_out":"0","y_in":"0","y_out":"5"}"#;
+        let key = EventCache::key("0xabc::liquidity_pool::SwapEvent<A, B>", data);
+
+        assert!(cache.get(&key).is_none());
+        cache.insert(key, ProcessedEventResult::LiquidSwap(LiquidSwapData {
+            x_in: "100".to_string(),
+            x_out: "0".to_string(),
+            y_in: "0".to_string(),
+            y_out: "5".to_string(),
+            token_x: "A".to_string(),
+            token_y: "B".to_string(),
+            fee: None,
+            stable: false,
+        }));
+
+        assert!(matches!(cache.get(&key), Some(ProcessedEventResult::LiquidSwap(_))));
+        assert_ne!(key, EventCache::key("0xabc::liquidity_pool::SwapEvent<B, A>", data));
+        assert_eq!(cache.hit_rate(), 0.5);
+    }
+}
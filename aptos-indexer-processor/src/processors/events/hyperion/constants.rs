@@ -1,5 +1,9 @@
 // Hyperion constants
 pub const HYPERION_SWAP_EVENT_TYPE: &str = "0x8b4a2c4bb53857c718a04c020b98f8c2e1f99a68b0f57389a8bf5434cd22e05c::pool_v3::SwapEventV3";
+pub const HYPERION_INITIALIZE_POOL_EVENT_TYPE: &str = "0x8b4a2c4bb53857c718a04c020b98f8c2e1f99a68b0f57389a8bf5434cd22e05c::pool_v3::InitializePoolEvent";
+
+// 2^128, the scale of Q128.128 fixed-point values such as fee_growth_global_x128
+pub const Q128: &str = "340282366920938463463374607431768211456";
 
 // Coin types for Hyperion
 pub const APT_COIN_TYPE: &str = "0xa";
@@ -1,11 +1,15 @@
 use super::constants::*;
+use crate::{
+    db::common::models::hyperion_pool_models::NewHyperionPool,
+    utils::math::{clamp_precision, MAX_VOLUME_SCALE},
+};
 use anyhow::Result;
 use bigdecimal::{BigDecimal, Zero, FromPrimitive};
 use serde_json;
 use std::{collections::HashMap, str::FromStr};
 use tracing::{info, debug};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SwapData {
     pub amount_in: String,
     pub amount_out: String,
@@ -18,6 +22,7 @@ pub struct SwapData {
 #[derive(Debug)]
 pub struct PoolVolume {
     pub pool: String,
+    pub trade_count: i64,
     pub apt_volume_24h: BigDecimal,
     pub usdc_volume_24h: BigDecimal,
     pub usdt_volume_24h: BigDecimal,
@@ -33,6 +38,7 @@ pub struct PoolVolume {
 }
 
 // Cached decimal divisors for performance
+#[derive(Clone)]
 struct DecimalDivisors {
     apt: BigDecimal,
     usdc: BigDecimal,
@@ -53,6 +59,7 @@ impl Default for PoolVolume {
     fn default() -> Self {
         Self {
             pool: String::new(),
+            trade_count: 0,
             apt_volume_24h: BigDecimal::zero(),
             usdc_volume_24h: BigDecimal::zero(),
             usdt_volume_24h: BigDecimal::zero(),
@@ -69,18 +76,123 @@ impl Default for PoolVolume {
     }
 }
 
+impl PoolVolume {
+    /// Round every accumulated amount to `MAX_VOLUME_SCALE` decimal places
+    pub fn clamp_precision(&mut self) {
+        for amount in [
+            &mut self.apt_volume_24h,
+            &mut self.usdc_volume_24h,
+            &mut self.usdt_volume_24h,
+            &mut self.apt_fee_24h,
+            &mut self.usdc_fee_24h,
+            &mut self.usdt_fee_24h,
+            &mut self.apt_buy_volume_24h,
+            &mut self.apt_sell_volume_24h,
+            &mut self.usdc_buy_volume_24h,
+            &mut self.usdc_sell_volume_24h,
+            &mut self.usdt_buy_volume_24h,
+            &mut self.usdt_sell_volume_24h,
+        ] {
+            *amount = clamp_precision(std::mem::take(amount), MAX_VOLUME_SCALE);
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct HyperionProcessor {
     divisors: DecimalDivisors,
+    // Token pairs of pools seen in InitializePoolEvents or loaded from hyperion_pools, keyed by pool address
+    registered_pools: HashMap<String, (String, String)>,
+}
+
+/// Address held by `field`, either directly or as an `{ "inner": ... }` object
+fn address_field<'a>(event_data: &'a serde_json::Value, field: &str) -> Option<&'a str> {
+    let value = event_data.get(field)?;
+    value.as_str().or_else(|| value.get("inner")?.as_str())
 }
 
 impl HyperionProcessor {
     pub fn new() -> Self {
         Self {
             divisors: DecimalDivisors::new(),
+            registered_pools: HashMap::new(),
+        }
+    }
+
+    pub fn is_initialize_pool_event(type_str: &str) -> bool {
+        type_str.split('<').next() == Some(HYPERION_INITIALIZE_POOL_EVENT_TYPE)
+    }
+
+    /// Pool created by an `InitializePoolEvent`. `created_at_version` is left 0 for the caller to set.
+    pub fn handle_initialize_pool(event_data: &serde_json::Value) -> Result<NewHyperionPool> {
+        let pool_address = address_field(event_data, "pool")
+            .or_else(|| address_field(event_data, "pool_id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing pool"))?;
+        let token0 = address_field(event_data, "token0").ok_or_else(|| anyhow::anyhow!("Missing token0"))?;
+        let token1 = address_field(event_data, "token1").ok_or_else(|| anyhow::anyhow!("Missing token1"))?;
+        let fee_tier = event_data
+            .get("fee_tier")
+            .and_then(|v| v.as_str().and_then(|raw| raw.parse::<i32>().ok()).or_else(|| v.as_i64()?.try_into().ok()))
+            .ok_or_else(|| anyhow::anyhow!("Missing fee_tier"))?;
+
+        info!(
+            "🆕 Hyperion pool {} initialized: {} / {}, fee tier {}, initial sqrt price {}",
+            pool_address,
+            token0,
+            token1,
+            fee_tier,
+            event_data.get("initial_sqrt_price").map_or("?".to_string(), |v| v.to_string())
+        );
+
+        Ok(NewHyperionPool {
+            pool_address: pool_address.to_string(),
+            token0: token0.to_string(),
+            token1: token1.to_string(),
+            fee_tier,
+            created_at_version: 0,
+        })
+    }
+
+    /// Accept swaps for `pool`'s token pair from now on
+    pub fn register_pool(&mut self, pool_address: &str, token0: &str, token1: &str) {
+        self.registered_pools
+            .insert(pool_address.to_string(), (token0.to_string(), token1.to_string()));
+    }
+
+    /// Both tokens are tracked coins, or some registered pool trades this pair
+    pub fn is_supported_pair(&self, token0: &str, token1: &str) -> bool {
+        let tracked = [APT_COIN_TYPE, USDC_COIN_TYPE, USDT_COIN_TYPE];
+        if tracked.contains(&token0) && tracked.contains(&token1) {
+            return true;
+        }
+        self.registered_pools
+            .values()
+            .any(|(a, b)| (a == token0 && b == token1) || (a == token1 && b == token0))
+    }
+
+    /// Matches V3 swap events with or without generic parameters
+    pub fn is_hyperion_event(&self, type_str: &str) -> bool {
+        type_str.split('<').next() == Some(HYPERION_SWAP_EVENT_TYPE)
+    }
+
+    /// Extract the pool address from the first generic parameter of a Hyperion V3 event type_str
+    /// Example: "0x8b4a...e05c::pool_v3::SwapEventV3<0x925660b8618394809f89f8002e2926600c775221f43bf1919782b297a79400d8, ...>"
+    pub fn extract_pool_from_type_str(type_str: &str) -> Option<String> {
+        let start = type_str.find('<')?;
+        let end = type_str.rfind('>')?;
+        if end <= start {
+            return None;
+        }
+
+        let pool = type_str[start + 1..end].split(',').next()?.trim();
+        if pool.starts_with("0x") && !pool.contains("::") {
+            Some(pool.to_string())
+        } else {
+            None
         }
     }
 
-    pub fn extract_swap_data(&self, event_data: &serde_json::Value) -> Result<SwapData> {
+    pub fn extract_swap_data(&self, event_data: &serde_json::Value, type_str: &str) -> Result<SwapData> {
         debug!("🔍 Extracting Hyperion swap data from event");
         
         let amount_in = event_data
@@ -93,22 +205,44 @@ impl HyperionProcessor {
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing amount_out"))?;
             
-        let from_token = event_data
-            .get("from_token")
-            .and_then(|obj| obj.get("inner"))
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow::anyhow!("Missing from_token.inner"))?;
-            
-        let to_token = event_data
-            .get("to_token")
-            .and_then(|obj| obj.get("inner"))
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow::anyhow!("Missing to_token.inner"))?;
+        // Prefer the pool address from the type string; older events only carry it in pool_id
+        let pool_id = match Self::extract_pool_from_type_str(type_str) {
+            Some(pool) => pool,
+            None => event_data
+                .get("pool_id")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Missing pool_id"))?
+                .to_string(),
+        };
 
-        let pool_id = event_data
-            .get("pool_id")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow::anyhow!("Missing pool_id"))?;
+        let token = |field: &str| event_data.get(field).and_then(|obj| obj.get("inner")).and_then(|v| v.as_str());
+        let zero_for_one = event_data
+            .get("zero_for_one")
+            .and_then(|v| v.as_bool().or_else(|| v.as_str()?.parse().ok()));
+
+        // The explicit direction flag wins over from_token/to_token when the pool's tokens are known
+        let (amount_in, from_token, amount_out, to_token) = match (zero_for_one, self.registered_pools.get(&pool_id)) {
+            (Some(zero_for_one), Some((token0, token1))) => {
+                let (in_amount, in_token, out_amount, out_token) = Self::apply_direction(
+                    zero_for_one,
+                    BigDecimal::from_str(amount_in)?,
+                    BigDecimal::from_str(amount_out)?,
+                    token0,
+                    token1,
+                );
+                if let Some(from_token) = token("from_token").filter(|from_token| *from_token != in_token) {
+                    debug!("🔀 Hyperion swap in pool {} has from_token {} but zero_for_one={}; using {}",
+                        pool_id, from_token, zero_for_one, in_token);
+                }
+                (in_amount.to_string(), in_token, out_amount.to_string(), out_token)
+            },
+            _ => (
+                amount_in.to_string(),
+                token("from_token").ok_or_else(|| anyhow::anyhow!("Missing from_token.inner"))?.to_string(),
+                amount_out.to_string(),
+                token("to_token").ok_or_else(|| anyhow::anyhow!("Missing to_token.inner"))?.to_string(),
+            ),
+        };
 
         let protocol_fee_amount = event_data
             .get("protocol_fee_amount")
@@ -119,26 +253,62 @@ impl HyperionProcessor {
             amount_in, from_token, amount_out, to_token, pool_id, protocol_fee_amount);
 
         Ok(SwapData {
-            amount_in: amount_in.to_string(),
-            amount_out: amount_out.to_string(),
-            from_token: from_token.to_string(),
-            to_token: to_token.to_string(),
-            pool_id: pool_id.to_string(),
+            amount_in,
+            amount_out,
+            from_token,
+            to_token,
+            pool_id,
             protocol_fee_amount: protocol_fee_amount.to_string(),
         })
     }
 
+    /// `(in_amount, in_token, out_amount, out_token)` of a swap in a token0/token1 pool.
+    /// `zero_for_one` swaps pay in token0 and receive token1, like Uniswap V3.
+    pub fn apply_direction(
+        zero_for_one: bool,
+        amount_in: BigDecimal,
+        amount_out: BigDecimal,
+        token0: &str,
+        token1: &str,
+    ) -> (BigDecimal, String, BigDecimal, String) {
+        let (in_token, out_token) = if zero_for_one { (token0, token1) } else { (token1, token0) };
+        (amount_in, in_token.to_string(), amount_out, out_token.to_string())
+    }
+
+    /// Fee growth deltas `(x, y)` of a swap, converted from Q128.128 by dividing by 2^128.
+    /// These are the fees credited per unit of in-range liquidity, shared by all LPs of the pool.
+    pub fn extract_fee_growth_delta(event_data: &serde_json::Value) -> Option<(BigDecimal, BigDecimal)> {
+        let parse = |field: &str| -> Option<BigDecimal> {
+            let value = event_data.get(field)?;
+            let raw = match value.as_str() {
+                Some(raw) => BigDecimal::from_str(raw).ok()?,
+                None => BigDecimal::from_u64(value.as_u64()?)?,
+            };
+            Some(raw / BigDecimal::from_str(Q128).unwrap())
+        };
+
+        Some((
+            parse("fee_growth_global_x128_delta_x")?,
+            parse("fee_growth_global_x128_delta_y")?,
+        ))
+    }
+
     pub async fn process_swap(&self, pool_volumes: &mut HashMap<String, PoolVolume>, swap_data: SwapData) {
         debug!("🔄 Processing Hyperion swap for pool: {}", swap_data.pool_id);
 
-        // Process all Hyperion swaps (removed target pool filter)
-        
+        // Tracked coins, plus the pairs of pools registered from InitializePoolEvents
+        if !self.is_supported_pair(&swap_data.from_token, &swap_data.to_token) {
+            debug!("🚫 Unsupported token pair: {} -> {}", swap_data.from_token, swap_data.to_token);
+            return;
+        }
+
         // Get or create pool entry
         let pool_entry = pool_volumes.entry(swap_data.pool_id.clone()).or_insert_with(|| {
             let mut volume = PoolVolume::default();
             volume.pool = swap_data.pool_id.clone();
             volume
         });
+        pool_entry.trade_count += 1;
 
         // Parse amounts
         let raw_amount_in = BigDecimal::from_str(&swap_data.amount_in).unwrap_or_else(|_| BigDecimal::zero());
@@ -272,9 +442,108 @@ impl HyperionProcessor {
                 return;
             }
         }
+        pool_entry.clamp_precision();
 
         info!("📊 Hyperion {} volume updated: APT={}, USDC={}, USDT={}, APT_fee={}, USDC_fee={}, USDT_fee={}", 
             pool_entry.pool, pool_entry.apt_volume_24h, pool_entry.usdc_volume_24h, pool_entry.usdt_volume_24h, 
             pool_entry.apt_fee_24h, pool_entry.usdc_fee_24h, pool_entry.usdt_fee_24h);
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL: &str = "0x925660b8618394809f89f8002e2926600c775221f43bf1919782b297a79400d8";
+
+    fn event_data(pool_id: Option<&str>) -> serde_json::Value {
+        let mut data = serde_json::json!({
+            "amount_in": "100000000",
+            "amount_out": "5230000",
+            "from_token": { "inner": APT_COIN_TYPE },
+            "to_token": { "inner": USDC_COIN_TYPE },
+            "protocol_fee_amount": "3000",
+        });
+        if let Some(pool_id) = pool_id {
+            data["pool_id"] = serde_json::json!(pool_id);
+        }
+        data
+    }
+
+    #[test]
+    fn test_pool_address_from_type_str() {
+        let processor = HyperionProcessor::new();
+        let type_str = format!("{}<{}, {}, {}>", HYPERION_SWAP_EVENT_TYPE, POOL, APT_COIN_TYPE, USDC_COIN_TYPE);
+
+        assert!(processor.is_hyperion_event(&type_str));
+        assert_eq!(HyperionProcessor::extract_pool_from_type_str(&type_str).as_deref(), Some(POOL));
+
+        // pool_id is not needed when the type string carries the pool
+        let swap_data = processor.extract_swap_data(&event_data(None), &type_str).unwrap();
+        assert_eq!(swap_data.pool_id, POOL);
+
+        // Without generic parameters the pool_id field is used
+        assert_eq!(HyperionProcessor::extract_pool_from_type_str(HYPERION_SWAP_EVENT_TYPE), None);
+        let swap_data = processor.extract_swap_data(&event_data(Some("0xabc")), HYPERION_SWAP_EVENT_TYPE).unwrap();
+        assert_eq!(swap_data.pool_id, "0xabc");
+        assert!(processor.extract_swap_data(&event_data(None), HYPERION_SWAP_EVENT_TYPE).is_err());
+    }
+
+    #[test]
+    fn test_fee_growth_delta_from_q128() {
+        let mut data = event_data(None);
+        // 1.5 * 2^128 and 0.25 * 2^128
+        data["fee_growth_global_x128_delta_x"] = serde_json::json!("510423550381407695195061911147652317184");
+        data["fee_growth_global_x128_delta_y"] = serde_json::json!("85070591730234615865843651857942052864");
+
+        let (x, y) = HyperionProcessor::extract_fee_growth_delta(&data).unwrap();
+        assert_eq!(x, BigDecimal::from_str("1.5").unwrap());
+        assert_eq!(y, BigDecimal::from_str("0.25").unwrap());
+
+        assert!(HyperionProcessor::extract_fee_growth_delta(&event_data(None)).is_none());
+    }
+
+    #[test]
+    fn test_initialize_pool_registers_pair() {
+        const WBTC: &str = "0x68844a0d7f2587e726ad0579f3d640865bb4162c08a4589eeda3f9689ec52a3d";
+        let event_data = serde_json::json!({
+            "pool": { "inner": POOL },
+            "token0": { "inner": APT_COIN_TYPE },
+            "token1": { "inner": WBTC },
+            "fee_tier": "3",
+            "initial_sqrt_price": "18446744073709551616",
+        });
+
+        let pool = HyperionProcessor::handle_initialize_pool(&event_data).unwrap();
+        assert_eq!(pool.pool_address, POOL);
+        assert_eq!((pool.token0.as_str(), pool.token1.as_str(), pool.fee_tier), (APT_COIN_TYPE, WBTC, 3));
+
+        let mut processor = HyperionProcessor::new();
+        assert!(processor.is_supported_pair(APT_COIN_TYPE, USDC_COIN_TYPE));
+        assert!(!processor.is_supported_pair(WBTC, APT_COIN_TYPE));
+        processor.register_pool(&pool.pool_address, &pool.token0, &pool.token1);
+        assert!(processor.is_supported_pair(WBTC, APT_COIN_TYPE));
+    }
+
+    #[test]
+    fn test_zero_for_one_sets_swap_direction() {
+        let (in_amount, in_token, out_amount, out_token) =
+            HyperionProcessor::apply_direction(false, BigDecimal::from(5), BigDecimal::from(1), APT_COIN_TYPE, USDC_COIN_TYPE);
+        assert_eq!((in_token.as_str(), out_token.as_str()), (USDC_COIN_TYPE, APT_COIN_TYPE));
+        assert_eq!((in_amount, out_amount), (BigDecimal::from(5), BigDecimal::from(1)));
+
+        let mut processor = HyperionProcessor::new();
+        processor.register_pool(POOL, APT_COIN_TYPE, USDC_COIN_TYPE);
+        // from_token says APT -> USDC, but the flag says token1 (USDC) was paid in
+        let mut data = event_data(Some(POOL));
+        data["zero_for_one"] = serde_json::json!(false);
+        let swap_data = processor.extract_swap_data(&data, HYPERION_SWAP_EVENT_TYPE).unwrap();
+        assert_eq!((swap_data.from_token.as_str(), swap_data.to_token.as_str()), (USDC_COIN_TYPE, APT_COIN_TYPE));
+        assert_eq!((swap_data.amount_in.as_str(), swap_data.amount_out.as_str()), ("100000000", "5230000"));
+
+        // Unknown pools fall back to from_token/to_token
+        let mut data = event_data(Some("0xabc"));
+        data["zero_for_one"] = serde_json::json!(false);
+        let swap_data = processor.extract_swap_data(&data, HYPERION_SWAP_EVENT_TYPE).unwrap();
+        assert_eq!(swap_data.from_token, APT_COIN_TYPE);
+    }
+}
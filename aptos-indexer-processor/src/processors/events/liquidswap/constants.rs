@@ -2,6 +2,14 @@
 
 // LiquidSwap swap event type
 pub const LIQUIDSWAP_SWAP_EVENT_TYPE: &str = "0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::liquidity_pool::SwapEvent";
+// LiquidSwap v0.5 swap event type (same event layout, new contract address)
+pub const LIQUIDSWAP_V05_SWAP_EVENT_TYPE: &str = "0x163df34fccbf003ce219d3f1d9e70d140b60622cb9dd47599c25fb2f797ba6e::liquidity_pool::SwapEvent";
+// LiquidSwap v0.5 stable pool swap event; same amounts as SwapEvent plus the fee paid
+pub const LIQUIDSWAP_V05_STABLE_SWAP_EVENT_TYPE: &str = "0x163df34fccbf003ce219d3f1d9e70d140b60622cb9dd47599c25fb2f797ba6e::liquidity_pool::StableSwapEvent";
+
+// Default pool fees, used when the event doesn't carry the fee
+pub const LIQUIDSWAP_UNCORRELATED_FEE_BPS: u32 = 30;
+pub const LIQUIDSWAP_STABLE_FEE_BPS: u32 = 4;
 
 // Coin types (reuse from existing constants)
 pub const APT_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
@@ -11,9 +19,20 @@ pub const WHUSDC_COIN_TYPE: &str = "0x5e156f1207d0ebfa19a9eeff00d62a282278fb8719
 pub const WHUSDT_COIN_TYPE: &str = "0x1f9e145308ba2fbd4737c6a08204087f29f5d6bb7d76969cdd79d5fc95e0ae3::coin::T";
 pub const IZWETH_COIN_TYPE: &str = "0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::WETH";
 pub const WHWETH_COIN_TYPE: &str = "0xcc8a89c8dce9693d354449f1f73e60e14e347417854f029db5bc8e7454008abb::coin::T";
+pub const IZBTC_COIN_TYPE: &str = "0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::WBTC";
+// Wrapped Bitcoin bridged to Aptos via Wormhole
+pub const WHBTC_COIN_TYPE: &str = "0xae478ff7d83ed072dbc5e264250e67ef58f57c99d89b447efd8a0a2e8b2be76e::coin::T";
+// Binance-peg BNB bridged to Aptos via Celer
+pub const BNB_COIN_TYPE: &str = "0x8d87a65ba30e09357fa2edea2c80dbac296e5dec2b18287113500b902942929d::celer_coin_manager::BnbCoin";
+// MOVE, the BlueMove governance coin
+pub const MOVE_COIN_TYPE: &str = "0x27fafcc4e39daac97556af8a803dbb52ecb03d1ae5d3e3e3e5a0d94cd4d4df0e::MOVE::MOVE";
 
 // Decimal places for each token
 pub const APT_DECIMALS: u8 = 8;
 pub const USDC_DECIMALS: u8 = 6;
 pub const USDT_DECIMALS: u8 = 6;
-pub const WETH_DECIMALS: u8 = 6; 
\ No newline at end of file
+pub const WETH_DECIMALS: u8 = 6;
+pub const BTC_DECIMALS: u8 = 8;
+pub const WHBTC_DECIMALS: u8 = 8;
+pub const BNB_DECIMALS: u8 = 8;
+pub const MOVE_DECIMALS: u8 = 8;
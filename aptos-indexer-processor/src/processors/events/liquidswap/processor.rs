@@ -1,4 +1,9 @@
 use super::constants::{
+    LIQUIDSWAP_SWAP_EVENT_TYPE,
+    LIQUIDSWAP_V05_SWAP_EVENT_TYPE,
+    LIQUIDSWAP_V05_STABLE_SWAP_EVENT_TYPE,
+    LIQUIDSWAP_UNCORRELATED_FEE_BPS,
+    LIQUIDSWAP_STABLE_FEE_BPS,
     APT_COIN_TYPE,
     IZUSDC_COIN_TYPE,
     IZUSDT_COIN_TYPE,
@@ -6,10 +11,22 @@ use super::constants::{
     WHUSDT_COIN_TYPE,
     IZWETH_COIN_TYPE,
     WHWETH_COIN_TYPE,
+    IZBTC_COIN_TYPE,
+    WHBTC_COIN_TYPE,
+    BNB_COIN_TYPE,
+    MOVE_COIN_TYPE,
     APT_DECIMALS,
     USDC_DECIMALS,
     USDT_DECIMALS,
     WETH_DECIMALS,
+    BTC_DECIMALS,
+    WHBTC_DECIMALS,
+    BNB_DECIMALS,
+    MOVE_DECIMALS,
+};
+use crate::utils::{
+    math::{clamp_precision, is_positive, MAX_VOLUME_SCALE},
+    pool_registry::PoolRegistry,
 };
 use anyhow::Result;
 use bigdecimal::{BigDecimal, Zero, FromPrimitive};
@@ -17,7 +34,7 @@ use serde_json;
 use std::{collections::HashMap, str::FromStr};
 use tracing::{info, debug};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LiquidSwapData {
     pub x_in: String,
     pub x_out: String,
@@ -25,15 +42,26 @@ pub struct LiquidSwapData {
     pub y_out: String,
     pub token_x: String,
     pub token_y: String,
+    // Fee paid in the input coin's raw units; only v0.5 StableSwapEvent carries it
+    pub fee: Option<BigDecimal>,
+    pub stable: bool,
 }
 
 #[derive(Debug)]
 pub struct LiquidPoolVolume {
     pub pair: String,
+    pub trade_count: i64,
     pub apt_volume_24h: BigDecimal,
     pub usdc_volume_24h: BigDecimal,
     pub usdt_volume_24h: BigDecimal,
     pub weth_volume_24h: BigDecimal,
+    pub btc_volume_24h: BigDecimal,
+    pub bnb_volume_24h: BigDecimal,
+    pub move_volume_24h: BigDecimal,
+    // Fees paid in the input coin
+    pub apt_fee_24h: BigDecimal,
+    pub usdc_fee_24h: BigDecimal,
+    pub usdt_fee_24h: BigDecimal,
     // Buy/Sell volume tracking
     pub apt_buy_volume_24h: BigDecimal,
     pub apt_sell_volume_24h: BigDecimal,
@@ -43,14 +71,25 @@ pub struct LiquidPoolVolume {
     pub usdt_sell_volume_24h: BigDecimal,
     pub weth_buy_volume_24h: BigDecimal,
     pub weth_sell_volume_24h: BigDecimal,
+    pub btc_buy_volume_24h: BigDecimal,
+    pub btc_sell_volume_24h: BigDecimal,
+    pub bnb_buy_volume_24h: BigDecimal,
+    pub bnb_sell_volume_24h: BigDecimal,
+    pub move_buy_volume_24h: BigDecimal,
+    pub move_sell_volume_24h: BigDecimal,
 }
 
 // Cached decimal divisors for performance
+#[derive(Clone)]
 struct LiquidDecimalDivisors {
     apt: BigDecimal,
     usdc: BigDecimal,
     usdt: BigDecimal,
     weth: BigDecimal,
+    btc: BigDecimal,
+    whbtc: BigDecimal,
+    bnb: BigDecimal,
+    move_: BigDecimal,
 }
 
 impl LiquidDecimalDivisors {
@@ -60,6 +99,10 @@ impl LiquidDecimalDivisors {
             usdc: BigDecimal::from_u64(10_u64.pow(USDC_DECIMALS as u32)).unwrap(),
             usdt: BigDecimal::from_u64(10_u64.pow(USDT_DECIMALS as u32)).unwrap(),
             weth: BigDecimal::from_u64(10_u64.pow(WETH_DECIMALS as u32)).unwrap(),
+            btc: BigDecimal::from_u64(10_u64.pow(BTC_DECIMALS as u32)).unwrap(),
+            whbtc: BigDecimal::from_u64(10_u64.pow(WHBTC_DECIMALS as u32)).unwrap(),
+            bnb: BigDecimal::from_u64(10_u64.pow(BNB_DECIMALS as u32)).unwrap(),
+            move_: BigDecimal::from_u64(10_u64.pow(MOVE_DECIMALS as u32)).unwrap(),
         }
     }
 }
@@ -68,10 +111,17 @@ impl Default for LiquidPoolVolume {
     fn default() -> Self {
         Self {
             pair: String::new(),
+            trade_count: 0,
             apt_volume_24h: BigDecimal::from(0),
             usdc_volume_24h: BigDecimal::from(0),
             usdt_volume_24h: BigDecimal::from(0),
             weth_volume_24h: BigDecimal::from(0),
+            btc_volume_24h: BigDecimal::from(0),
+            bnb_volume_24h: BigDecimal::from(0),
+            move_volume_24h: BigDecimal::from(0),
+            apt_fee_24h: BigDecimal::from(0),
+            usdc_fee_24h: BigDecimal::from(0),
+            usdt_fee_24h: BigDecimal::from(0),
             apt_buy_volume_24h: BigDecimal::from(0),
             apt_sell_volume_24h: BigDecimal::from(0),
             usdc_buy_volume_24h: BigDecimal::from(0),
@@ -80,10 +130,51 @@ impl Default for LiquidPoolVolume {
             usdt_sell_volume_24h: BigDecimal::from(0),
             weth_buy_volume_24h: BigDecimal::from(0),
             weth_sell_volume_24h: BigDecimal::from(0),
+            btc_buy_volume_24h: BigDecimal::from(0),
+            btc_sell_volume_24h: BigDecimal::from(0),
+            bnb_buy_volume_24h: BigDecimal::from(0),
+            bnb_sell_volume_24h: BigDecimal::from(0),
+            move_buy_volume_24h: BigDecimal::from(0),
+            move_sell_volume_24h: BigDecimal::from(0),
+        }
+    }
+}
+
+impl LiquidPoolVolume {
+    /// Round every accumulated amount to `MAX_VOLUME_SCALE` decimal places
+    pub fn clamp_precision(&mut self) {
+        for amount in [
+            &mut self.apt_volume_24h,
+            &mut self.usdc_volume_24h,
+            &mut self.usdt_volume_24h,
+            &mut self.weth_volume_24h,
+            &mut self.btc_volume_24h,
+            &mut self.bnb_volume_24h,
+            &mut self.move_volume_24h,
+            &mut self.apt_fee_24h,
+            &mut self.usdc_fee_24h,
+            &mut self.usdt_fee_24h,
+            &mut self.apt_buy_volume_24h,
+            &mut self.apt_sell_volume_24h,
+            &mut self.usdc_buy_volume_24h,
+            &mut self.usdc_sell_volume_24h,
+            &mut self.usdt_buy_volume_24h,
+            &mut self.usdt_sell_volume_24h,
+            &mut self.weth_buy_volume_24h,
+            &mut self.weth_sell_volume_24h,
+            &mut self.btc_buy_volume_24h,
+            &mut self.btc_sell_volume_24h,
+            &mut self.bnb_buy_volume_24h,
+            &mut self.bnb_sell_volume_24h,
+            &mut self.move_buy_volume_24h,
+            &mut self.move_sell_volume_24h,
+        ] {
+            *amount = clamp_precision(std::mem::take(amount), MAX_VOLUME_SCALE);
         }
     }
 }
 
+#[derive(Clone)]
 pub struct LiquidSwapProcessor {
     divisors: LiquidDecimalDivisors,
 }
@@ -105,9 +196,9 @@ impl LiquidSwapProcessor {
             if let Some(end) = type_str.rfind('>') {
                 let generic_part = &type_str[start + 1..end];
                 
-                // Split by comma and clean up - take only first 2 tokens (ignore the curve type)
-                let tokens: Vec<&str> = generic_part.split(',').map(|s| s.trim()).collect();
-                if tokens.len() >= 2 {
+                // Split on top-level commas only - take the first 2 tokens (ignore the curve type)
+                let tokens = split_generic_params(generic_part);
+                if tokens.len() >= 2 && tokens.iter().all(|token| !token.is_empty()) {
                     let token_x = tokens[0].to_string();
                     let token_y = tokens[1].to_string();
                     debug!("✅ Extracted tokens: X={}, Y={}", token_x, token_y);
@@ -157,9 +248,26 @@ impl LiquidSwapProcessor {
             y_out: y_out.to_string(),
             token_x,
             token_y,
+            fee: Self::extract_fee_from_event(event_data),
+            stable: Self::is_stable_swap(type_str),
         })
     }
 
+    /// The `fee` field of a v0.5 `StableSwapEvent`, in the input coin's raw units.
+    /// v0.4 events have no fee field.
+    pub fn extract_fee_from_event(event_data: &serde_json::Value) -> Option<BigDecimal> {
+        let fee = event_data.get("fee")?;
+        match fee.as_str() {
+            Some(raw) => BigDecimal::from_str(raw).ok(),
+            None => BigDecimal::from_u64(fee.as_u64()?),
+        }
+    }
+
+    /// True for stable-curve pools, which charge `LIQUIDSWAP_STABLE_FEE_BPS`
+    fn is_stable_swap(type_str: &str) -> bool {
+        type_str.contains(LIQUIDSWAP_V05_STABLE_SWAP_EVENT_TYPE) || type_str.contains("::curves::Stable>")
+    }
+
     pub fn is_supported_pair(&self, token_x: &str, token_y: &str) -> bool {
         // Check if this is APT/izUSDC pair (in either order)
         let is_apt_izusdc = (token_x == APT_COIN_TYPE && token_y == IZUSDC_COIN_TYPE) ||
@@ -189,40 +297,61 @@ impl LiquidSwapProcessor {
         let is_izusdt_whusdt = (token_x == IZUSDT_COIN_TYPE && token_y == WHUSDT_COIN_TYPE) ||
                               (token_x == WHUSDT_COIN_TYPE && token_y == IZUSDT_COIN_TYPE);
         
-        is_apt_izusdc || is_apt_izusdt || is_apt_whusdt || is_apt_izweth || is_apt_whweth || is_whusdc_izusdc || is_izusdt_whusdt
+        // Check if this is APT/izBTC pair (in either order)
+        let is_apt_izbtc = (token_x == APT_COIN_TYPE && token_y == IZBTC_COIN_TYPE) ||
+                          (token_x == IZBTC_COIN_TYPE && token_y == APT_COIN_TYPE);
+        
+        // Check if this is APT/whBTC pair (in either order)
+        let is_apt_whbtc = (token_x == APT_COIN_TYPE && token_y == WHBTC_COIN_TYPE) ||
+                          (token_x == WHBTC_COIN_TYPE && token_y == APT_COIN_TYPE);
+        
+        // Check if this is APT/BNB pair (in either order)
+        let is_apt_bnb = (token_x == APT_COIN_TYPE && token_y == BNB_COIN_TYPE) ||
+                        (token_x == BNB_COIN_TYPE && token_y == APT_COIN_TYPE);
+        
+        // Check if this is APT/MOVE pair (in either order)
+        let is_apt_move = (token_x == APT_COIN_TYPE && token_y == MOVE_COIN_TYPE) ||
+                         (token_x == MOVE_COIN_TYPE && token_y == APT_COIN_TYPE);
+        
+        is_apt_izusdc || is_apt_izusdt || is_apt_whusdt || is_apt_izweth || is_apt_whweth || is_whusdc_izusdc || is_izusdt_whusdt || is_apt_izbtc || is_apt_whbtc || is_apt_bnb || is_apt_move
+    }
+
+    /// True when the pair (in either order) is a LiquidSwap pool in `pool_registry`. The
+    /// registry holds every hardcoded pair plus any registered at runtime.
+    pub fn validate_pair_against_registry(pool_registry: &PoolRegistry, token_x: &str, token_y: &str) -> bool {
+        pool_registry.get_pair_name("liquidswap", token_x, token_y).is_some()
+    }
+
+    /// True when a token is both sent in and taken out, as in a flash loan. Such events
+    /// are not directional trades.
+    pub fn is_flash_loan(x_in: &BigDecimal, x_out: &BigDecimal, y_in: &BigDecimal, y_out: &BigDecimal) -> bool {
+        (!x_in.is_zero() && !x_out.is_zero()) || (!y_in.is_zero() && !y_out.is_zero())
+    }
+
+    /// `is_flash_loan` for the event's raw amounts
+    pub fn is_flash_loan_swap(data: &LiquidSwapData) -> bool {
+        let parse = |amount: &str| BigDecimal::from_str(amount).unwrap_or_else(|_| BigDecimal::zero());
+        Self::is_flash_loan(&parse(&data.x_in), &parse(&data.x_out), &parse(&data.y_in), &parse(&data.y_out))
     }
 
     pub async fn process_liquidswap(&self, pool_volumes: &mut HashMap<String, LiquidPoolVolume>, swap_data: LiquidSwapData) {
+        if Self::is_flash_loan_swap(&swap_data) {
+            debug!("⚡ Skipping LiquidSwap flash loan: {} / {}", swap_data.token_x, swap_data.token_y);
+            return;
+        }
+
         // Only process supported pairs
         if !self.is_supported_pair(&swap_data.token_x, &swap_data.token_y) {
             debug!("🚫 Unsupported pair: {} / {}", swap_data.token_x, swap_data.token_y);
             return;
         }
 
-        // Create a unique identifier for the pair (always in consistent order)
-        let pair_key = if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE) ||
-                          (swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDC".to_string()
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDT_COIN_TYPE) ||
-                  (swap_data.token_x == IZUSDT_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDT".to_string()
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == WHUSDT_COIN_TYPE) ||
-                  (swap_data.token_x == WHUSDT_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDT".to_string()  // whUSDT stored as USDT in database
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZWETH_COIN_TYPE) ||
-                  (swap_data.token_x == IZWETH_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/WETH".to_string()  // izWETH stored as WETH in database
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == WHWETH_COIN_TYPE) ||
-                  (swap_data.token_x == WHWETH_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/WETH".to_string()  // whWETH stored as WETH in database
-        } else if (swap_data.token_x == WHUSDC_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE) ||
-                  (swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == WHUSDC_COIN_TYPE) {
-            "USDC/USDC".to_string()  // Both stored as USDC in database
-        } else if (swap_data.token_x == IZUSDT_COIN_TYPE && swap_data.token_y == WHUSDT_COIN_TYPE) ||
-                  (swap_data.token_x == WHUSDT_COIN_TYPE && swap_data.token_y == IZUSDT_COIN_TYPE) {
-            "USDT/USDT".to_string()  // Both stored as USDT in database
-        } else {
-            return; // Should not happen due to is_supported_pair check
+        // Canonical pair name (always the same for either token order)
+        let Some(pair_key) = PoolRegistry::global()
+            .get_pair_name("liquidswap", &swap_data.token_x, &swap_data.token_y)
+            .map(str::to_string)
+        else {
+            return; // Shouldn't happen due to is_supported_pair check
         };
 
         // Get or create pool entry
@@ -231,6 +360,7 @@ impl LiquidSwapProcessor {
             volume.pair = pair_key.clone();
             volume
         });
+        pool_entry.trade_count += 1;
 
         // Parse amounts
         let x_in = BigDecimal::from_str(&swap_data.x_in).unwrap_or_else(|_| BigDecimal::zero());
@@ -281,10 +411,62 @@ impl LiquidSwapProcessor {
         } else if swap_data.token_x == WHUSDT_COIN_TYPE && swap_data.token_y == IZUSDT_COIN_TYPE {
             // whUSDT is token_x, izUSDT is token_y
             self.process_whusdt_izusdt_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZBTC_COIN_TYPE {
+            // APT is token_x, izBTC is token_y
+            self.process_apt_izbtc_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == IZBTC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            // izBTC is token_x, APT is token_y
+            self.process_izbtc_apt_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == WHBTC_COIN_TYPE {
+            // APT is token_x, whBTC is token_y
+            self.process_apt_whbtc_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == WHBTC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            // whBTC is token_x, APT is token_y
+            self.process_whbtc_apt_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == BNB_COIN_TYPE {
+            // APT is token_x, BNB is token_y
+            self.process_apt_bnb_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == BNB_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            // BNB is token_x, APT is token_y
+            self.process_bnb_apt_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == MOVE_COIN_TYPE {
+            // APT is token_x, MOVE is token_y
+            self.process_apt_move_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
+        } else if swap_data.token_x == MOVE_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            // MOVE is token_x, APT is token_y
+            self.process_move_apt_liquidswap(pool_entry, &x_in, &x_out, &y_in, &y_out).await;
         }
 
-        info!("📊 LiquidSwap {} volume updated: APT={}, USDC={}, USDT={}, WETH={}", 
-            pool_entry.pair, pool_entry.apt_volume_24h, pool_entry.usdc_volume_24h, pool_entry.usdt_volume_24h, pool_entry.weth_volume_24h);
+        self.record_fee(pool_entry, &swap_data, &x_in, &y_in);
+        pool_entry.clamp_precision();
+
+        info!("📊 LiquidSwap {} volume updated: APT={}, USDC={}, USDT={}, WETH={}, BTC={}, BNB={}, MOVE={}", 
+            pool_entry.pair, pool_entry.apt_volume_24h, pool_entry.usdc_volume_24h, pool_entry.usdt_volume_24h, pool_entry.weth_volume_24h, pool_entry.btc_volume_24h, pool_entry.bnb_volume_24h, pool_entry.move_volume_24h);
+    }
+
+    /// Add the swap's fee to the input coin's fee total. The event's `fee` is used when present,
+    /// otherwise the fee is estimated from the pool's default fee rate.
+    fn record_fee(&self, pool_entry: &mut LiquidPoolVolume, swap_data: &LiquidSwapData, x_in: &BigDecimal, y_in: &BigDecimal) {
+        let (input_token, raw_amount_in) = if !x_in.is_zero() {
+            (swap_data.token_x.as_str(), x_in)
+        } else {
+            (swap_data.token_y.as_str(), y_in)
+        };
+
+        let raw_fee = match &swap_data.fee {
+            Some(fee) => fee.clone(),
+            None => {
+                let fee_bps = if swap_data.stable { LIQUIDSWAP_STABLE_FEE_BPS } else { LIQUIDSWAP_UNCORRELATED_FEE_BPS };
+                raw_amount_in * BigDecimal::from(fee_bps) / BigDecimal::from(10_000)
+            },
+        };
+
+        match input_token {
+            APT_COIN_TYPE => pool_entry.apt_fee_24h += raw_fee / &self.divisors.apt,
+            IZUSDC_COIN_TYPE | WHUSDC_COIN_TYPE => pool_entry.usdc_fee_24h += raw_fee / &self.divisors.usdc,
+            IZUSDT_COIN_TYPE | WHUSDT_COIN_TYPE => pool_entry.usdt_fee_24h += raw_fee / &self.divisors.usdt,
+            _ => debug!("💸 Not tracking LiquidSwap fees in {}", input_token),
+        }
     }
 
     async fn process_apt_izusdc_liquidswap(
@@ -299,7 +481,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent APT amounts
         // y_in, y_out represent izUSDC amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling APT for izUSDC: APT in, izUSDC out
             let apt_volume = x_in / &self.divisors.apt;
             let usdc_volume = y_out / &self.divisors.usdc;
@@ -313,7 +495,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdc_buy_volume_24h += &usdc_volume;  // USDC is being bought
             
             info!("💱 LiquidSwap APT→izUSDC: Sold {} APT, received {} izUSDC", apt_volume, usdc_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling izUSDC for APT: izUSDC in, APT out
             let usdc_volume = y_in / &self.divisors.usdc;
             let apt_volume = x_out / &self.divisors.apt;
@@ -342,7 +524,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent izUSDC amounts
         // y_in, y_out represent APT amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling izUSDC for APT: izUSDC in, APT out
             let usdc_volume = x_in / &self.divisors.usdc;
             let apt_volume = y_out / &self.divisors.apt;
@@ -356,7 +538,7 @@ impl LiquidSwapProcessor {
             pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
             
             info!("💱 LiquidSwap izUSDC→APT: Sold {} izUSDC, received {} APT", usdc_volume, apt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling APT for izUSDC: APT in, izUSDC out
             let apt_volume = y_in / &self.divisors.apt;
             let usdc_volume = x_out / &self.divisors.usdc;
@@ -385,7 +567,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent APT amounts
         // y_in, y_out represent izUSDT amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling APT for izUSDT: APT in, izUSDT out
             let apt_volume = x_in / &self.divisors.apt;
             let usdt_volume = y_out / &self.divisors.usdt;
@@ -399,7 +581,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdt_buy_volume_24h += &usdt_volume;  // USDT is being bought
             
             info!("💱 LiquidSwap APT→izUSDT: Sold {} APT, received {} izUSDT", apt_volume, usdt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling izUSDT for APT: izUSDT in, APT out
             let usdt_volume = y_in / &self.divisors.usdt;
             let apt_volume = x_out / &self.divisors.apt;
@@ -428,7 +610,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent izUSDT amounts
         // y_in, y_out represent APT amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling izUSDT for APT: izUSDT in, APT out
             let usdt_volume = x_in / &self.divisors.usdt;
             let apt_volume = y_out / &self.divisors.apt;
@@ -442,7 +624,7 @@ impl LiquidSwapProcessor {
             pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
             
             info!("💱 LiquidSwap izUSDT→APT: Sold {} izUSDT, received {} APT", usdt_volume, apt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling APT for izUSDT: APT in, izUSDT out
             let apt_volume = y_in / &self.divisors.apt;
             let usdt_volume = x_out / &self.divisors.usdt;
@@ -472,7 +654,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent whUSDT amounts
         // whUSDT is stored as USDT in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling APT for whUSDT: APT in, whUSDT out
             let apt_volume = x_in / &self.divisors.apt;
             let usdt_volume = y_out / &self.divisors.usdt;
@@ -486,7 +668,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdt_buy_volume_24h += &usdt_volume;  // USDT is being bought
             
             info!("💱 LiquidSwap APT→whUSDT: Sold {} APT, received {} whUSDT", apt_volume, usdt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling whUSDT for APT: whUSDT in, APT out
             let usdt_volume = y_in / &self.divisors.usdt;
             let apt_volume = x_out / &self.divisors.apt;
@@ -516,7 +698,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent APT amounts
         // whUSDT is stored as USDT in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling whUSDT for APT: whUSDT in, APT out
             let usdt_volume = x_in / &self.divisors.usdt;
             let apt_volume = y_out / &self.divisors.apt;
@@ -530,7 +712,7 @@ impl LiquidSwapProcessor {
             pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
             
             info!("💱 LiquidSwap whUSDT→APT: Sold {} whUSDT, received {} APT", usdt_volume, apt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling APT for whUSDT: APT in, whUSDT out
             let apt_volume = y_in / &self.divisors.apt;
             let usdt_volume = x_out / &self.divisors.usdt;
@@ -560,7 +742,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent izUSDC amounts
         // Both are stored as USDC in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling whUSDC for izUSDC: whUSDC in, izUSDC out
             let whusdc_volume = x_in / &self.divisors.usdc;
             let izusdc_volume = y_out / &self.divisors.usdc;
@@ -574,7 +756,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdc_buy_volume_24h += &izusdc_volume;  // izUSDC is being bought
             
             info!("💱 LiquidSwap whUSDC→izUSDC: Sold {} whUSDC, received {} izUSDC", whusdc_volume, izusdc_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling izUSDC for whUSDC: izUSDC in, whUSDC out
             let izusdc_volume = y_in / &self.divisors.usdc;
             let whusdc_volume = x_out / &self.divisors.usdc;
@@ -604,7 +786,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent whUSDC amounts
         // Both are stored as USDC in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling izUSDC for whUSDC: izUSDC in, whUSDC out
             let izusdc_volume = x_in / &self.divisors.usdc;
             let whusdc_volume = y_out / &self.divisors.usdc;
@@ -618,7 +800,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdc_buy_volume_24h += &whusdc_volume;  // whUSDC is being bought
             
             info!("💱 LiquidSwap izUSDC→whUSDC: Sold {} izUSDC, received {} whUSDC", izusdc_volume, whusdc_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling whUSDC for izUSDC: whUSDC in, izUSDC out
             let whusdc_volume = y_in / &self.divisors.usdc;
             let izusdc_volume = x_out / &self.divisors.usdc;
@@ -648,7 +830,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent whUSDT amounts
         // Both are stored as USDT in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling izUSDT for whUSDT: izUSDT in, whUSDT out
             let izusdt_volume = x_in / &self.divisors.usdt;
             let whusdt_volume = y_out / &self.divisors.usdt;
@@ -662,7 +844,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdt_buy_volume_24h += &whusdt_volume;  // whUSDT is being bought
             
             info!("💱 LiquidSwap izUSDT→whUSDT: Sold {} izUSDT, received {} whUSDT", izusdt_volume, whusdt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling whUSDT for izUSDT: whUSDT in, izUSDT out
             let whusdt_volume = y_in / &self.divisors.usdt;
             let izusdt_volume = x_out / &self.divisors.usdt;
@@ -692,7 +874,7 @@ impl LiquidSwapProcessor {
         // y_in, y_out represent izUSDT amounts
         // Both are stored as USDT in database
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling whUSDT for izUSDT: whUSDT in, izUSDT out
             let whusdt_volume = x_in / &self.divisors.usdt;
             let izusdt_volume = y_out / &self.divisors.usdt;
@@ -706,7 +888,7 @@ impl LiquidSwapProcessor {
             pool_entry.usdt_buy_volume_24h += &izusdt_volume;  // izUSDT is being bought
             
             info!("💱 LiquidSwap whUSDT→izUSDT: Sold {} whUSDT, received {} izUSDT", whusdt_volume, izusdt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling izUSDT for whUSDT: izUSDT in, whUSDT out
             let izusdt_volume = y_in / &self.divisors.usdt;
             let whusdt_volume = x_out / &self.divisors.usdt;
@@ -735,7 +917,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent APT amounts
         // y_in, y_out represent izWETH amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling APT for izWETH: APT in, izWETH out
             let apt_volume = x_in / &self.divisors.apt;
             let izweth_volume = y_out / &self.divisors.weth;
@@ -749,7 +931,7 @@ impl LiquidSwapProcessor {
             pool_entry.weth_buy_volume_24h += &izweth_volume;  // izWETH is being bought
             
             info!("💱 LiquidSwap APT→izWETH: Sold {} APT, received {} izWETH", apt_volume, izweth_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling izWETH for APT: izWETH in, APT out
             let izweth_volume = y_in / &self.divisors.weth;
             let apt_volume = x_out / &self.divisors.apt;
@@ -778,7 +960,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent izWETH amounts
         // y_in, y_out represent APT amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling izWETH for APT: izWETH in, APT out
             let izweth_volume = x_in / &self.divisors.weth;
             let apt_volume = y_out / &self.divisors.apt;
@@ -792,7 +974,7 @@ impl LiquidSwapProcessor {
             pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
             
             info!("💱 LiquidSwap izWETH→APT: Sold {} izWETH, received {} APT", izweth_volume, apt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling APT for izWETH: APT in, izWETH out
             let apt_volume = y_in / &self.divisors.apt;
             let izweth_volume = x_out / &self.divisors.weth;
@@ -821,7 +1003,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent APT amounts
         // y_in, y_out represent whWETH amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling APT for whWETH: APT in, whWETH out
             let apt_volume = x_in / &self.divisors.apt;
             let whweth_volume = y_out / &self.divisors.weth;
@@ -835,7 +1017,7 @@ impl LiquidSwapProcessor {
             pool_entry.weth_buy_volume_24h += &whweth_volume;  // whWETH is being bought
             
             info!("💱 LiquidSwap APT→whWETH: Sold {} APT, received {} whWETH", apt_volume, whweth_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling whWETH for APT: whWETH in, APT out
             let whweth_volume = y_in / &self.divisors.weth;
             let apt_volume = x_out / &self.divisors.apt;
@@ -864,7 +1046,7 @@ impl LiquidSwapProcessor {
         // x_in, x_out represent whWETH amounts
         // y_in, y_out represent APT amounts
         
-        if x_in > &BigDecimal::zero() && y_out > &BigDecimal::zero() {
+        if is_positive(x_in) && is_positive(y_out) {
             // Selling whWETH for APT: whWETH in, APT out
             let whweth_volume = x_in / &self.divisors.weth;
             let apt_volume = y_out / &self.divisors.apt;
@@ -878,7 +1060,7 @@ impl LiquidSwapProcessor {
             pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
             
             info!("💱 LiquidSwap whWETH→APT: Sold {} whWETH, received {} APT", whweth_volume, apt_volume);
-        } else if y_in > &BigDecimal::zero() && x_out > &BigDecimal::zero() {
+        } else if is_positive(y_in) && is_positive(x_out) {
             // Selling APT for whWETH: APT in, whWETH out
             let apt_volume = y_in / &self.divisors.apt;
             let whweth_volume = x_out / &self.divisors.weth;
@@ -895,7 +1077,581 @@ impl LiquidSwapProcessor {
         }
     }
 
+    async fn process_apt_izbtc_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // APT is token_x, izBTC is token_y
+        // x_in, x_out represent APT amounts
+        // y_in, y_out represent izBTC amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling APT for izBTC: APT in, izBTC out
+            let apt_volume = x_in / &self.divisors.apt;
+            let izbtc_volume = y_out / &self.divisors.btc;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.btc_volume_24h += &izbtc_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.btc_buy_volume_24h += &izbtc_volume;  // izBTC is being bought
+            
+            info!("💱 LiquidSwap APT→izBTC: Sold {} APT, received {} izBTC", apt_volume, izbtc_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling izBTC for APT: izBTC in, APT out
+            let izbtc_volume = y_in / &self.divisors.btc;
+            let apt_volume = x_out / &self.divisors.apt;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.btc_volume_24h += &izbtc_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.btc_sell_volume_24h += &izbtc_volume;  // izBTC is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap izBTC→APT: Sold {} izBTC, received {} APT", izbtc_volume, apt_volume);
+        }
+    }
+
+    async fn process_izbtc_apt_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // izBTC is token_x, APT is token_y
+        // x_in, x_out represent izBTC amounts
+        // y_in, y_out represent APT amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling izBTC for APT: izBTC in, APT out
+            let izbtc_volume = x_in / &self.divisors.btc;
+            let apt_volume = y_out / &self.divisors.apt;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.btc_volume_24h += &izbtc_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.btc_sell_volume_24h += &izbtc_volume;  // izBTC is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap izBTC→APT: Sold {} izBTC, received {} APT", izbtc_volume, apt_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling APT for izBTC: APT in, izBTC out
+            let apt_volume = y_in / &self.divisors.apt;
+            let izbtc_volume = x_out / &self.divisors.btc;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.btc_volume_24h += &izbtc_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.btc_buy_volume_24h += &izbtc_volume;  // izBTC is being bought
+            
+            info!("💱 LiquidSwap APT→izBTC: Sold {} APT, received {} izBTC", apt_volume, izbtc_volume);
+        }
+    }
+
+    async fn process_apt_whbtc_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // APT is token_x, whBTC is token_y
+        // x_in, x_out represent APT amounts
+        // y_in, y_out represent whBTC amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling APT for whBTC: APT in, whBTC out
+            let apt_volume = x_in / &self.divisors.apt;
+            let whbtc_volume = y_out / &self.divisors.whbtc;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.btc_volume_24h += &whbtc_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.btc_buy_volume_24h += &whbtc_volume;  // whBTC is being bought
+            
+            info!("💱 LiquidSwap APT→whBTC: Sold {} APT, received {} whBTC", apt_volume, whbtc_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling whBTC for APT: whBTC in, APT out
+            let whbtc_volume = y_in / &self.divisors.whbtc;
+            let apt_volume = x_out / &self.divisors.apt;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.btc_volume_24h += &whbtc_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.btc_sell_volume_24h += &whbtc_volume;  // whBTC is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap whBTC→APT: Sold {} whBTC, received {} APT", whbtc_volume, apt_volume);
+        }
+    }
+
+    async fn process_whbtc_apt_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // whBTC is token_x, APT is token_y
+        // x_in, x_out represent whBTC amounts
+        // y_in, y_out represent APT amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling whBTC for APT: whBTC in, APT out
+            let whbtc_volume = x_in / &self.divisors.whbtc;
+            let apt_volume = y_out / &self.divisors.apt;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.btc_volume_24h += &whbtc_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.btc_sell_volume_24h += &whbtc_volume;  // whBTC is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap whBTC→APT: Sold {} whBTC, received {} APT", whbtc_volume, apt_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling APT for whBTC: APT in, whBTC out
+            let apt_volume = y_in / &self.divisors.apt;
+            let whbtc_volume = x_out / &self.divisors.whbtc;
+            
+            // Update total volumes (for backward compatibility)
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.btc_volume_24h += &whbtc_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.btc_buy_volume_24h += &whbtc_volume;  // whBTC is being bought
+            
+            info!("💱 LiquidSwap APT→whBTC: Sold {} APT, received {} whBTC", apt_volume, whbtc_volume);
+        }
+    }
+
+    async fn process_apt_bnb_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // APT is token_x, BNB is token_y
+        // x_in, x_out represent APT amounts
+        // y_in, y_out represent BNB amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling APT for BNB: APT in, BNB out
+            let apt_volume = x_in / &self.divisors.apt;
+            let bnb_volume = y_out / &self.divisors.bnb;
+            
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.bnb_volume_24h += &bnb_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.bnb_buy_volume_24h += &bnb_volume;  // BNB is being bought
+            
+            info!("💱 LiquidSwap APT→BNB: Sold {} APT, received {} BNB", apt_volume, bnb_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling BNB for APT: BNB in, APT out
+            let bnb_volume = y_in / &self.divisors.bnb;
+            let apt_volume = x_out / &self.divisors.apt;
+            
+            pool_entry.bnb_volume_24h += &bnb_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.bnb_sell_volume_24h += &bnb_volume;  // BNB is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap BNB→APT: Sold {} BNB, received {} APT", bnb_volume, apt_volume);
+        }
+    }
+
+    async fn process_bnb_apt_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // BNB is token_x, APT is token_y
+        // x_in, x_out represent BNB amounts
+        // y_in, y_out represent APT amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling BNB for APT: BNB in, APT out
+            let bnb_volume = x_in / &self.divisors.bnb;
+            let apt_volume = y_out / &self.divisors.apt;
+            
+            pool_entry.bnb_volume_24h += &bnb_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.bnb_sell_volume_24h += &bnb_volume;  // BNB is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap BNB→APT: Sold {} BNB, received {} APT", bnb_volume, apt_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling APT for BNB: APT in, BNB out
+            let apt_volume = y_in / &self.divisors.apt;
+            let bnb_volume = x_out / &self.divisors.bnb;
+            
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.bnb_volume_24h += &bnb_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.bnb_buy_volume_24h += &bnb_volume;  // BNB is being bought
+            
+            info!("💱 LiquidSwap APT→BNB: Sold {} APT, received {} BNB", apt_volume, bnb_volume);
+        }
+    }
+
+    async fn process_apt_move_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // APT is token_x, MOVE is token_y
+        // x_in, x_out represent APT amounts
+        // y_in, y_out represent MOVE amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling APT for MOVE: APT in, MOVE out
+            let apt_volume = x_in / &self.divisors.apt;
+            let move_volume = y_out / &self.divisors.move_;
+            
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.move_volume_24h += &move_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.move_buy_volume_24h += &move_volume;  // MOVE is being bought
+            
+            info!("💱 LiquidSwap APT→MOVE: Sold {} APT, received {} MOVE", apt_volume, move_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling MOVE for APT: MOVE in, APT out
+            let move_volume = y_in / &self.divisors.move_;
+            let apt_volume = x_out / &self.divisors.apt;
+            
+            pool_entry.move_volume_24h += &move_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.move_sell_volume_24h += &move_volume;  // MOVE is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap MOVE→APT: Sold {} MOVE, received {} APT", move_volume, apt_volume);
+        }
+    }
+
+    async fn process_move_apt_liquidswap(
+        &self,
+        pool_entry: &mut LiquidPoolVolume,
+        x_in: &BigDecimal,
+        x_out: &BigDecimal,
+        y_in: &BigDecimal,
+        y_out: &BigDecimal,
+    ) {
+        // MOVE is token_x, APT is token_y
+        // x_in, x_out represent MOVE amounts
+        // y_in, y_out represent APT amounts
+        
+        if is_positive(x_in) && is_positive(y_out) {
+            // Selling MOVE for APT: MOVE in, APT out
+            let move_volume = x_in / &self.divisors.move_;
+            let apt_volume = y_out / &self.divisors.apt;
+            
+            pool_entry.move_volume_24h += &move_volume;
+            pool_entry.apt_volume_24h += &apt_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.move_sell_volume_24h += &move_volume;  // MOVE is being sold
+            pool_entry.apt_buy_volume_24h += &apt_volume;  // APT is being bought
+            
+            info!("💱 LiquidSwap MOVE→APT: Sold {} MOVE, received {} APT", move_volume, apt_volume);
+        } else if is_positive(y_in) && is_positive(x_out) {
+            // Selling APT for MOVE: APT in, MOVE out
+            let apt_volume = y_in / &self.divisors.apt;
+            let move_volume = x_out / &self.divisors.move_;
+            
+            pool_entry.apt_volume_24h += &apt_volume;
+            pool_entry.move_volume_24h += &move_volume;
+            
+            // Update buy/sell volumes
+            pool_entry.apt_sell_volume_24h += &apt_volume;  // APT is being sold
+            pool_entry.move_buy_volume_24h += &move_volume;  // MOVE is being bought
+            
+            info!("💱 LiquidSwap APT→MOVE: Sold {} APT, received {} MOVE", apt_volume, move_volume);
+        }
+    }
+
+    /// Matches v0.4 and v0.5 swap events with any generic parameters
     pub fn is_liquidswap_event(&self, type_str: &str) -> bool {
-        type_str.contains("190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::liquidity_pool::SwapEvent")
+        type_str.contains(LIQUIDSWAP_SWAP_EVENT_TYPE)
+            || type_str.contains(LIQUIDSWAP_V05_SWAP_EVENT_TYPE)
+            || type_str.contains(LIQUIDSWAP_V05_STABLE_SWAP_EVENT_TYPE)
+    }
+}
+
+/// Split generic parameters on commas that are not inside a nested `<...>`
+fn split_generic_params(generic_part: &str) -> Vec<&str> {
+    let mut params = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in generic_part.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                params.push(generic_part[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    params.push(generic_part[start..].trim());
+
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIQUIDSWAP_V05: &str = "0x163df34fccbf003ce219d3f1d9e70d140b60622cb9dd47599c25fb2f797ba6e";
+
+    #[test]
+    fn test_validate_pair_against_registry() {
+        let mut registry = PoolRegistry::global().clone();
+        let unknown_coin = "0x1234::coin::UNKNOWN";
+
+        assert!(LiquidSwapProcessor::validate_pair_against_registry(&registry, IZUSDC_COIN_TYPE, APT_COIN_TYPE));
+        assert!(!LiquidSwapProcessor::validate_pair_against_registry(&registry, APT_COIN_TYPE, unknown_coin));
+
+        registry.register_pair("liquidswap", APT_COIN_TYPE, unknown_coin, "APT/UNKNOWN");
+        assert!(LiquidSwapProcessor::validate_pair_against_registry(&registry, unknown_coin, APT_COIN_TYPE));
+    }
+
+    #[test]
+    fn test_extract_token_types_from_type_str() {
+        let processor = LiquidSwapProcessor::new();
+
+        // Three generic params: the curve type is ignored
+        let uncorrelated = format!("{}<{}, {}, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Uncorrelated>",
+            LIQUIDSWAP_SWAP_EVENT_TYPE, APT_COIN_TYPE, IZUSDC_COIN_TYPE);
+        assert_eq!(
+            processor.extract_token_types_from_type_str(&uncorrelated),
+            Some((APT_COIN_TYPE.to_string(), IZUSDC_COIN_TYPE.to_string()))
+        );
+
+        let stable = format!("{}<{}, {}, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Stable>",
+            LIQUIDSWAP_SWAP_EVENT_TYPE, IZUSDT_COIN_TYPE, WHUSDT_COIN_TYPE);
+        assert_eq!(
+            processor.extract_token_types_from_type_str(&stable),
+            Some((IZUSDT_COIN_TYPE.to_string(), WHUSDT_COIN_TYPE.to_string()))
+        );
+
+        // CoinX with a nested generic must not be split on its inner comma
+        let nested_coin = "0xabc::lp_coin::LP<0x1::aptos_coin::AptosCoin, 0xdef::coin::T>";
+        let nested = format!("{}<{}, {}, {}::curves::Uncorrelated>",
+            LIQUIDSWAP_V05_SWAP_EVENT_TYPE, nested_coin, APT_COIN_TYPE, LIQUIDSWAP_V05);
+        assert_eq!(
+            processor.extract_token_types_from_type_str(&nested),
+            Some((nested_coin.to_string(), APT_COIN_TYPE.to_string()))
+        );
+
+        // No generics, or a single generic param
+        assert_eq!(processor.extract_token_types_from_type_str(LIQUIDSWAP_SWAP_EVENT_TYPE), None);
+        assert_eq!(processor.extract_token_types_from_type_str(&format!("{}<{}>", LIQUIDSWAP_SWAP_EVENT_TYPE, APT_COIN_TYPE)), None);
+    }
+
+    // Mainnet type strings for LiquidSwap v0.4 pools (zUSDC/APT, zUSDC/zUSDT and APT/ceBNB)
+    const MAINNET_USDC_APT_UNCORRELATED: &str = "0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::liquidity_pool::SwapEvent<0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDC, 0x1::aptos_coin::AptosCoin, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Uncorrelated>";
+    const MAINNET_USDC_USDT_STABLE: &str = "0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::liquidity_pool::SwapEvent<0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDC, 0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDT, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Stable>";
+    const MAINNET_APT_BNB_UNCORRELATED: &str = "0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::liquidity_pool::SwapEvent<0x1::aptos_coin::AptosCoin, 0x8d87a65ba30e09357fa2edea2c80dbac296e5dec2b18287113500b902942929d::celer_coin_manager::BnbCoin, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Uncorrelated>";
+
+    #[test]
+    fn test_extract_liquidswap_real_event_types() {
+        let processor = LiquidSwapProcessor::new();
+
+        assert_eq!(
+            processor.extract_token_types_from_type_str(MAINNET_USDC_APT_UNCORRELATED),
+            Some((IZUSDC_COIN_TYPE.to_string(), APT_COIN_TYPE.to_string()))
+        );
+        assert_eq!(
+            processor.extract_token_types_from_type_str(MAINNET_USDC_USDT_STABLE),
+            Some((IZUSDC_COIN_TYPE.to_string(), IZUSDT_COIN_TYPE.to_string()))
+        );
+        // Module path with underscores (celer_coin_manager)
+        assert_eq!(
+            processor.extract_token_types_from_type_str(MAINNET_APT_BNB_UNCORRELATED),
+            Some((APT_COIN_TYPE.to_string(), BNB_COIN_TYPE.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_is_liquidswap_event() {
+        let processor = LiquidSwapProcessor::new();
+        let generics = format!("<{}, {}, {}::curves::Uncorrelated>", APT_COIN_TYPE, IZUSDC_COIN_TYPE, LIQUIDSWAP_V05);
+
+        // v0.4 and v0.5 module paths
+        assert!(processor.is_liquidswap_event(&format!("{}{}", LIQUIDSWAP_SWAP_EVENT_TYPE, generics)));
+        assert!(processor.is_liquidswap_event(&format!("{}{}", LIQUIDSWAP_V05_SWAP_EVENT_TYPE, generics)));
+
+        // Other events from the same modules, or other contracts
+        assert!(!processor.is_liquidswap_event(&format!("{}::liquidity_pool::LiquidityAddedEvent{}", LIQUIDSWAP_V05, generics)));
+        assert!(!processor.is_liquidswap_event("0x31a6675cbe84365bf2b0cbce617ece6c47023ef70826533bde5203d32171dc3c::swap::SwapEvent"));
+    }
+
+    #[tokio::test]
+    async fn test_apt_bnb_swap_in_either_token_order() {
+        let processor = LiquidSwapProcessor::new();
+        let mut pool_volumes = HashMap::new();
+
+        // 2 APT sold for 0.03 BNB with BNB as token_x
+        let swap = LiquidSwapData {
+            x_in: "0".to_string(),
+            x_out: "3000000".to_string(),
+            y_in: "200000000".to_string(),
+            y_out: "0".to_string(),
+            token_x: BNB_COIN_TYPE.to_string(),
+            token_y: APT_COIN_TYPE.to_string(),
+            fee: None,
+            stable: false,
+        };
+        processor.process_liquidswap(&mut pool_volumes, swap).await;
+
+        let pool = &pool_volumes["APT/BNB"];
+        assert_eq!(pool.apt_sell_volume_24h, BigDecimal::from(2));
+        assert_eq!(pool.bnb_buy_volume_24h, BigDecimal::from_str("0.03").unwrap());
+        assert_eq!(pool.bnb_volume_24h, BigDecimal::from_str("0.03").unwrap());
+        assert!(processor.is_supported_pair(APT_COIN_TYPE, BNB_COIN_TYPE));
+    }
+
+    #[tokio::test]
+    async fn test_apt_move_swap_normalizes_move_decimals() {
+        let processor = LiquidSwapProcessor::new();
+        let mut pool_volumes = HashMap::new();
+
+        // 1234.5 MOVE sold for 12.5 APT with MOVE as token_x
+        let swap = LiquidSwapData {
+            x_in: "123450000000".to_string(),
+            x_out: "0".to_string(),
+            y_in: "0".to_string(),
+            y_out: "1250000000".to_string(),
+            token_x: MOVE_COIN_TYPE.to_string(),
+            token_y: APT_COIN_TYPE.to_string(),
+            fee: None,
+            stable: false,
+        };
+        processor.process_liquidswap(&mut pool_volumes, swap).await;
+
+        let pool = &pool_volumes["APT/MOVE"];
+        assert_eq!(pool.move_volume_24h, BigDecimal::from_str("1234.5").unwrap());
+        assert_eq!(pool.move_sell_volume_24h, BigDecimal::from_str("1234.5").unwrap());
+        assert_eq!(pool.apt_buy_volume_24h, BigDecimal::from_str("12.5").unwrap());
+        assert!(processor.is_supported_pair(APT_COIN_TYPE, MOVE_COIN_TYPE));
+    }
+
+    #[tokio::test]
+    async fn test_apt_whbtc_swap_normalizes_btc_decimals() {
+        let processor = LiquidSwapProcessor::new();
+        let mut pool_volumes = HashMap::new();
+
+        // 0.015 whBTC sold for 250 APT with whBTC as token_x
+        let swap = LiquidSwapData {
+            x_in: "1500000".to_string(),
+            x_out: "0".to_string(),
+            y_in: "0".to_string(),
+            y_out: "25000000000".to_string(),
+            token_x: WHBTC_COIN_TYPE.to_string(),
+            token_y: APT_COIN_TYPE.to_string(),
+            fee: None,
+            stable: false,
+        };
+        processor.process_liquidswap(&mut pool_volumes, swap).await;
+
+        let pool = &pool_volumes["APT/BTC"];
+        assert_eq!(pool.btc_sell_volume_24h, BigDecimal::from_str("0.015").unwrap());
+        assert_eq!(pool.btc_volume_24h, BigDecimal::from_str("0.015").unwrap());
+        assert_eq!(pool.apt_buy_volume_24h, BigDecimal::from(250));
+        assert!(processor.is_supported_pair(APT_COIN_TYPE, WHBTC_COIN_TYPE));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_flash_loan_is_not_a_trade() {
+        let amount = |value: u64| BigDecimal::from(value);
+        let zero = BigDecimal::zero();
+
+        // Plain swaps in either direction
+        assert!(!LiquidSwapProcessor::is_flash_loan(&amount(100), &zero, &zero, &amount(50)));
+        assert!(!LiquidSwapProcessor::is_flash_loan(&zero, &amount(100), &amount(50), &zero));
+
+        // All four amounts set: x is borrowed and repaid in the same event
+        assert!(LiquidSwapProcessor::is_flash_loan(&amount(100), &amount(100), &amount(1), &amount(50)));
+    }
+
+    #[tokio::test]
+    async fn test_v05_stable_swap_fee_comes_from_the_event() {
+        let processor = LiquidSwapProcessor::new();
+        let type_str = format!("{}<{}, {}, {}::curves::Stable>",
+            LIQUIDSWAP_V05_STABLE_SWAP_EVENT_TYPE, WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE, LIQUIDSWAP_V05);
+        assert!(processor.is_liquidswap_event(&type_str));
+
+        // 1000 whUSDC in for 999.5 izUSDC, 0.1 whUSDC fee
+        let event_data = serde_json::json!({
+            "x_in": "1000000000",
+            "x_out": "0",
+            "y_in": "0",
+            "y_out": "999500000",
+            "fee": "100000",
+        });
+        let swap = processor.extract_liquidswap_data(&event_data, &type_str).unwrap();
+        assert_eq!(swap.fee, Some(BigDecimal::from(100_000)));
+        assert!(swap.stable);
+
+        let mut pool_volumes = HashMap::new();
+        processor.process_liquidswap(&mut pool_volumes, swap).await;
+        let pool = &pool_volumes["USDC/USDC"];
+        assert_eq!(pool.usdc_fee_24h, BigDecimal::from_str("0.1").unwrap());
+
+        // Without the field the stable fee rate (4 bps) is used instead
+        let mut event_data = event_data;
+        event_data.as_object_mut().unwrap().remove("fee");
+        assert_eq!(LiquidSwapProcessor::extract_fee_from_event(&event_data), None);
+        let swap = processor.extract_liquidswap_data(&event_data, &type_str).unwrap();
+        let mut pool_volumes = HashMap::new();
+        processor.process_liquidswap(&mut pool_volumes, swap).await;
+        let pool = &pool_volumes["USDC/USDC"];
+        assert_eq!(pool.usdc_fee_24h, BigDecimal::from_str("0.4").unwrap());
+    }
+}
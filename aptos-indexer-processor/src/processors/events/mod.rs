@@ -1,5 +1,10 @@
 pub mod volume_calculator;
 pub mod bucket_calculator;
+pub mod anomaly_detector;
+pub mod price_validator;
+pub mod event_cache;
+pub mod block_metadata;
+pub mod checkpoint_monitor;
 pub mod cellana;
 pub mod thala;
 pub mod sushiswap;
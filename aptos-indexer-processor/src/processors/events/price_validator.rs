@@ -0,0 +1,131 @@
+use bigdecimal::{BigDecimal, FromPrimitive, Zero};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+use super::bucket_calculator::CoinVolumeData;
+
+/// Coins treated as USD quotes when deriving an implied price
+pub const USD_QUOTE_COINS: &[&str] = &["USDC", "USDT"];
+
+/// Default max/min ratio between protocols for the same coin and second.
+/// Decimal errors show up as powers of ten, so anything above 5x is almost certainly one.
+const DEFAULT_MAX_DEVIATION_RATIO: f64 = 5.0;
+
+/// Price of `base_coin` in `quote_coin` implied by a single swap
+#[derive(Debug, Clone)]
+pub struct ImpliedPrice {
+    pub source_protocol: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub price: BigDecimal,
+    // Normalized amount of `base_coin` swapped, the weight used for VWAP
+    pub base_volume: BigDecimal,
+    pub transaction_version: i64,
+    pub timestamp_seconds: i64,
+}
+
+/// Derive `(base_coin, quote_coin, price)` from the normalized coin volumes of one swap.
+/// Only swaps of a non-stable coin against a USD stablecoin produce a price.
+pub fn implied_price_from_coin_volumes(coin_volumes: &[CoinVolumeData]) -> Option<(String, String, BigDecimal)> {
+    let quote = coin_volumes.iter().find(|cv| USD_QUOTE_COINS.contains(&cv.coin.as_str()))?;
+    let base = coin_volumes.iter().find(|cv| !USD_QUOTE_COINS.contains(&cv.coin.as_str()))?;
+
+    if base.volume.is_zero() {
+        return None;
+    }
+
+    Some((base.coin.clone(), quote.coin.clone(), &quote.volume / &base.volume))
+}
+
+/// PriceCrossValidator compares implied prices reported by different protocols for the
+/// same coin in the same second and warns when they disagree by more than a ratio
+#[derive(Clone, Debug)]
+pub struct PriceCrossValidator {
+    max_deviation_ratio: BigDecimal,
+}
+
+impl Default for PriceCrossValidator {
+    fn default() -> Self {
+        Self {
+            max_deviation_ratio: BigDecimal::from_f64(DEFAULT_MAX_DEVIATION_RATIO).unwrap(),
+        }
+    }
+}
+
+impl PriceCrossValidator {
+    /// Returns the number of (coin, second) groups whose protocol prices diverge
+    pub fn validate(&self, prices: &[ImpliedPrice]) -> usize {
+        let mut by_coin_and_second: HashMap<(&str, i64), Vec<&ImpliedPrice>> = HashMap::new();
+        for price in prices {
+            by_coin_and_second
+                .entry((price.base_coin.as_str(), price.timestamp_seconds))
+                .or_default()
+                .push(price);
+        }
+
+        let mut divergent = 0;
+        for ((coin, second), group) in by_coin_and_second {
+            let (Some(lowest), Some(highest)) = (
+                group.iter().min_by(|a, b| a.price.cmp(&b.price)),
+                group.iter().max_by(|a, b| a.price.cmp(&b.price)),
+            ) else {
+                continue;
+            };
+
+            // Only disagreement between different sources is interesting here
+            if lowest.source_protocol == highest.source_protocol || lowest.price <= BigDecimal::zero() {
+                continue;
+            }
+
+            if &highest.price / &lowest.price > self.max_deviation_ratio {
+                divergent += 1;
+                warn!(
+                    "⚠️ Potential decimal error: {} price at {} is ${} on {} (version {}) but ${} on {} (version {})",
+                    coin, second,
+                    lowest.price, lowest.source_protocol, lowest.transaction_version,
+                    highest.price, highest.source_protocol, highest.transaction_version
+                );
+            } else {
+                debug!("✅ {} prices consistent across protocols at {}", coin, second);
+            }
+        }
+
+        divergent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn price(protocol: &str, value: &str, second: i64) -> ImpliedPrice {
+        ImpliedPrice {
+            source_protocol: protocol.to_string(),
+            base_coin: "APT".to_string(),
+            quote_coin: "USDC".to_string(),
+            price: BigDecimal::from_str(value).unwrap(),
+            base_volume: BigDecimal::from(1),
+            transaction_version: 1,
+            timestamp_seconds: second,
+        }
+    }
+
+    #[test]
+    fn test_implied_price_and_cross_validation() {
+        let coin_volumes = vec![
+            CoinVolumeData { coin: "APT".to_string(), volume: BigDecimal::from(100) },
+            CoinVolumeData { coin: "USDC".to_string(), volume: BigDecimal::from(642) },
+        ];
+        let (base, quote, value) = implied_price_from_coin_volumes(&coin_volumes).unwrap();
+        assert_eq!((base.as_str(), quote.as_str()), ("APT", "USDC"));
+        assert_eq!(value, BigDecimal::from_str("6.42").unwrap());
+
+        let validator = PriceCrossValidator::default();
+        // Same second, 100x apart across protocols -> flagged
+        assert_eq!(validator.validate(&[price("cellana", "6.42", 10), price("sushiswap", "640", 10)]), 1);
+        // Close prices, or a divergence in different seconds -> not flagged
+        assert_eq!(validator.validate(&[price("cellana", "6.42", 10), price("sushiswap", "6.45", 10)]), 0);
+        assert_eq!(validator.validate(&[price("cellana", "6.42", 10), price("sushiswap", "640", 11)]), 0);
+    }
+}
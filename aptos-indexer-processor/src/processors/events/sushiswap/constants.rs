@@ -1,5 +1,14 @@
 // SushiSwap constants
-pub const SUSHISWAP_SWAP_EVENT_TYPE: &str = "0x31a6675cbe84365bf2b0cbce617ece6c47023ef70826533bde5203d32171dc3c::swap::SwapEvent";
+// Swap module address per contract version; empty until that version is deployed
+pub const SUSHISWAP_V1_CONTRACT_ADDRESS: &str = "0x31a6675cbe84365bf2b0cbce617ece6c47023ef70826533bde5203d32171dc3c";
+pub const SUSHISWAP_V2_CONTRACT_ADDRESS: &str = "";
+pub const SUSHISWAP_CONTRACT_ADDRESSES: [(u8, &str); 2] = [
+    (1, SUSHISWAP_V1_CONTRACT_ADDRESS),
+    (2, SUSHISWAP_V2_CONTRACT_ADDRESS),
+];
+pub const SUSHISWAP_SWAP_EVENT_SUFFIX: &str = "::swap::SwapEvent";
+// Emitted after each swap with the pair's updated reserves
+pub const SUSHISWAP_SYNC_EVENT_SUFFIX: &str = "::swap::SyncEvent";
 
 // SushiSwap coin types (different from Cellana/Thala)
 pub const APT_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
@@ -1,5 +1,7 @@
 use super::constants::{
-    SUSHISWAP_SWAP_EVENT_TYPE,
+    SUSHISWAP_CONTRACT_ADDRESSES,
+    SUSHISWAP_SWAP_EVENT_SUFFIX,
+    SUSHISWAP_SYNC_EVENT_SUFFIX,
     APT_COIN_TYPE,
     IZUSDT_COIN_TYPE,  // izUSDT but tracked as USDT in database
     IZUSDC_COIN_TYPE,  // izUSDC but tracked as USDC in database
@@ -10,13 +12,17 @@ use super::constants::{
     USDC_DECIMALS,
     WETH_DECIMALS,
 };
+use crate::utils::{
+    math::{clamp_precision, is_positive, MAX_VOLUME_SCALE},
+    pool_registry::PoolRegistry,
+};
 use anyhow::Result;
 use bigdecimal::{BigDecimal, Zero, FromPrimitive};
 use serde_json;
 use std::{collections::HashMap, str::FromStr};
 use tracing::{info, debug};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SushiSwapData {
     pub amount_x_in: String,
     pub amount_x_out: String,
@@ -30,6 +36,7 @@ pub struct SushiSwapData {
 #[derive(Debug)]
 pub struct SushiPoolVolume {
     pub pair: String,
+    pub trade_count: i64,
     pub apt_volume_24h: BigDecimal,
     pub usdt_volume_24h: BigDecimal,
     pub usdc_volume_24h: BigDecimal,  // Add USDC support
@@ -47,6 +54,7 @@ pub struct SushiPoolVolume {
 }
 
 // Cached decimal divisors for performance
+#[derive(Clone)]
 struct SushiDecimalDivisors {
     apt: BigDecimal,
     usdt: BigDecimal,
@@ -69,6 +77,7 @@ impl Default for SushiPoolVolume {
     fn default() -> Self {
         Self {
             pair: String::new(),
+            trade_count: 0,
             apt_volume_24h: BigDecimal::from(0),
             usdt_volume_24h: BigDecimal::from(0),
             usdc_volume_24h: BigDecimal::from(0),
@@ -85,6 +94,29 @@ impl Default for SushiPoolVolume {
     }
 }
 
+impl SushiPoolVolume {
+    /// Round every accumulated amount to `MAX_VOLUME_SCALE` decimal places
+    pub fn clamp_precision(&mut self) {
+        for amount in [
+            &mut self.apt_volume_24h,
+            &mut self.usdt_volume_24h,
+            &mut self.usdc_volume_24h,
+            &mut self.weth_volume_24h,
+            &mut self.apt_buy_volume_24h,
+            &mut self.apt_sell_volume_24h,
+            &mut self.usdt_buy_volume_24h,
+            &mut self.usdt_sell_volume_24h,
+            &mut self.usdc_buy_volume_24h,
+            &mut self.usdc_sell_volume_24h,
+            &mut self.weth_buy_volume_24h,
+            &mut self.weth_sell_volume_24h,
+        ] {
+            *amount = clamp_precision(std::mem::take(amount), MAX_VOLUME_SCALE);
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct SushiSwapProcessor {
     divisors: SushiDecimalDivisors,
 }
@@ -195,33 +227,45 @@ impl SushiSwapProcessor {
         is_apt_izusdt || is_apt_izusdc || is_apt_whusdc || is_apt_izweth || is_izweth_izusdc || is_whusdc_izusdc
     }
 
+    /// False for no-op events some routers emit with all four amounts zero
+    pub fn is_meaningful_swap(data: &SushiSwapData) -> bool {
+        [&data.amount_x_in, &data.amount_x_out, &data.amount_y_in, &data.amount_y_out]
+            .iter()
+            .any(|amount| BigDecimal::from_str(amount).is_ok_and(|amount| !amount.is_zero()))
+    }
+
+    /// Volume-weighted average price `sum(price * volume) / sum(volume)` of `(price, volume)`
+    /// swaps on `pair`; zero when there is no volume
+    pub fn compute_vwap(pair: &str, swaps: &[(BigDecimal, BigDecimal)]) -> BigDecimal {
+        let (weighted_sum, total_volume) = swaps.iter().fold(
+            (BigDecimal::zero(), BigDecimal::zero()),
+            |(weighted_sum, total_volume), (price, volume)| (weighted_sum + price * volume, total_volume + volume),
+        );
+
+        if total_volume.is_zero() {
+            debug!("⏭️ No volume for {} VWAP", pair);
+            return BigDecimal::zero();
+        }
+        weighted_sum / total_volume
+    }
+
     pub async fn process_sushiswap(&self, pool_volumes: &mut HashMap<String, SushiPoolVolume>, swap_data: SushiSwapData) {
+        if !Self::is_meaningful_swap(&swap_data) {
+            debug!("⏭️ Skipping zero-amount SushiSwap event: {} / {}", swap_data.token_x, swap_data.token_y);
+            return;
+        }
+
         // Only process supported pairs
         if !self.is_supported_pair(&swap_data.token_x, &swap_data.token_y) {
             debug!("🚫 Unsupported pair: {} / {}", swap_data.token_x, swap_data.token_y);
             return;
         }
 
-        // Create a unique identifier for the pair (always in consistent order)
-        let pair_key = if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDT_COIN_TYPE) ||
-                          (swap_data.token_x == IZUSDT_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDT".to_string()
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE) ||
-                  (swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDC".to_string()
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == WHUSDC_COIN_TYPE) ||
-                  (swap_data.token_x == WHUSDC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/USDC".to_string()  // whUSDC also tracked as USDC
-        } else if (swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZWETH_COIN_TYPE) ||
-                  (swap_data.token_x == IZWETH_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE) {
-            "APT/WETH".to_string()  // izWETH also tracked as WETH
-        } else if (swap_data.token_x == IZWETH_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE) ||
-                  (swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == IZWETH_COIN_TYPE) {
-            "WETH/USDC".to_string()  // izWETH/izUSDC pair
-        } else if (swap_data.token_x == WHUSDC_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE) ||
-                  (swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == WHUSDC_COIN_TYPE) {
-            "whUSDC/izUSDC".to_string()  // whUSDC/izUSDC pair - both stored as USDC
-        } else {
+        // Canonical pair name (always the same for either token order)
+        let Some(pair_key) = PoolRegistry::global()
+            .get_pair_name("sushiswap", &swap_data.token_x, &swap_data.token_y)
+            .map(str::to_string)
+        else {
             return; // Shouldn't happen due to is_supported_pair check
         };
 
@@ -232,6 +276,7 @@ impl SushiSwapProcessor {
                 ..Default::default()
             }
         });
+        pool_entry.trade_count += 1;
 
         // Parse amounts
         let amount_x_in = BigDecimal::from_str(&swap_data.amount_x_in).unwrap_or_else(|_| BigDecimal::zero());
@@ -239,43 +284,83 @@ impl SushiSwapProcessor {
         let amount_y_in = BigDecimal::from_str(&swap_data.amount_y_in).unwrap_or_else(|_| BigDecimal::zero());
         let amount_y_out = BigDecimal::from_str(&swap_data.amount_y_out).unwrap_or_else(|_| BigDecimal::zero());
 
-        // Determine swap direction and process volume
-        if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDT_COIN_TYPE {
+        // Both directions in one event, e.g. when a router extracts its fee in the other token
+        let zero = BigDecimal::zero();
+        if [&amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out].iter().all(|amount| **amount > zero) {
+            self.process_dual_direction_swap(pool_entry, &swap_data.token_x, &swap_data.token_y, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
+        } else {
+            self.process_pair_swap(pool_entry, &swap_data.token_x, &swap_data.token_y, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
+        }
+        pool_entry.clamp_precision();
+    }
+
+    /// Process an event with all four amounts set as an X→Y swap and a Y→X swap,
+    /// adding volume for both input tokens
+    #[allow(clippy::too_many_arguments)]
+    async fn process_dual_direction_swap(
+        &self,
+        pool_entry: &mut SushiPoolVolume,
+        token_x: &str,
+        token_y: &str,
+        amount_x_in: &BigDecimal,
+        amount_x_out: &BigDecimal,
+        amount_y_in: &BigDecimal,
+        amount_y_out: &BigDecimal,
+    ) {
+        info!("🔀 SushiSwap dual-direction swap on {}: processing X→Y and Y→X", pool_entry.pair);
+        let zero = BigDecimal::zero();
+        self.process_pair_swap(pool_entry, token_x, token_y, amount_x_in, &zero, &zero, amount_y_out).await;
+        self.process_pair_swap(pool_entry, token_x, token_y, &zero, amount_x_out, amount_y_in, &zero).await;
+    }
+
+    /// Route a swap to the handler for its token order
+    #[allow(clippy::too_many_arguments)]
+    async fn process_pair_swap(
+        &self,
+        pool_entry: &mut SushiPoolVolume,
+        token_x: &str,
+        token_y: &str,
+        amount_x_in: &BigDecimal,
+        amount_x_out: &BigDecimal,
+        amount_y_in: &BigDecimal,
+        amount_y_out: &BigDecimal,
+    ) {
+        if token_x == APT_COIN_TYPE && token_y == IZUSDT_COIN_TYPE {
             // Token X = APT, Token Y = izUSDT (but track as USDT in database)
-            self.process_apt_izusdt_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZUSDT_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            self.process_apt_izusdt_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZUSDT_COIN_TYPE && token_y == APT_COIN_TYPE {
             // Token X = izUSDT, Token Y = APT (swapped order)
-            self.process_izusdt_apt_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE {
+            self.process_izusdt_apt_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == APT_COIN_TYPE && token_y == IZUSDC_COIN_TYPE {
             // Token X = APT, Token Y = izUSDC (but track as USDC in database)
-            self.process_apt_izusdc_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            self.process_apt_izusdc_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZUSDC_COIN_TYPE && token_y == APT_COIN_TYPE {
             // Token X = izUSDC, Token Y = APT (swapped order)
-            self.process_izusdc_apt_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == WHUSDC_COIN_TYPE {
+            self.process_izusdc_apt_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == APT_COIN_TYPE && token_y == WHUSDC_COIN_TYPE {
             // Token X = APT, Token Y = whUSDC (but track as USDC in database)
-            self.process_apt_whusdc_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == WHUSDC_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            self.process_apt_whusdc_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == WHUSDC_COIN_TYPE && token_y == APT_COIN_TYPE {
             // Token X = whUSDC, Token Y = APT (swapped order)
-            self.process_whusdc_apt_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == APT_COIN_TYPE && swap_data.token_y == IZWETH_COIN_TYPE {
+            self.process_whusdc_apt_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == APT_COIN_TYPE && token_y == IZWETH_COIN_TYPE {
             // Token X = APT, Token Y = izWETH (but track as WETH in database)
-            self.process_apt_izweth_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZWETH_COIN_TYPE && swap_data.token_y == APT_COIN_TYPE {
+            self.process_apt_izweth_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZWETH_COIN_TYPE && token_y == APT_COIN_TYPE {
             // Token X = izWETH, Token Y = APT (swapped order)
-            self.process_izweth_apt_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZWETH_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE {
+            self.process_izweth_apt_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZWETH_COIN_TYPE && token_y == IZUSDC_COIN_TYPE {
             // Token X = izWETH, Token Y = izUSDC
-            self.process_izweth_izusdc_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == IZWETH_COIN_TYPE {
+            self.process_izweth_izusdc_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZUSDC_COIN_TYPE && token_y == IZWETH_COIN_TYPE {
             // Token X = izUSDC, Token Y = izWETH (swapped order)
-            self.process_izusdc_izweth_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == WHUSDC_COIN_TYPE && swap_data.token_y == IZUSDC_COIN_TYPE {
+            self.process_izusdc_izweth_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == WHUSDC_COIN_TYPE && token_y == IZUSDC_COIN_TYPE {
             // Token X = whUSDC, Token Y = izUSDC (both stored as USDC)
-            self.process_whusdc_izusdc_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
-        } else if swap_data.token_x == IZUSDC_COIN_TYPE && swap_data.token_y == WHUSDC_COIN_TYPE {
+            self.process_whusdc_izusdc_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
+        } else if token_x == IZUSDC_COIN_TYPE && token_y == WHUSDC_COIN_TYPE {
             // Token X = izUSDC, Token Y = whUSDC (swapped order, both stored as USDC)
-            self.process_izusdc_whusdc_sushiswap(pool_entry, &amount_x_in, &amount_x_out, &amount_y_in, &amount_y_out).await;
+            self.process_izusdc_whusdc_sushiswap(pool_entry, amount_x_in, amount_x_out, amount_y_in, amount_y_out).await;
         }
     }
 
@@ -289,7 +374,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // APT → izUSDT: User sells APT (X) and receives izUSDT (Y)
             let apt_amount = amount_x_in / &self.divisors.apt;
             let izusdt_amount = amount_y_out / &self.divisors.usdt;
@@ -305,7 +390,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap APT→izUSDT: {} APT sold, {} izUSDT received", 
                 apt_amount, izusdt_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izUSDT → APT: User sells izUSDT (Y) and receives APT (X)
             let izusdt_amount = amount_y_in / &self.divisors.usdt;
             let apt_amount = amount_x_out / &self.divisors.apt;
@@ -333,7 +418,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izUSDT → APT: User sells izUSDT (X) and receives APT (Y)
             let izusdt_amount = amount_x_in / &self.divisors.usdt;
             let apt_amount = amount_y_out / &self.divisors.apt;
@@ -349,7 +434,7 @@ impl SushiSwapProcessor {
             info!("📈 SushiSwap izUSDT→APT: {} izUSDT sold, {} APT received", 
                 izusdt_amount, apt_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // APT → izUSDT: User sells APT (Y) and receives izUSDT (X)
             let apt_amount = amount_y_in / &self.divisors.apt;
             let izusdt_amount = amount_x_out / &self.divisors.usdt;
@@ -377,7 +462,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // APT → izUSDC: User sells APT (X) and receives izUSDC (Y)
             let apt_amount = amount_x_in / &self.divisors.apt;
             let izusdc_amount = amount_y_out / &self.divisors.usdc;
@@ -393,7 +478,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap APT→izUSDC: {} APT sold, {} izUSDC received", 
                 apt_amount, izusdc_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izUSDC → APT: User sells izUSDC (Y) and receives APT (X)
             let izusdc_amount = amount_y_in / &self.divisors.usdc;
             let apt_amount = amount_x_out / &self.divisors.apt;
@@ -421,7 +506,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izUSDC → APT: User sells izUSDC (X) and receives APT (Y)
             let izusdc_amount = amount_x_in / &self.divisors.usdc;
             let apt_amount = amount_y_out / &self.divisors.apt;
@@ -437,7 +522,7 @@ impl SushiSwapProcessor {
             info!("📈 SushiSwap izUSDC→APT: {} izUSDC sold, {} APT received", 
                 izusdc_amount, apt_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // APT → izUSDC: User sells APT (Y) and receives izUSDC (X)
             let apt_amount = amount_y_in / &self.divisors.apt;
             let izusdc_amount = amount_x_out / &self.divisors.usdc;
@@ -465,7 +550,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // APT → whUSDC: User sells APT (X) and receives whUSDC (Y)
             let apt_amount = amount_x_in / &self.divisors.apt;
             let whusdc_amount = amount_y_out / &self.divisors.usdc;
@@ -481,7 +566,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap APT→whUSDC: {} APT sold, {} whUSDC received", 
                 apt_amount, whusdc_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // whUSDC → APT: User sells whUSDC (Y) and receives APT (X)
             let whusdc_amount = amount_y_in / &self.divisors.usdc;
             let apt_amount = amount_x_out / &self.divisors.apt;
@@ -509,7 +594,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // whUSDC → APT: User sells whUSDC (X) and receives APT (Y)
             let whusdc_amount = amount_x_in / &self.divisors.usdc;
             let apt_amount = amount_y_out / &self.divisors.apt;
@@ -525,7 +610,7 @@ impl SushiSwapProcessor {
             info!("📈 SushiSwap whUSDC→APT: {} whUSDC sold, {} APT received", 
                 whusdc_amount, apt_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // APT → whUSDC: User sells APT (Y) and receives whUSDC (X)
             let apt_amount = amount_y_in / &self.divisors.apt;
             let whusdc_amount = amount_x_out / &self.divisors.usdc;
@@ -553,7 +638,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // APT → izWETH: User sells APT (X) and receives izWETH (Y)
             let apt_amount = amount_x_in / &self.divisors.apt;
             let izweth_amount = amount_y_out / &self.divisors.weth;
@@ -569,7 +654,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap APT→izWETH: {} APT sold, {} izWETH received", 
                 apt_amount, izweth_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izWETH → APT: User sells izWETH (Y) and receives APT (X)
             let izweth_amount = amount_y_in / &self.divisors.weth;
             let apt_amount = amount_x_out / &self.divisors.apt;
@@ -597,7 +682,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izWETH → APT: User sells izWETH (X) and receives APT (Y)
             let izweth_amount = amount_x_in / &self.divisors.weth;
             let apt_amount = amount_y_out / &self.divisors.apt;
@@ -613,7 +698,7 @@ impl SushiSwapProcessor {
             info!("📈 SushiSwap izWETH→APT: {} izWETH sold, {} APT received", 
                 izweth_amount, apt_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // APT → izWETH: User sells APT (Y) and receives izWETH (X)
             let apt_amount = amount_y_in / &self.divisors.apt;
             let izweth_amount = amount_x_out / &self.divisors.weth;
@@ -641,7 +726,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izWETH → izUSDC: User sells izWETH (X) and receives izUSDC (Y)
             let izweth_amount = amount_x_in / &self.divisors.weth;
             let izusdc_amount = amount_y_out / &self.divisors.usdc;
@@ -657,7 +742,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap izWETH→izUSDC: {} izWETH sold, {} izUSDC received", 
                 izweth_amount, izusdc_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izUSDC → izWETH: User sells izUSDC (Y) and receives izWETH (X)
             let izusdc_amount = amount_y_in / &self.divisors.usdc;
             let izweth_amount = amount_x_out / &self.divisors.weth;
@@ -685,7 +770,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // whUSDC → izUSDC: User sells whUSDC (X) and receives izUSDC (Y)
             let whusdc_amount = amount_x_in / &self.divisors.usdc;
             let izusdc_amount = amount_y_out / &self.divisors.usdc;
@@ -701,7 +786,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap whUSDC→izUSDC: {} whUSDC sold, {} izUSDC received", 
                 whusdc_amount, izusdc_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izUSDC → whUSDC: User sells izUSDC (Y) and receives whUSDC (X)
             let izusdc_amount = amount_y_in / &self.divisors.usdc;
             let whusdc_amount = amount_x_out / &self.divisors.usdc;
@@ -729,7 +814,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izUSDC → whUSDC: User sells izUSDC (X) and receives whUSDC (Y)
             let izusdc_amount = amount_x_in / &self.divisors.usdc;
             let whusdc_amount = amount_y_out / &self.divisors.usdc;
@@ -745,7 +830,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap izUSDC→whUSDC: {} izUSDC sold, {} whUSDC received", 
                 izusdc_amount, whusdc_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // whUSDC → izUSDC: User sells whUSDC (Y) and receives izUSDC (X)
             let whusdc_amount = amount_y_in / &self.divisors.usdc;
             let izusdc_amount = amount_x_out / &self.divisors.usdc;
@@ -773,7 +858,7 @@ impl SushiSwapProcessor {
         amount_y_out: &BigDecimal,
     ) {
         // Determine swap direction based on non-zero amounts
-        if amount_x_in > &BigDecimal::zero() && amount_y_out > &BigDecimal::zero() {
+        if is_positive(amount_x_in) && is_positive(amount_y_out) {
             // izUSDC → izWETH: User sells izUSDC (X) and receives izWETH (Y)
             let izusdc_amount = amount_x_in / &self.divisors.usdc;
             let izweth_amount = amount_y_out / &self.divisors.weth;
@@ -789,7 +874,7 @@ impl SushiSwapProcessor {
             info!("📉 SushiSwap izUSDC→izWETH: {} izUSDC sold, {} izWETH received", 
                 izusdc_amount, izweth_amount);
                 
-        } else if amount_y_in > &BigDecimal::zero() && amount_x_out > &BigDecimal::zero() {
+        } else if is_positive(amount_y_in) && is_positive(amount_x_out) {
             // izWETH → izUSDC: User sells izWETH (Y) and receives izUSDC (X)
             let izweth_amount = amount_y_in / &self.divisors.weth;
             let izusdc_amount = amount_x_out / &self.divisors.usdc;
@@ -807,7 +892,237 @@ impl SushiSwapProcessor {
         }
     }
 
+    /// Price of the base coin in the stablecoin implied by a swap, used to cross-validate
+    /// prices between protocols. Returns `(base_coin, quote_coin, price)`, e.g. 100 APT in
+    /// for 642 izUSDC out gives `("APT", "USDC", 6.42)`. Stable/stable pairs return None.
+    pub fn get_implied_price(&self, data: &SushiSwapData) -> Option<(String, String, BigDecimal)> {
+        let (symbol_x, divisor_x) = self.coin_symbol_and_divisor(&data.token_x)?;
+        let (symbol_y, divisor_y) = self.coin_symbol_and_divisor(&data.token_y)?;
+
+        let parse = |amount: &str| BigDecimal::from_str(amount).unwrap_or_else(|_| BigDecimal::zero());
+        let x_in = parse(&data.amount_x_in);
+        let y_in = parse(&data.amount_y_in);
+        let amount_x = (if is_positive(&x_in) { x_in } else { parse(&data.amount_x_out) }) / divisor_x;
+        let amount_y = (if is_positive(&y_in) { y_in } else { parse(&data.amount_y_out) }) / divisor_y;
+
+        let is_stable = |symbol: &str| symbol == "USDC" || symbol == "USDT";
+        let (base, base_amount, quote, quote_amount) = match (is_stable(symbol_x), is_stable(symbol_y)) {
+            (false, true) => (symbol_x, amount_x, symbol_y, amount_y),
+            (true, false) => (symbol_y, amount_y, symbol_x, amount_x),
+            _ => return None,
+        };
+
+        if base_amount.is_zero() {
+            return None;
+        }
+
+        Some((base.to_string(), quote.to_string(), quote_amount / base_amount))
+    }
+
+    /// Database coin symbol and decimal divisor for a supported SushiSwap coin type
+    fn coin_symbol_and_divisor(&self, token_type: &str) -> Option<(&'static str, &BigDecimal)> {
+        match token_type {
+            APT_COIN_TYPE => Some(("APT", &self.divisors.apt)),
+            IZUSDT_COIN_TYPE => Some(("USDT", &self.divisors.usdt)),
+            IZUSDC_COIN_TYPE | WHUSDC_COIN_TYPE => Some(("USDC", &self.divisors.usdc)),
+            IZWETH_COIN_TYPE => Some(("WETH", &self.divisors.weth)),
+            _ => None,
+        }
+    }
+
     pub fn is_sushiswap_event(&self, type_str: &str) -> bool {
-        type_str.contains(SUSHISWAP_SWAP_EVENT_TYPE)
+        sushi_contract_version_from_type_str(type_str).is_some()
+    }
+
+    pub fn is_sync_event(&self, type_str: &str) -> bool {
+        sushi_contract_version_for_suffix(type_str, SUSHISWAP_SYNC_EVENT_SUFFIX).is_some()
+    }
+
+    /// `(reserve0, reserve1)` of a pair from its `SyncEvent`, raw (not decimal-normalized)
+    /// and in the order of the event's type parameters
+    pub fn handle_sync_event(event_data: &serde_json::Value, pair_key: &str) -> Option<(BigDecimal, BigDecimal)> {
+        let reserve = |field: &str, fallback: &str| {
+            event_data
+                .get(field)
+                .or_else(|| event_data.get(fallback))
+                .and_then(|v| v.as_str())
+                .and_then(|v| BigDecimal::from_str(v).ok())
+        };
+        let reserves = reserve("reserve0", "reserve_x").zip(reserve("reserve1", "reserve_y"));
+        if reserves.is_none() {
+            debug!("❌ SushiSwap SyncEvent for {} has no reserves", pair_key);
+        }
+        reserves
     }
-} 
\ No newline at end of file
+}
+
+/// Contract version (1 or 2) of a SushiSwap swap event, or None if it is not one.
+/// Addresses that are not deployed yet (empty) never match.
+pub fn sushi_contract_version_from_type_str(type_str: &str) -> Option<u8> {
+    sushi_contract_version_for_suffix(type_str, SUSHISWAP_SWAP_EVENT_SUFFIX)
+}
+
+fn sushi_contract_version_for_suffix(type_str: &str, suffix: &str) -> Option<u8> {
+    let event_type = type_str.split('<').next().unwrap_or(type_str).trim();
+    SUSHISWAP_CONTRACT_ADDRESSES
+        .iter()
+        .filter(|(_, address)| !address.is_empty())
+        .find(|(_, address)| event_type.strip_prefix(address) == Some(suffix))
+        .map(|(version, _)| *version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processors::events::sushiswap::constants::SUSHISWAP_V1_CONTRACT_ADDRESS;
+    use proptest::prelude::*;
+
+    const SUPPORTED_PAIRS: [(&str, &str); 6] = [
+        (APT_COIN_TYPE, IZUSDT_COIN_TYPE),
+        (APT_COIN_TYPE, IZUSDC_COIN_TYPE),
+        (APT_COIN_TYPE, WHUSDC_COIN_TYPE),
+        (APT_COIN_TYPE, IZWETH_COIN_TYPE),
+        (IZWETH_COIN_TYPE, IZUSDC_COIN_TYPE),
+        (WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE),
+    ];
+
+    fn swap_on_pair(pair: usize, flipped: bool, [x_in, x_out, y_in, y_out]: [u64; 4]) -> SushiSwapData {
+        let (token_x, token_y) = SUPPORTED_PAIRS[pair];
+        let (token_x, token_y) = if flipped { (token_y, token_x) } else { (token_x, token_y) };
+        SushiSwapData {
+            amount_x_in: x_in.to_string(),
+            amount_x_out: x_out.to_string(),
+            amount_y_in: y_in.to_string(),
+            amount_y_out: y_out.to_string(),
+            token_x: token_x.to_string(),
+            token_y: token_y.to_string(),
+            user: "0xuser".to_string(),
+        }
+    }
+
+    fn volume_fields(pool: &SushiPoolVolume) -> [BigDecimal; 12] {
+        [
+            &pool.apt_volume_24h, &pool.usdt_volume_24h, &pool.usdc_volume_24h, &pool.weth_volume_24h,
+            &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h, &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h,
+            &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h, &pool.weth_buy_volume_24h, &pool.weth_sell_volume_24h,
+        ]
+        .map(BigDecimal::clone)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn test_volumes_stay_non_negative_and_never_decrease(
+            (first_pair, first_flipped, first_amounts) in (0..SUPPORTED_PAIRS.len(), any::<bool>(), any::<[u64; 4]>()),
+            (second_pair, second_flipped, second_amounts) in (0..SUPPORTED_PAIRS.len(), any::<bool>(), any::<[u64; 4]>()),
+        ) {
+            let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            let processor = SushiSwapProcessor::new();
+            let mut pool_volumes = HashMap::new();
+
+            runtime.block_on(processor.process_sushiswap(&mut pool_volumes, swap_on_pair(first_pair, first_flipped, first_amounts)));
+            let after_first: HashMap<String, [BigDecimal; 12]> = pool_volumes
+                .iter()
+                .map(|(pair, pool)| (pair.clone(), volume_fields(pool)))
+                .collect();
+            for fields in after_first.values() {
+                prop_assert!(fields.iter().all(|volume| volume >= &BigDecimal::zero()));
+            }
+
+            runtime.block_on(processor.process_sushiswap(&mut pool_volumes, swap_on_pair(second_pair, second_flipped, second_amounts)));
+            for (pair, pool) in &pool_volumes {
+                let after_second = volume_fields(pool);
+                prop_assert!(after_second.iter().all(|volume| volume >= &BigDecimal::zero()));
+                if let Some(previous) = after_first.get(pair) {
+                    for (before, after) in previous.iter().zip(after_second.iter()) {
+                        prop_assert!(after >= before, "{} decreased from {} to {}", pair, before, after);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_contract_version_from_type_str() {
+        let v1 = format!(
+            "{}::swap::SwapEvent<{}, {}>",
+            SUSHISWAP_V1_CONTRACT_ADDRESS, APT_COIN_TYPE, IZUSDT_COIN_TYPE
+        );
+        assert_eq!(sushi_contract_version_from_type_str(&v1), Some(1));
+        assert!(SushiSwapProcessor::new().is_sushiswap_event(&v1));
+
+        // Other modules at the same address and unknown addresses are not swap events
+        let liquidity = format!("{}::swap::LiquidityEvent<{}, {}>", SUSHISWAP_V1_CONTRACT_ADDRESS, APT_COIN_TYPE, IZUSDT_COIN_TYPE);
+        assert_eq!(sushi_contract_version_from_type_str(&liquidity), None);
+        assert_eq!(sushi_contract_version_from_type_str("0xabc::swap::SwapEvent<A, B>"), None);
+        // The V2 address is empty until deployed, so a bare suffix must not match it
+        assert_eq!(sushi_contract_version_from_type_str("::swap::SwapEvent"), None);
+    }
+
+    #[test]
+    fn test_sync_event_reserves() {
+        let processor = SushiSwapProcessor::new();
+        let sync = format!("{}::swap::SyncEvent<{}, {}>", SUSHISWAP_V1_CONTRACT_ADDRESS, APT_COIN_TYPE, IZUSDT_COIN_TYPE);
+        assert!(processor.is_sync_event(&sync));
+        assert!(!processor.is_sushiswap_event(&sync));
+
+        let event_data = serde_json::json!({"reserve0": "250000000000", "reserve1": "1200000000"});
+        assert_eq!(
+            SushiSwapProcessor::handle_sync_event(&event_data, "APT/USDT"),
+            Some((BigDecimal::from(250_000_000_000u64), BigDecimal::from(1_200_000_000u64)))
+        );
+        assert_eq!(SushiSwapProcessor::handle_sync_event(&serde_json::json!({"reserve0": "1"}), "APT/USDT"), None);
+    }
+
+    #[test]
+    fn test_zero_for_zero_swap_is_not_meaningful() {
+        let mut swap = SushiSwapData {
+            amount_x_in: "0".to_string(),
+            amount_x_out: "0".to_string(),
+            amount_y_in: "0".to_string(),
+            amount_y_out: "0".to_string(),
+            token_x: APT_COIN_TYPE.to_string(),
+            token_y: IZUSDT_COIN_TYPE.to_string(),
+            user: "0x1234".to_string(),
+        };
+        assert!(!SushiSwapProcessor::is_meaningful_swap(&swap));
+
+        swap.amount_y_out = "1500000".to_string();
+        assert!(SushiSwapProcessor::is_meaningful_swap(&swap));
+    }
+
+    #[test]
+    fn test_compute_vwap_weights_by_volume() {
+        let swaps = vec![
+            (BigDecimal::from(6), BigDecimal::from(300)),
+            (BigDecimal::from(7), BigDecimal::from(100)),
+        ];
+        assert_eq!(SushiSwapProcessor::compute_vwap("APT/USDC", &swaps), BigDecimal::from_str("6.25").unwrap());
+        assert_eq!(SushiSwapProcessor::compute_vwap("APT/USDC", &[]), BigDecimal::zero());
+    }
+
+    #[tokio::test]
+    async fn test_dual_direction_swap_counts_both_inputs() {
+        let processor = SushiSwapProcessor::new();
+        let mut pool_volumes = HashMap::new();
+        let swap = SushiSwapData {
+            amount_x_in: "100000000".to_string(),
+            amount_x_out: "10000000".to_string(),
+            amount_y_in: "500000".to_string(),
+            amount_y_out: "5000000".to_string(),
+            token_x: APT_COIN_TYPE.to_string(),
+            token_y: IZUSDT_COIN_TYPE.to_string(),
+            user: "0x1234".to_string(),
+        };
+        processor.process_sushiswap(&mut pool_volumes, swap).await;
+
+        let volume = pool_volumes.values().next().unwrap();
+        assert_eq!(volume.trade_count, 1);
+        // 1 APT sold for 5 izUSDT, and 0.5 izUSDT sold for 0.1 APT
+        assert_eq!(volume.apt_sell_volume_24h, BigDecimal::from(1));
+        assert_eq!(volume.usdt_sell_volume_24h, BigDecimal::from_str("0.5").unwrap());
+        assert_eq!(volume.apt_volume_24h, BigDecimal::from_str("1.1").unwrap());
+        assert_eq!(volume.usdt_volume_24h, BigDecimal::from_str("5.5").unwrap());
+    }
+}
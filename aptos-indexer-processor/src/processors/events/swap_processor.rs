@@ -1,14 +1,25 @@
 use crate::{
     common::processor_status_saver::get_processor_status_saver,
-    config::indexer_processor_config::IndexerProcessorConfig,
-    processors::tasmil_processor::TasmilProcessor,
+    config::{indexer_processor_config::IndexerProcessorConfig, protocol_toggles::ProtocolToggles},
+    processors::{
+        events::{
+            anomaly_detector::VolumeAnomalyDetector,
+            bucket_calculator::BucketCalculator,
+            checkpoint_monitor::{spawn_checkpoint_monitor, CHECKPOINT_CHANNEL_CAPACITY},
+            volume_calculator::VolumeCalculator,
+        },
+        protocol_health_monitor::{spawn_protocol_health_monitor, ProtocolHealthMonitor},
+        tasmil_processor::TasmilProcessor,
+    },
     utils::{
-        chain_id::check_or_update_chain_id,
-        database::{new_db_pool, run_migrations, ArcDbPool},
+        chain_id::{check_or_update_chain_id, verify_chain_id},
+        metrics::record_token_decimals,
+        database::{new_db_pool_with_options, run_migrations, ArcDbPool, MultiPoolConfig},
+        migration_checker::verify_required_tables,
         starting_version::get_starting_version,
     },
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use aptos_indexer_processor_sdk::{
     aptos_indexer_transaction_stream::{TransactionStream, TransactionStreamConfig},
     builder::ProcessorBuilder,
@@ -17,12 +28,14 @@ use aptos_indexer_processor_sdk::{
     },
     traits::IntoRunnableStep,
 };
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, Mutex};
+use tokio::sync::broadcast;
 use tracing::{info, warn};
 
 pub struct SwapProcessor {
     pub config: IndexerProcessorConfig,
     pub db_pool: ArcDbPool,
+    pub read_db_pool: Option<ArcDbPool>,
 }
 
 impl SwapProcessor {
@@ -30,23 +43,38 @@ impl SwapProcessor {
         info!("🚀 Initializing SwapProcessor for Cellana and Thala");
         info!("📊 Processor type: {}", config.processor_config.name());
         
-        let conn_pool = new_db_pool(
+        let conn_pool = new_db_pool_with_options(
             &config.db_config.postgres_connection_string,
-            Some(config.db_config.db_pool_size),
+            &config.db_config.pool_options(),
         )
         .await
         .expect("Failed to create connection pool");
         
-        info!("🔌 Database connection pool created with size: {}", config.db_config.db_pool_size);
+        info!("🔌 Database connection pool created with size: {} (min idle: {:?}, timeout: {}s)",
+            config.db_config.db_pool_size, config.db_config.min_idle, config.db_config.connection_timeout_seconds);
+
+        // Optional read replica, sized like the primary pool
+        let read_db_pool = match &config.database_read_url {
+            Some(read_url) => {
+                let pool = new_db_pool_with_options(read_url, &config.db_config.pool_options())
+                    .await
+                    .context("Failed to create read replica connection pool")?;
+                info!("📖 Read replica connection pool created; queries will use the replica");
+                Some(pool)
+            },
+            None => None,
+        };
 
         Ok(Self {
             config,
             db_pool: conn_pool,
+            read_db_pool,
         })
     }
 
     pub async fn run_processor(self) -> Result<()> {
         info!("▶️ Starting SwapProcessor for multi-protocol indexing");
+        record_token_decimals();
         
         // Run migrations
         info!("🔄 Running database migrations");
@@ -55,6 +83,7 @@ impl SwapProcessor {
             self.db_pool.clone(),
         )
         .await;
+        verify_required_tables(&self.db_pool).await?;
 
         // Merge the starting version from config and the latest processed version from the DB
         let starting_version = get_starting_version(&self.config, self.db_pool.clone()).await?;
@@ -69,6 +98,8 @@ impl SwapProcessor {
         info!("⛓️ Chain ID from gRPC: {}", grpc_chain_id);
         
         check_or_update_chain_id(grpc_chain_id as i64, self.db_pool.clone()).await?;
+        // Mandatory: never write volumes from a network other than the configured one
+        verify_chain_id(&self.db_pool, self.config.expected_chain_id).await?;
 
         // Define processor steps
         let transaction_stream_config = self.config.transaction_stream_config.clone();
@@ -83,9 +114,54 @@ impl SwapProcessor {
         // Create notification channel
         let (notification_sender, notification_receiver) = mpsc::channel();
 
+        // Protocol enable/disable switches, optionally hot-reloaded from disk
+        let protocol_toggles = ProtocolToggles::new(self.config.protocols.clone());
+        let _protocol_config_watcher = match &self.config.protocols_reload_path {
+            Some(path) => Some(protocol_toggles.watch(path)?),
+            None => None,
+        };
+
+        // State checkpoints double as a chain heartbeat for the chain_stalled alert
+        let (checkpoint_sender, checkpoint_receiver) = broadcast::channel(CHECKPOINT_CHANNEL_CAPACITY);
+        let _checkpoint_monitor = spawn_checkpoint_monitor(checkpoint_receiver);
+
+        // Warns when a protocol that was producing swaps goes quiet
+        let health_monitor = Arc::new(Mutex::new(ProtocolHealthMonitor::new()));
+        let _protocol_health_monitor = spawn_protocol_health_monitor(health_monitor.clone());
+
         // Create TasmilProcessor
         info!("🔧 Setting up TasmilProcessor for Cellana and Thala");
-        let tasmil_processor = TasmilProcessor::new(self.db_pool.clone(), notification_sender);
+        let volume_calculator = VolumeCalculator::with_config(
+            protocol_toggles,
+            VolumeAnomalyDetector::new(&self.config.anomaly_thresholds),
+            BucketCalculator::with_duration_minutes(self.config.bucket_duration_minutes),
+        )
+        .with_event_cache_capacity(self.config.event_cache_capacity)
+        .with_trusted_deployers(&self.config.trusted_deployer_addresses)
+        .with_event_processing_timeout(self.config.event_processing_timeout())
+        .with_checkpoint_sender(checkpoint_sender)
+        .with_health_monitor(health_monitor);
+        let mut tasmil_processor = TasmilProcessor::new(
+            MultiPoolConfig::new(self.db_pool.clone(), self.read_db_pool.clone()),
+            notification_sender,
+            volume_calculator,
+        )
+        .with_streaming(self.config.streaming)
+        .with_max_batch_size(self.config.max_batch_size)
+        .with_dry_run(self.config.dry_run);
+        if let Some(path) = &self.config.dedup_state_path {
+            tasmil_processor = tasmil_processor.with_dedup_state_path(path.into())?;
+        }
+        if self.config.dry_run {
+            warn!("🧪 Dry run: volumes are computed and logged, but nothing is written to the database");
+        } else {
+            // Finishes before the pipeline starts, so it cannot zero rows the first batch wrote
+            tasmil_processor.reset_volumes_on_startup().await?;
+            tasmil_processor.spawn_hourly_snapshot_task();
+            if let Some(retention_days) = self.config.data_retention_days {
+                tasmil_processor.spawn_data_retention_task(retention_days);
+            }
+        }
         
         let version_tracker = VersionTrackerStep::new(
             get_processor_status_saver(self.db_pool.clone(), self.config.clone()),
@@ -95,12 +171,17 @@ impl SwapProcessor {
 
         // Connect processor steps together
         info!("🔗 Connecting processing pipeline components");
-        let (_, buffer_receiver) = ProcessorBuilder::new_with_inputless_first_step(
+        let builder = ProcessorBuilder::new_with_inputless_first_step(
             transaction_stream.into_runnable_step(),
         )
-        .connect_to(tasmil_processor.into_runnable_step(), 10)
-        .connect_to(version_tracker.into_runnable_step(), 10)
-        .end_and_return_output_receiver(10);
+        .connect_to(tasmil_processor.into_runnable_step(), 10);
+        // A dry run must not advance processor_status, or the real run would skip these versions
+        let builder = if self.config.dry_run {
+            builder
+        } else {
+            builder.connect_to(version_tracker.into_runnable_step(), 10)
+        };
+        let (_, buffer_receiver) = builder.end_and_return_output_receiver(10);
 
         info!("✅ Processing pipeline setup complete");
         info!("🔄 Starting continuous processing loop");
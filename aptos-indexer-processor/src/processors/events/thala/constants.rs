@@ -1,6 +1,21 @@
 // Thala swap event configuration
 pub const THALA_SWAP_EVENT_TYPE: &str = "0x7730cd28ee1cdc9e999336cbc430f99e7c44397c0aa77516f6f23a78559bb5::pool::SwapEvent";
 
+// Thala v1 emits separate events for weighted and stable pools.
+// They are generic over the pool's coin types, so they are matched by prefix.
+pub const THALA_WEIGHTED_SWAP_EVENT_TYPE: &str = "0x48271d39d0b05bd6efca2278f22277d6fcc375504f9839fd73f74ace240861af::weighted_pool::SwapEvent";
+pub const THALA_STABLE_SWAP_EVENT_TYPE: &str = "0x48271d39d0b05bd6efca2278f22277d6fcc375504f9839fd73f74ace240861af::stable_pool::SwapEvent";
+
+// Multi-asset (3+ coin) pools swapped through the v2 `pool::SwapEvent`, keyed by pool address.
+// v1 pools with more than two coins are detected from their type parameters instead.
+pub const THALA_MULTI_ASSET_POOL_ADDRESSES: &[&str] = &[];
+
+// Placeholder type for unused coin slots in v1 pools
+pub const THALA_NULL_COIN_SUFFIX: &str = "::base_pool::Null";
+
+// v1 pools use the legacy coin type for APT
+pub const APTOS_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
+
 // Coin types for Thala (different from Cellana)
 pub const APT_COIN_TYPE: &str = "0xa";
 pub const USDC_COIN_TYPE: &str = "0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b";
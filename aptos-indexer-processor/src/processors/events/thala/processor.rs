@@ -1,11 +1,14 @@
 use super::constants::*;
+use crate::utils::math::{clamp_precision, MAX_VOLUME_SCALE};
 use anyhow::Result;
+use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{write_set_change::Change, EventKey, Transaction};
 use bigdecimal::{BigDecimal, Zero};
 use serde_json;
 use std::{collections::HashMap, str::FromStr};
 use tracing::{info, debug};
 
 // Cached decimal divisors for performance
+#[derive(Clone)]
 struct DecimalDivisors {
     apt: BigDecimal,
     usdc: BigDecimal,
@@ -22,7 +25,19 @@ impl DecimalDivisors {
     }
 }
 
-#[derive(Debug)]
+/// Which Thala event a swap came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThalaPoolType {
+    /// v2 `pool::SwapEvent`, shared by all pool kinds
+    #[default]
+    Unified,
+    /// v1 `weighted_pool::SwapEvent`
+    Weighted,
+    /// v1 `stable_pool::SwapEvent`
+    Stable,
+}
+
+#[derive(Debug, Clone)]
 pub struct SwapData {
     pub amount_in: String,
     pub amount_out: String,
@@ -30,17 +45,29 @@ pub struct SwapData {
     pub to_token: String,
     pub pool: String,
     pub protocol_fee_amount: String,
+    pub pool_type: ThalaPoolType,
+    pub lp_fee_bps: u32,
+    pub protocol_fee_bps: u32,
 }
 
 #[derive(Debug)]
 pub struct PoolVolume {
     pub pool: String,
+    pub pool_type: ThalaPoolType,
+    pub trade_count: i64,
     pub apt_volume_24h: BigDecimal,
     pub usdc_volume_24h: BigDecimal,
     pub usdt_volume_24h: BigDecimal,
     pub apt_fee_24h: BigDecimal,
     pub usdc_fee_24h: BigDecimal,
     pub usdt_fee_24h: BigDecimal,
+    // LP and protocol fees on the input amount at the pool's fee rates, in input coin units
+    pub lp_apt_fee_24h: BigDecimal,
+    pub lp_usdc_fee_24h: BigDecimal,
+    pub lp_usdt_fee_24h: BigDecimal,
+    pub protocol_apt_fee_24h: BigDecimal,
+    pub protocol_usdc_fee_24h: BigDecimal,
+    pub protocol_usdt_fee_24h: BigDecimal,
     pub apt_buy_volume_24h: BigDecimal,
     pub apt_sell_volume_24h: BigDecimal,
     pub usdc_buy_volume_24h: BigDecimal,
@@ -53,12 +80,20 @@ impl Default for PoolVolume {
     fn default() -> Self {
         Self {
             pool: String::new(),
+            pool_type: ThalaPoolType::default(),
+            trade_count: 0,
             apt_volume_24h: BigDecimal::zero(),
             usdc_volume_24h: BigDecimal::zero(),
             usdt_volume_24h: BigDecimal::zero(),
             apt_fee_24h: BigDecimal::zero(),
             usdc_fee_24h: BigDecimal::zero(),
             usdt_fee_24h: BigDecimal::zero(),
+            lp_apt_fee_24h: BigDecimal::zero(),
+            lp_usdc_fee_24h: BigDecimal::zero(),
+            lp_usdt_fee_24h: BigDecimal::zero(),
+            protocol_apt_fee_24h: BigDecimal::zero(),
+            protocol_usdc_fee_24h: BigDecimal::zero(),
+            protocol_usdt_fee_24h: BigDecimal::zero(),
             apt_buy_volume_24h: BigDecimal::zero(),
             apt_sell_volume_24h: BigDecimal::zero(),
             usdc_buy_volume_24h: BigDecimal::zero(),
@@ -69,6 +104,62 @@ impl Default for PoolVolume {
     }
 }
 
+impl PoolVolume {
+    /// Round every accumulated amount to `MAX_VOLUME_SCALE` decimal places
+    pub fn clamp_precision(&mut self) {
+        for amount in [
+            &mut self.apt_volume_24h,
+            &mut self.usdc_volume_24h,
+            &mut self.usdt_volume_24h,
+            &mut self.apt_fee_24h,
+            &mut self.usdc_fee_24h,
+            &mut self.usdt_fee_24h,
+            &mut self.lp_apt_fee_24h,
+            &mut self.lp_usdc_fee_24h,
+            &mut self.lp_usdt_fee_24h,
+            &mut self.protocol_apt_fee_24h,
+            &mut self.protocol_usdc_fee_24h,
+            &mut self.protocol_usdt_fee_24h,
+            &mut self.apt_buy_volume_24h,
+            &mut self.apt_sell_volume_24h,
+            &mut self.usdc_buy_volume_24h,
+            &mut self.usdc_sell_volume_24h,
+            &mut self.usdt_buy_volume_24h,
+            &mut self.usdt_sell_volume_24h,
+        ] {
+            *amount = clamp_precision(std::mem::take(amount), MAX_VOLUME_SCALE);
+        }
+    }
+
+    /// Average APT price in the pool's stablecoin over the batch, if it traded APT against one
+    fn apt_price(&self) -> Option<BigDecimal> {
+        let stable_volume = &self.usdc_volume_24h + &self.usdt_volume_24h;
+        if self.apt_volume_24h.is_zero() || stable_volume.is_zero() {
+            return None;
+        }
+        Some(stable_volume / &self.apt_volume_24h)
+    }
+
+    /// `(lp_fee, protocol_fee)` in USD. Stablecoin fees count at 1.0 and APT fees at the pool's
+    /// average APT price; APT fees in a pool without a stablecoin side are left out.
+    pub fn fee_split_usd(&self) -> (BigDecimal, BigDecimal) {
+        let mut lp_fee = &self.lp_usdc_fee_24h + &self.lp_usdt_fee_24h;
+        let mut protocol_fee = &self.protocol_usdc_fee_24h + &self.protocol_usdt_fee_24h;
+        match self.apt_price() {
+            Some(apt_price) => {
+                lp_fee += &self.lp_apt_fee_24h * &apt_price;
+                protocol_fee += &self.protocol_apt_fee_24h * &apt_price;
+            },
+            None if !self.lp_apt_fee_24h.is_zero() || !self.protocol_apt_fee_24h.is_zero() => {
+                debug!("💲 No APT price in Thala pool {}, leaving APT fees out of USD totals", self.pool);
+            },
+            None => {},
+        }
+        (lp_fee, protocol_fee)
+    }
+}
+
+#[derive(Clone)]
 pub struct ThalaProcessor {
     divisors: DecimalDivisors,
 }
@@ -80,7 +171,43 @@ impl ThalaProcessor {
         }
     }
 
-    pub fn extract_swap_data(&self, event_data: &serde_json::Value) -> Result<SwapData> {
+    /// Account that emitted an event through its event handle. v1 pools emit their swap events
+    /// from the pool's own account, so this is the pool address. Module events carry the zero
+    /// address and have no emitting account.
+    pub fn extract_pool_from_event_key(event_key: &EventKey) -> Option<String> {
+        let address = event_key.account_address.trim_start_matches("0x");
+        if address.is_empty() || address.chars().all(|c| c == '0') {
+            return None;
+        }
+        Some(format!("0x{}", address.to_ascii_lowercase()))
+    }
+
+    /// Returns the pool type for any Thala swap event type, or None for other events
+    pub fn pool_type_for_event(&self, type_str: &str) -> Option<ThalaPoolType> {
+        if type_str == THALA_SWAP_EVENT_TYPE {
+            Some(ThalaPoolType::Unified)
+        } else if type_str.starts_with(THALA_WEIGHTED_SWAP_EVENT_TYPE) {
+            Some(ThalaPoolType::Weighted)
+        } else if type_str.starts_with(THALA_STABLE_SWAP_EVENT_TYPE) {
+            Some(ThalaPoolType::Stable)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_thala_event(&self, type_str: &str) -> bool {
+        self.pool_type_for_event(type_str).is_some()
+    }
+
+    pub fn extract_swap_data(&self, event_data: &serde_json::Value, type_str: &str) -> Result<SwapData> {
+        match self.pool_type_for_event(type_str) {
+            Some(ThalaPoolType::Unified) => self.extract_unified_swap_data(event_data),
+            Some(pool_type) => self.extract_v1_swap_data(event_data, type_str, pool_type),
+            None => Err(anyhow::anyhow!("Not a Thala swap event: {}", type_str)),
+        }
+    }
+
+    fn extract_unified_swap_data(&self, event_data: &serde_json::Value) -> Result<SwapData> {
         debug!("🔍 Extracting Thala swap data from event");
         
         // Extract indices with early return on error
@@ -157,25 +284,160 @@ impl ThalaProcessor {
             to_token: to_token.to_string(),
             pool: pool_address.to_string(),
             protocol_fee_amount: protocol_fee_amount.to_string(),
+            pool_type: ThalaPoolType::Unified,
+            lp_fee_bps: 0, // Will be filled from transaction changes
+            protocol_fee_bps: 0,
         })
     }
 
+    /// Parse a v1 weighted/stable pool SwapEvent. Coin types come from the event's generic
+    /// parameters (unused slots are `base_pool::Null`), and the pool is identified by them.
+    fn extract_v1_swap_data(&self, event_data: &serde_json::Value, type_str: &str, pool_type: ThalaPoolType) -> Result<SwapData> {
+        debug!("🔍 Extracting Thala {:?} pool swap data from event", pool_type);
+
+        let idx_in = Self::get_index(event_data, "idx_in")?;
+        let idx_out = Self::get_index(event_data, "idx_out")?;
+
+        // Stable and weighted pools name their amount fields differently
+        let (amount_in_field, amount_out_field) = match pool_type {
+            ThalaPoolType::Stable => ("amount_stable_in", "amount_stable_out"),
+            _ => ("amount_weighted_in", "amount_weighted_out"),
+        };
+        let amount_in = event_data
+            .get(amount_in_field)
+            .or_else(|| event_data.get("amount_in"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing {} / amount_in", amount_in_field))?;
+        let amount_out = event_data
+            .get(amount_out_field)
+            .or_else(|| event_data.get("amount_out"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing {} / amount_out", amount_out_field))?;
+
+        let protocol_fee_amount = event_data
+            .get("protocol_fee_amount")
+            .or_else(|| event_data.get("fee_amount"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("0");
+
+        // Generic parameters: coin types first (weighted pools append weight types)
+        let start = type_str.find('<').ok_or_else(|| anyhow::anyhow!("Missing type parameters in {}", type_str))?;
+        let end = type_str.rfind('>').ok_or_else(|| anyhow::anyhow!("Missing type parameters in {}", type_str))?;
+        let generic_part = &type_str[start + 1..end];
+        let coin_types: Vec<&str> = generic_part
+            .split(',')
+            .map(|s| s.trim())
+            .take(4)
+            .filter(|s| !s.ends_with(THALA_NULL_COIN_SUFFIX))
+            .collect();
+
+        let from_token = coin_types
+            .get(idx_in)
+            .ok_or_else(|| anyhow::anyhow!("idx_in {} out of range for {} coins", idx_in, coin_types.len()))?;
+        let to_token = coin_types
+            .get(idx_out)
+            .ok_or_else(|| anyhow::anyhow!("idx_out {} out of range for {} coins", idx_out, coin_types.len()))?;
+
+        debug!("✅ Extracted Thala {:?} swap: {} {} -> {} {} (fee: {})",
+            pool_type, amount_in, from_token, amount_out, to_token, protocol_fee_amount);
+
+        Ok(SwapData {
+            amount_in: amount_in.to_string(),
+            amount_out: amount_out.to_string(),
+            from_token: Self::normalize_coin_type(from_token).to_string(),
+            to_token: Self::normalize_coin_type(to_token).to_string(),
+            pool: generic_part.to_string(),
+            protocol_fee_amount: protocol_fee_amount.to_string(),
+            pool_type,
+            lp_fee_bps: 0, // Will be filled from transaction changes
+            protocol_fee_bps: 0,
+        })
+    }
+
+    fn get_index(event_data: &serde_json::Value, field: &str) -> Result<usize> {
+        event_data
+            .get(field)
+            .and_then(|v| v.as_str().and_then(|s| s.parse::<usize>().ok()).or_else(|| v.as_u64().map(|n| n as usize)))
+            .ok_or_else(|| anyhow::anyhow!("Missing or invalid {}", field))
+    }
+
+    /// Map legacy coin types onto the fungible asset addresses used by the v2 pools
+    fn normalize_coin_type(coin_type: &str) -> &str {
+        if coin_type == APTOS_COIN_TYPE {
+            APT_COIN_TYPE
+        } else {
+            coin_type
+        }
+    }
+
+    /// Whether a pool holds more than two coins. v2 pools are looked up in
+    /// `THALA_MULTI_ASSET_POOL_ADDRESSES`; v1 pools are keyed by their coin type parameters,
+    /// so those are counted directly.
+    pub fn is_multi_asset_pool(pool_address: &str) -> bool {
+        if THALA_MULTI_ASSET_POOL_ADDRESSES.contains(&pool_address) {
+            return true;
+        }
+        pool_address
+            .split(',')
+            .map(|s| s.trim())
+            .take(4)
+            .filter(|s| s.contains("::") && !s.ends_with(THALA_NULL_COIN_SUFFIX))
+            .count()
+            > 2
+    }
+
+    /// `(lp_fee_bps, protocol_fee_bps)` from the V2 `WeightedPool` resource written by the
+    /// swap, or `(0, 0)` if the transaction didn't write it
+    pub fn extract_fees(txn: &Transaction, pool_address: &str) -> (u32, u32) {
+        let Some(info) = &txn.info else {
+            return (0, 0);
+        };
+
+        for change in &info.changes {
+            let Some(Change::WriteResource(resource)) = &change.change else {
+                continue;
+            };
+            if resource.address != pool_address || !resource.type_str.contains("WeightedPool") {
+                continue;
+            }
+            if let Ok(pool_data) = serde_json::from_str::<serde_json::Value>(&resource.data) {
+                let bps_field = |name: &str| pool_data
+                    .get(name)
+                    .and_then(|v| v.as_str().and_then(|s| s.parse::<u32>().ok()).or_else(|| v.as_u64().map(|n| n as u32)))
+                    .unwrap_or(0);
+                let (lp_fee_bps, protocol_fee_bps) = (bps_field("lp_fee_bps"), bps_field("protocol_fee_bps"));
+                debug!("🔧 Found Thala lp_fee_bps: {} (protocol_fee_bps: {}) for pool {}", lp_fee_bps, protocol_fee_bps, pool_address);
+                return (lp_fee_bps, protocol_fee_bps);
+            }
+        }
+
+        (0, 0)
+    }
+
     pub async fn process_swap(&self, pool_volumes: &mut HashMap<String, PoolVolume>, swap_data: SwapData) {
         // Get or create pool volume entry with optimized default
         let pool_entry = pool_volumes.entry(swap_data.pool.clone()).or_insert_with(|| {
             PoolVolume {
                 pool: swap_data.pool.clone(),
+                pool_type: swap_data.pool_type,
                 ..Default::default()
             }
         });
+        pool_entry.trade_count += 1;
 
         // Parse amounts once with error handling
         let raw_amount_in = BigDecimal::from_str(&swap_data.amount_in).unwrap_or_else(|_| BigDecimal::zero());
         let raw_amount_out = BigDecimal::from_str(&swap_data.amount_out).unwrap_or_else(|_| BigDecimal::zero());
         let protocol_fee = BigDecimal::from_str(&swap_data.protocol_fee_amount).unwrap_or_else(|_| BigDecimal::zero());
 
-        // Process the swap with unified logic
-        self.process_thala_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &protocol_fee).await;
+        // Multi-asset pools can swap any pair of their coins, so they skip the pair handlers
+        if Self::is_multi_asset_pool(&swap_data.pool) {
+            self.process_generic_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &protocol_fee).await;
+        } else {
+            // Process the swap with unified logic
+            self.process_thala_swap(pool_entry, &swap_data, &raw_amount_in, &raw_amount_out, &protocol_fee).await;
+        }
+        pool_entry.clamp_precision();
     }
 
     async fn process_thala_swap(
@@ -186,42 +448,44 @@ impl ThalaProcessor {
         raw_amount_out: &BigDecimal,
         protocol_fee: &BigDecimal,
     ) {
+        let fee_bps = (swap_data.lp_fee_bps, swap_data.protocol_fee_bps);
+
         // Match swap pairs and delegate to unified processing
         match (swap_data.from_token.as_str(), swap_data.to_token.as_str()) {
             (APT_COIN_TYPE, USDC_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "APT", "USDC", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.apt, &self.divisors.usdc, "📈", "APT->USDC"
+                    &self.divisors.apt, &self.divisors.usdc, "📈", "APT->USDC", fee_bps
                 ).await;
             },
             (USDC_COIN_TYPE, APT_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "USDC", "APT", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.usdc, &self.divisors.apt, "📉", "USDC->APT"
+                    &self.divisors.usdc, &self.divisors.apt, "📉", "USDC->APT", fee_bps
                 ).await;
             },
             (USDT_COIN_TYPE, USDC_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "USDT", "USDC", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.usdt, &self.divisors.usdc, "📈", "USDT->USDC"
+                    &self.divisors.usdt, &self.divisors.usdc, "📈", "USDT->USDC", fee_bps
                 ).await;
             },
             (USDC_COIN_TYPE, USDT_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "USDC", "USDT", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.usdc, &self.divisors.usdt, "📉", "USDC->USDT"
+                    &self.divisors.usdc, &self.divisors.usdt, "📉", "USDC->USDT", fee_bps
                 ).await;
             },
             (APT_COIN_TYPE, USDT_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "APT", "USDT", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.apt, &self.divisors.usdt, "📈", "APT->USDT"
+                    &self.divisors.apt, &self.divisors.usdt, "📈", "APT->USDT", fee_bps
                 ).await;
             },
             (USDT_COIN_TYPE, APT_COIN_TYPE) => {
                 self.process_swap_pair(
                     pool_entry, "USDT", "APT", raw_amount_in, raw_amount_out, protocol_fee,
-                    &self.divisors.usdt, &self.divisors.apt, "📉", "USDT->APT"
+                    &self.divisors.usdt, &self.divisors.apt, "📉", "USDT->APT", fee_bps
                 ).await;
             },
             _ => {
@@ -231,6 +495,98 @@ impl ThalaProcessor {
         }
     }
 
+    /// Tracked currency name and divisor for a coin type, if it is APT, USDC or USDT
+    fn tracked_currency(&self, coin_type: &str) -> Option<(&'static str, &BigDecimal)> {
+        match coin_type {
+            APT_COIN_TYPE => Some(("APT", &self.divisors.apt)),
+            USDC_COIN_TYPE => Some(("USDC", &self.divisors.usdc)),
+            USDT_COIN_TYPE => Some(("USDT", &self.divisors.usdt)),
+            _ => None,
+        }
+    }
+
+    /// Add `input_amount * bps / 10_000` to the LP and protocol fees of the input coin
+    fn record_fee_split(pool_entry: &mut PoolVolume, currency: &str, input_amount: &BigDecimal, lp_fee_bps: u32, protocol_fee_bps: u32) {
+        if lp_fee_bps == 0 && protocol_fee_bps == 0 {
+            return;
+        }
+        let bps = BigDecimal::from(10_000);
+        let lp_fee_amount = input_amount * BigDecimal::from(lp_fee_bps) / &bps;
+        let protocol_fee_amount = input_amount * BigDecimal::from(protocol_fee_bps) / &bps;
+        match currency {
+            "APT" => {
+                pool_entry.lp_apt_fee_24h += lp_fee_amount;
+                pool_entry.protocol_apt_fee_24h += protocol_fee_amount;
+            },
+            "USDC" => {
+                pool_entry.lp_usdc_fee_24h += lp_fee_amount;
+                pool_entry.protocol_usdc_fee_24h += protocol_fee_amount;
+            },
+            "USDT" => {
+                pool_entry.lp_usdt_fee_24h += lp_fee_amount;
+                pool_entry.protocol_usdt_fee_24h += protocol_fee_amount;
+            },
+            _ => debug!("Unknown fee currency: {}", currency),
+        }
+    }
+
+    /// Count each side of a multi-asset pool swap on its own: a tracked coin's volume is
+    /// updated even when the other coin (e.g. DAI or MOD) is not tracked
+    async fn process_generic_swap(
+        &self,
+        pool_entry: &mut PoolVolume,
+        swap_data: &SwapData,
+        raw_amount_in: &BigDecimal,
+        raw_amount_out: &BigDecimal,
+        protocol_fee: &BigDecimal,
+    ) {
+        if let Some((from_currency, from_divisor)) = self.tracked_currency(&swap_data.from_token) {
+            let from_amount = raw_amount_in / from_divisor;
+            let fee_amount = protocol_fee / from_divisor;
+            let net_volume = &from_amount - &fee_amount;
+            Self::record_fee_split(pool_entry, from_currency, &from_amount, swap_data.lp_fee_bps, swap_data.protocol_fee_bps);
+            match from_currency {
+                "APT" => {
+                    pool_entry.apt_volume_24h += &net_volume;
+                    pool_entry.apt_fee_24h += &fee_amount;
+                    pool_entry.apt_sell_volume_24h += &net_volume;
+                },
+                "USDC" => {
+                    pool_entry.usdc_volume_24h += &net_volume;
+                    pool_entry.usdc_fee_24h += &fee_amount;
+                    pool_entry.usdc_sell_volume_24h += &net_volume;
+                },
+                _ => {
+                    pool_entry.usdt_volume_24h += &net_volume;
+                    pool_entry.usdt_fee_24h += &fee_amount;
+                    pool_entry.usdt_sell_volume_24h += &net_volume;
+                },
+            }
+            debug!("🧺 Thala multi-asset pool: {} {} sold (net: {}, fee: {})", from_amount, from_currency, net_volume, fee_amount);
+        }
+
+        if let Some((to_currency, to_divisor)) = self.tracked_currency(&swap_data.to_token) {
+            let to_amount = raw_amount_out / to_divisor;
+            match to_currency {
+                "APT" => {
+                    pool_entry.apt_volume_24h += &to_amount;
+                    pool_entry.apt_buy_volume_24h += &to_amount;
+                },
+                "USDC" => {
+                    pool_entry.usdc_volume_24h += &to_amount;
+                    pool_entry.usdc_buy_volume_24h += &to_amount;
+                },
+                _ => {
+                    pool_entry.usdt_volume_24h += &to_amount;
+                    pool_entry.usdt_buy_volume_24h += &to_amount;
+                },
+            }
+            debug!("🧺 Thala multi-asset pool: {} {} bought", to_amount, to_currency);
+        }
+
+        info!("🧺 Thala multi-asset swap: {} -> {} (pool: {})", swap_data.from_token, swap_data.to_token, swap_data.pool);
+    }
+
     // Unified swap processing function to eliminate code duplication
     async fn process_swap_pair(
         &self,
@@ -244,12 +600,14 @@ impl ThalaProcessor {
         to_divisor: &BigDecimal,
         emoji: &str,
         swap_type: &str,
+        fee_bps: (u32, u32),
     ) {
         // Calculate normalized amounts
         let from_amount = raw_amount_in / from_divisor;
         let to_amount = raw_amount_out / to_divisor;
         let fee_amount = protocol_fee / from_divisor;
         let net_volume = &from_amount - &fee_amount;
+        Self::record_fee_split(pool_entry, from_currency, &from_amount, fee_bps.0, fee_bps.1);
 
         // Update total volumes and fees based on currency types (for backward compatibility)
         match from_currency {
@@ -294,4 +652,136 @@ impl ThalaProcessor {
         info!("{} Thala {}: {} {} sold (net: {}), {} {} bought, {} {} fee", 
             emoji, swap_type, from_amount, from_currency, net_volume, to_amount, to_currency, fee_amount, from_currency);
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{TransactionInfo, WriteResource, WriteSetChange};
+
+    #[tokio::test]
+    async fn test_multi_asset_pool_counts_tracked_side() {
+        let processor = ThalaProcessor::new();
+        let four_coin_pool = "0xabc::asset::USDC, 0xabc::asset::USDT, 0xdef::dai::DAI, 0x6f98::mod_coin::MOD";
+        let two_coin_pool = format!("0xabc::asset::USDC, 0xabc::asset::USDT, 0x48271d39::base_pool{}, 0x48271d39::base_pool{}",
+            THALA_NULL_COIN_SUFFIX, THALA_NULL_COIN_SUFFIX);
+        assert!(ThalaProcessor::is_multi_asset_pool(four_coin_pool));
+        assert!(!ThalaProcessor::is_multi_asset_pool(&two_coin_pool));
+        assert!(!ThalaProcessor::is_multi_asset_pool("0x1234"));
+
+        // 100 USDC -> DAI: no pair handler exists, but the USDC side is still counted
+        let mut pool_volumes = HashMap::new();
+        processor.process_swap(&mut pool_volumes, SwapData {
+            amount_in: "100000000".to_string(),
+            amount_out: "99900000000000000000".to_string(),
+            from_token: USDC_COIN_TYPE.to_string(),
+            to_token: "0xdef::dai::DAI".to_string(),
+            pool: four_coin_pool.to_string(),
+            protocol_fee_amount: "10000".to_string(),
+            pool_type: ThalaPoolType::Stable,
+            lp_fee_bps: 0,
+            protocol_fee_bps: 0,
+        }).await;
+
+        let pool = &pool_volumes[four_coin_pool];
+        assert_eq!(pool.usdc_volume_24h, BigDecimal::from_str("99.99").unwrap());
+        assert_eq!(pool.usdc_sell_volume_24h, BigDecimal::from_str("99.99").unwrap());
+        assert_eq!(pool.usdc_fee_24h, BigDecimal::from_str("0.01").unwrap());
+        assert_eq!(pool.usdt_volume_24h, BigDecimal::zero());
+        assert_eq!(pool.trade_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_pair_swap_tracks_buy_and_sell() {
+        let processor = ThalaProcessor::new();
+        let mut pool_volumes = HashMap::new();
+
+        // 2 APT (0.002 fee) sold for 10 USDC
+        processor.process_swap(&mut pool_volumes, SwapData {
+            amount_in: "200000000".to_string(),
+            amount_out: "10000000".to_string(),
+            from_token: APT_COIN_TYPE.to_string(),
+            to_token: USDC_COIN_TYPE.to_string(),
+            pool: "0x1234".to_string(),
+            protocol_fee_amount: "200000".to_string(),
+            pool_type: ThalaPoolType::Weighted,
+            lp_fee_bps: 0,
+            protocol_fee_bps: 0,
+        }).await;
+
+        let pool = &pool_volumes["0x1234"];
+        assert_eq!(pool.apt_sell_volume_24h, BigDecimal::from_str("1.998").unwrap());
+        assert_eq!(pool.usdc_buy_volume_24h, BigDecimal::from(10));
+        assert_eq!(pool.apt_buy_volume_24h, BigDecimal::zero());
+        assert_eq!(pool.usdc_sell_volume_24h, BigDecimal::zero());
+    }
+
+    #[tokio::test]
+    async fn test_v2_fees_split_on_input_amount() {
+        let txn = Transaction {
+            info: Some(TransactionInfo {
+                changes: vec![WriteSetChange {
+                    change: Some(Change::WriteResource(WriteResource {
+                        address: "0xpool".to_string(),
+                        type_str: "0x7730::pool::WeightedPool".to_string(),
+                        data: r#"{"lp_fee_bps":"25","protocol_fee_bps":"5"}"#.to_string(),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(ThalaProcessor::extract_fees(&txn, "0xpool"), (25, 5));
+        assert_eq!(ThalaProcessor::extract_fees(&txn, "0xother"), (0, 0));
+
+        // 1000 USDC -> 200 APT in the same pool
+        let processor = ThalaProcessor::new();
+        let mut pool_volumes = HashMap::new();
+        processor.process_swap(&mut pool_volumes, SwapData {
+            amount_in: "1000000000".to_string(),
+            amount_out: "20000000000".to_string(),
+            from_token: USDC_COIN_TYPE.to_string(),
+            to_token: APT_COIN_TYPE.to_string(),
+            pool: "0xpool".to_string(),
+            protocol_fee_amount: "0".to_string(),
+            pool_type: ThalaPoolType::Unified,
+            lp_fee_bps: 25,
+            protocol_fee_bps: 5,
+        }).await;
+
+        let pool = &pool_volumes["0xpool"];
+        assert_eq!(pool.lp_usdc_fee_24h, BigDecimal::from_str("2.5").unwrap());
+        assert_eq!(pool.protocol_usdc_fee_24h, BigDecimal::from_str("0.5").unwrap());
+        assert_eq!(pool.lp_apt_fee_24h, BigDecimal::zero());
+        assert_eq!(pool.fee_split_usd(), (BigDecimal::from_str("2.5").unwrap(), BigDecimal::from_str("0.5").unwrap()));
+    }
+
+    #[test]
+    fn test_pool_address_from_event_key() {
+        use crate::utils::pool_registry::{PoolInfo, PoolRegistry};
+
+        let pool_address = "0x4a3fd5e2a8a0fe1ea0f2c2a5d6d9ab18e3a43b63fc06e9d1a7f3c5d6d1e8ab10";
+        let mut registry = PoolRegistry::default();
+        registry.register_pool(pool_address, PoolInfo {
+            protocol: "thala".to_string(),
+            canonical_pair: "USDT/USDC".to_string(),
+            token_x: USDT_COIN_TYPE.to_string(),
+            token_y: USDC_COIN_TYPE.to_string(),
+        });
+
+        let event_key = EventKey {
+            creation_number: 3,
+            account_address: pool_address.to_string(),
+        };
+        let pool = ThalaProcessor::extract_pool_from_event_key(&event_key).unwrap();
+        assert_eq!(pool, pool_address);
+        assert_eq!(registry.get_pair_name_by_address(&pool), Some("USDT/USDC"));
+
+        let module_event_key = EventKey {
+            creation_number: 0,
+            account_address: "0x0".to_string(),
+        };
+        assert_eq!(ThalaProcessor::extract_pool_from_event_key(&module_event_key), None);
+    }
+}
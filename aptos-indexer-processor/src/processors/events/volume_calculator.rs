@@ -1,29 +1,67 @@
 use std::collections::HashMap;
 use crate::db::common::models::{
-    apt_models::NewAptData, 
-    coin_volume_models::{NewCoinVolume24h, NewCoinVolumeBucket}
+    anomalous_swap_models::NewAnomalousSwap,
+    chain_metrics_models::ChainMetric,
+    apt_models::{NewAptData, NewAptDataBuilder, NewAptDataUsd},
+    coin_price_models::NewCoinPriceUsd,
+    deployed_contract_models::{DeployedContract, NewDeployedContract},
+    hyperion_fee_growth_models::NewHyperionPoolFeeGrowth,
+    hyperion_pool_models::{HyperionPool, NewHyperionPool},
+    cellana_pool_models::{CellanaPool, NewCellanaPool},
+    pair_volume_models::NewPairProtocolVolume,
+    pool_reserve_models::NewPoolReserve,
+    vwap_bucket_models::NewVwapBucket,
+    protocol_fee_models::NewProtocolFee,
+    undiscovered_pool_models::NewUndiscoveredPool,
+    coin_volume_models::{NewCoinVolume24h, NewCoinVolumeBucket, NewCoinVolumeDailyBucket}
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use aptos_indexer_processor_sdk::{
-    aptos_protos::transaction::v1::{transaction::TxnData, Transaction},
+    aptos_protos::transaction::v1::{
+        transaction::TxnData, write_set, write_set_change::Change, EventKey, GenesisTransaction, Transaction,
+        UserTransaction, WriteModule,
+    },
     traits::{async_step::AsyncRunType, AsyncStep, NamedStep, Processable},
     types::transaction_context::TransactionContext,
     utils::errors::ProcessorError,
 };
 use async_trait::async_trait;
-use bigdecimal::{BigDecimal, Zero};
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
 use chrono::{DateTime, Utc, Duration};
 use serde_json;
-use std::str::FromStr;
-use tracing::{info, debug};
+use std::{
+    future::Future,
+    str::FromStr,
+    sync::{Arc, Mutex, RwLock},
+};
+use tokio::sync::{broadcast, mpsc};
+use tracing::{info, debug, debug_span, error, warn, Instrument};
 
 // Import the new modular processors
-use super::cellana::{CellanaProcessor, constants::CELLANA_SWAP_EVENT_TYPE};
-use super::thala::{ThalaProcessor, constants::THALA_SWAP_EVENT_TYPE};
-use super::sushiswap::SushiSwapProcessor;
+use super::cellana::{CellanaProcessor, constants::CELLANA_SWAP_EVENT_TYPE, processor::PoolMigration};
+use super::thala::{ThalaProcessor, processor::ThalaPoolType};
+use super::sushiswap::{sushi_contract_version_from_type_str, SushiSwapProcessor};
 use super::liquidswap::LiquidSwapProcessor;
-use super::hyperion::{HyperionProcessor, constants::HYPERION_SWAP_EVENT_TYPE};
+use super::hyperion::HyperionProcessor;
 use super::bucket_calculator::{BucketCalculator, SwapEventData, CoinVolumeData};
+use super::anomaly_detector::VolumeAnomalyDetector;
+use super::block_metadata::BlockMetadataProcessor;
+use super::checkpoint_monitor::CheckpointEvent;
+use super::event_cache::{EventCache, ProcessedEventResult};
+use super::price_validator::{implied_price_from_coin_volumes, ImpliedPrice, PriceCrossValidator, USD_QUOTE_COINS};
+use crate::{
+    config::protocol_toggles::ProtocolToggles,
+    processors::protocol_health_monitor::SharedProtocolHealthMonitor,
+    utils::{
+        contract_registry::ContractRegistry,
+        filter::is_skipped_sender,
+        math::is_positive,
+        pool_registry::PoolRegistry,
+        metrics::{ANOMALIES_DETECTED_TOTAL, DISABLED_PROTOCOL_EVENTS_SKIPPED, PROCESSING_TIMEOUTS_TOTAL, SWAP_COUNT_BY_PAIR, SWAP_VOLUME_BY_PAIR_USD, UNKNOWN_PAIRS_SEEN_TOTAL},
+        protocol_config_loader::ProtocolFeeConfig,
+        volume_window::VolumeWindowManager,
+    },
+};
 
 // Re-export the processor types for internal use
 pub use super::cellana::processor::PoolVolume as CellanaPoolVolume;
@@ -32,6 +70,12 @@ pub use super::sushiswap::processor::SushiPoolVolume;
 pub use super::liquidswap::processor::LiquidPoolVolume;
 pub use super::hyperion::processor::PoolVolume as HyperionPoolVolume;
 
+/// Latest USD price per coin name ("APT", "WETH", ...), shared with the per-protocol tasks
+pub type SharedCoinPrices = Arc<RwLock<HashMap<String, BigDecimal>>>;
+
+/// Seconds a single swap may wait on an `.await` in its protocol processor when no timeout is configured
+pub const DEFAULT_EVENT_PROCESSING_TIMEOUT_SECS: u64 = 5;
+
 // Helper function to check if a transaction is within the last 24 hours
 fn is_within_24h(txn_timestamp_seconds: i64) -> bool {
     let now = Utc::now();
@@ -42,6 +86,17 @@ fn is_within_24h(txn_timestamp_seconds: i64) -> bool {
     txn_time >= cutoff_time
 }
 
+/// The account that signed for the swap: the transaction sender. Multi-agent transactions
+/// list secondary signers and fee payer transactions a fee payer, but the sender is always
+/// the primary signer, unlike the `user` field some protocols put in their events.
+pub fn extract_primary_signer(user_txn: &UserTransaction) -> Option<&str> {
+    user_txn
+        .request
+        .as_ref()
+        .map(|request| request.sender.as_str())
+        .filter(|sender| !sender.is_empty())
+}
+
 /// VolumeCalculator calculates real-time 24h rolling volume and 2-hour buckets for chart data
 pub struct VolumeCalculator {
     cellana_processor: CellanaProcessor,
@@ -49,30 +104,397 @@ pub struct VolumeCalculator {
     sushi_swap_processor: SushiSwapProcessor,
     liquid_swap_processor: LiquidSwapProcessor,
     hyperion_processor: HyperionProcessor,
+    block_metadata_processor: BlockMetadataProcessor,
+    // Swap event types of DEX modules deployed at addresses not in the constants, learned from
+    // genesis and trusted deployers only
+    contract_registry: ContractRegistry,
     bucket_calculator: BucketCalculator,
+    protocol_toggles: ProtocolToggles,
+    anomaly_detector: VolumeAnomalyDetector,
+    price_validator: PriceCrossValidator,
+    // Shared by the per-protocol futures, which are joined on `&self`
+    event_cache: Mutex<EventCache>,
+    // Window events must fall in to be counted; without one the last 24h are used
+    volume_window: Option<VolumeWindowManager>,
+    // State checkpoints are published here as chain heartbeats
+    checkpoint_sender: Option<broadcast::Sender<CheckpointEvent>>,
+    // Pool addresses, kept up to date with Cellana pool migrations
+    pool_registry: PoolRegistry,
+    // Longest a single swap may sit at an `.await` in a protocol processor before it is skipped
+    event_processing_timeout: std::time::Duration,
+    // Told which protocols processed swaps in each batch
+    health_monitor: Option<SharedProtocolHealthMonitor>,
+    // Latest USD price per coin, refreshed by TasmilProcessor after each coin_price_usd insert
+    coin_prices: SharedCoinPrices,
 }
 
 impl VolumeCalculator {
     pub fn new() -> Self {
+        Self::with_config(ProtocolToggles::default(), VolumeAnomalyDetector::default(), BucketCalculator::new())
+    }
+
+    pub fn with_config(
+        protocol_toggles: ProtocolToggles,
+        anomaly_detector: VolumeAnomalyDetector,
+        bucket_calculator: BucketCalculator,
+    ) -> Self {
         info!("🚀 Initializing VolumeCalculator with modular architecture and bucket support");
         info!("📊 Configured for Cellana, Thala, SushiSwap, LiquidSwap, and Hyperion volume tracking");
-        info!("🕐 Configured for 2-hour GMT+7 buckets for chart data");
+        info!("🕐 Configured for {} GMT+7 buckets per 24h for chart data", bucket_calculator.buckets_per_24h());
         Self {
             cellana_processor: CellanaProcessor::new(),
             thala_processor: ThalaProcessor::new(),
             sushi_swap_processor: SushiSwapProcessor::new(),
             liquid_swap_processor: LiquidSwapProcessor::new(),
             hyperion_processor: HyperionProcessor::new(),
-            bucket_calculator: BucketCalculator::new(),
+            block_metadata_processor: BlockMetadataProcessor::new(),
+            contract_registry: ContractRegistry::new(),
+            bucket_calculator,
+            protocol_toggles,
+            anomaly_detector,
+            price_validator: PriceCrossValidator::default(),
+            event_cache: Mutex::new(EventCache::default()),
+            volume_window: None,
+            checkpoint_sender: None,
+            pool_registry: PoolRegistry::global().clone(),
+            event_processing_timeout: std::time::Duration::from_secs(DEFAULT_EVENT_PROCESSING_TIMEOUT_SECS),
+            health_monitor: None,
+            coin_prices: SharedCoinPrices::default(),
+        }
+    }
+
+    /// Only count events inside `window` instead of the 24h before now
+    pub fn set_volume_window(&mut self, window: VolumeWindowManager) {
+        self.volume_window = Some(window);
+    }
+
+    /// Keep up to `capacity` decoded event payloads for reuse
+    pub fn with_event_cache_capacity(mut self, capacity: usize) -> Self {
+        self.event_cache = Mutex::new(EventCache::new(capacity));
+        self
+    }
+
+    /// Learn DEX modules published after genesis, but only at one of `addresses`
+    pub fn with_trusted_deployers(mut self, addresses: &[String]) -> Self {
+        self.contract_registry = ContractRegistry::with_trusted_deployers(addresses);
+        self
+    }
+
+    /// Give up on a swap if its protocol processor is still awaiting after `timeout`
+    pub fn with_event_processing_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.event_processing_timeout = timeout;
+        self
+    }
+
+    /// Publish every state checkpoint transaction on `sender`
+    pub fn with_checkpoint_sender(mut self, sender: broadcast::Sender<CheckpointEvent>) -> Self {
+        self.checkpoint_sender = Some(sender);
+        self
+    }
+
+    /// Report the protocols that processed swaps in each batch to `monitor`
+    pub fn with_health_monitor(mut self, monitor: SharedProtocolHealthMonitor) -> Self {
+        self.health_monitor = Some(monitor);
+        self
+    }
+
+    /// Replace the cached price of each coin in `prices` with its newest one
+    pub fn update_coin_prices(&self, prices: &[NewCoinPriceUsd]) {
+        let mut latest: HashMap<&str, &NewCoinPriceUsd> = HashMap::new();
+        for price in prices {
+            let entry = latest.entry(price.coin.as_str()).or_insert(price);
+            if price.transaction_version > entry.transaction_version {
+                *entry = price;
+            }
+        }
+
+        let mut cached = self.coin_prices.write().unwrap_or_else(|e| e.into_inner());
+        for (coin, price) in latest {
+            cached.insert(coin.to_string(), price.price_usd.clone());
         }
     }
+
+    /// Count a swap in the per-pair Prometheus metrics. Its USD value is skipped when neither
+    /// side is a stablecoin and the base coin has no cached price yet.
+    fn record_pair_metrics(&self, protocol_name: &str, coin_volumes: &[CoinVolumeData]) {
+        let Some(pair) = swap_pair_name(coin_volumes) else {
+            return;
+        };
+        SWAP_COUNT_BY_PAIR.with_label_values(&[protocol_name, &pair]).inc();
+
+        let usd_volume = match coin_volumes.iter().find(|cv| USD_QUOTE_COINS.contains(&cv.coin.as_str())) {
+            Some(quote) => Some(quote.volume.clone()),
+            None => {
+                let prices = self.coin_prices.read().unwrap_or_else(|e| e.into_inner());
+                coin_volumes
+                    .iter()
+                    .find_map(|cv| prices.get(&cv.coin).map(|price| &cv.volume * price))
+            },
+        };
+        if let Some(usd_volume) = usd_volume.and_then(|volume| volume.to_f64()) {
+            SWAP_VOLUME_BY_PAIR_USD.with_label_values(&[protocol_name, &pair]).inc_by(usd_volume);
+        }
+    }
+
+    /// Size of a chart bucket in minutes
+    pub fn bucket_duration_minutes(&self) -> u32 {
+        self.bucket_calculator.duration_minutes()
+    }
+
+    /// Number of chart buckets covering a 24h window
+    pub fn buckets_per_24h(&self) -> usize {
+        self.bucket_calculator.buckets_per_24h()
+    }
+
+    /// Replace the Cellana per-pool fee overrides loaded from `protocol_config`
+    pub fn set_cellana_fee_overrides(&mut self, fee_overrides: ProtocolFeeConfig) {
+        self.cellana_processor.set_fee_overrides(fee_overrides);
+    }
+
+    /// Register Hyperion pools stored in `hyperion_pools` by earlier runs
+    pub fn register_hyperion_pools(&mut self, pools: &[HyperionPool]) {
+        for pool in pools {
+            self.hyperion_processor.register_pool(&pool.pool_address, &pool.token0, &pool.token1);
+        }
+    }
+
+    /// Register DEX modules stored in `deployed_contracts` by earlier runs
+    pub fn register_deployed_contracts(&mut self, contracts: &[DeployedContract]) {
+        for contract in contracts {
+            self.contract_registry.register_deployed_contract(contract);
+        }
+    }
+
+    /// Register Cellana pools stored in `cellana_pools` by earlier runs
+    pub fn register_cellana_pools(&mut self, pools: &[CellanaPool]) {
+        for pool in pools {
+            CellanaProcessor::register_pool(&mut self.pool_registry, &pool.pool_address, &pool.token_x, &pool.token_y);
+        }
+    }
+
+    /// Returns true for swap events of any supported protocol
+    pub fn is_tracked_swap_event(&self, event_type: &str) -> bool {
+        event_type == CELLANA_SWAP_EVENT_TYPE
+            || self.thala_processor.is_thala_event(event_type)
+            || self.sushi_swap_processor.is_sushiswap_event(event_type)
+            || self.liquid_swap_processor.is_liquidswap_event(event_type)
+            || self.hyperion_processor.is_hyperion_event(event_type)
+            || self.contract_registry.protocol_for_event(event_type).is_some()
+    }
+
+    /// DEX modules published by the genesis write set, also registered so their swap
+    /// events are routed. Lets a fresh chain be indexed without configuring addresses.
+    pub fn process_genesis(&mut self, genesis_txn: &GenesisTransaction) -> Result<Vec<NewDeployedContract>> {
+        let write_set = genesis_txn
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.write_set.as_ref())
+            .context("Genesis transaction has no write set")?;
+        let write_set::WriteSet::DirectWriteSet(direct_write_set) = write_set else {
+            return Ok(vec![]);
+        };
+
+        let mut deployed = Vec::new();
+        for change in &direct_write_set.write_set_change {
+            let Some(Change::WriteModule(write_module)) = &change.change else {
+                continue;
+            };
+            let Some(protocol) = self.contract_registry.register_from_genesis_module(write_module) else {
+                continue;
+            };
+            deployed.extend(deployed_contract(write_module, protocol));
+        }
+
+        info!("🌱 Found {} DEX module deployments in genesis", deployed.len());
+        Ok(deployed)
+    }
+
+    /// Returns true if the protocol is enabled, otherwise counts the event as skipped
+    fn should_process(&self, protocol_name: &str) -> bool {
+        if self.protocol_toggles.is_enabled(protocol_name) {
+            return true;
+        }
+        DISABLED_PROTOCOL_EVENTS_SKIPPED.with_label_values(&[protocol_name]).inc();
+        false
+    }
+}
+
+/// `deployed_contracts` row for a module the contract registry recognized as `protocol`
+fn deployed_contract(write_module: &WriteModule, protocol: &str) -> Option<NewDeployedContract> {
+    let abi = write_module.data.as_ref()?.abi.as_ref()?;
+    Some(NewDeployedContract {
+        address: write_module.address.clone(),
+        module_name: abi.name.clone(),
+        protocol: protocol.to_string(),
+    })
 }
 
-#[derive(Debug)]
+/// A decoded swap event routed to a single protocol
+struct ProtocolEvent {
+    event_data: serde_json::Value,
+    raw_data: String,
+    type_str: String,
+    // Key of the emitting event handle, None for module events
+    event_key: Option<EventKey>,
+    txn: Arc<Transaction>,
+    txn_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct VolumeData {
     pub apt_data: Vec<NewAptData>,
+    pub protocol_fees: Vec<NewProtocolFee>,
     pub coin_volume_data: Vec<NewCoinVolume24h>,
     pub coin_volume_buckets: Vec<NewCoinVolumeBucket>,
+    pub coin_volume_daily_buckets: Vec<NewCoinVolumeDailyBucket>,
+    pub anomalous_swaps: Vec<NewAnomalousSwap>,
+    pub coin_prices: Vec<NewCoinPriceUsd>,
+    pub hyperion_fee_growth: Vec<NewHyperionPoolFeeGrowth>,
+    pub chain_metrics: Vec<ChainMetric>,
+    pub deployed_contracts: Vec<NewDeployedContract>,
+    pub pair_volumes: Vec<NewPairProtocolVolume>,
+    pub vwap_buckets: Vec<NewVwapBucket>,
+    pub pool_reserves: Vec<NewPoolReserve>,
+    pub hyperion_pools: Vec<NewHyperionPool>,
+    pub cellana_pools: Vec<NewCellanaPool>,
+    pub pool_migrations: Vec<PoolMigration>,
+    pub undiscovered_pools: Vec<NewUndiscoveredPool>,
+}
+
+/// `a + b`, where a missing side counts as zero and two missing sides stay missing
+fn add_optional(a: Option<BigDecimal>, b: Option<BigDecimal>) -> Option<BigDecimal> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Fold `pool` into the entry for the same protocol and pair, so each pair is upserted once
+fn add_undiscovered_pool(pools: &mut Vec<NewUndiscoveredPool>, pool: NewUndiscoveredPool) {
+    let existing = pools.iter_mut().find(|existing| {
+        existing.protocol == pool.protocol && existing.token_x == pool.token_x && existing.token_y == pool.token_y
+    });
+    match existing {
+        Some(existing) => {
+            existing.first_seen_version = existing.first_seen_version.min(pool.first_seen_version);
+            existing.last_seen_version = existing.last_seen_version.max(pool.last_seen_version);
+            existing.swap_count += pool.swap_count;
+        },
+        None => pools.push(pool),
+    }
+}
+
+impl VolumeData {
+    /// Combine two batches' results, e.g. to buffer several small batches before one flush.
+    /// `apt_data` is summed per protocol, `coin_volume_data` per coin and `coin_volume_buckets`
+    /// per `(coin, protocol, bucket_start)`; every other list is concatenated.
+    pub fn merge(mut self, other: VolumeData) -> VolumeData {
+        for record in other.apt_data {
+            match self.apt_data.iter_mut().find(|existing| existing.protocol_name == record.protocol_name) {
+                Some(existing) => {
+                    existing.apt_volume_24h = add_optional(existing.apt_volume_24h.take(), record.apt_volume_24h);
+                    existing.usdc_volume_24h = add_optional(existing.usdc_volume_24h.take(), record.usdc_volume_24h);
+                    existing.usdt_volume_24h = add_optional(existing.usdt_volume_24h.take(), record.usdt_volume_24h);
+                    existing.weth_volume_24h = add_optional(existing.weth_volume_24h.take(), record.weth_volume_24h);
+                    existing.btc_volume_24h = add_optional(existing.btc_volume_24h.take(), record.btc_volume_24h);
+                    existing.bnb_volume_24h = add_optional(existing.bnb_volume_24h.take(), record.bnb_volume_24h);
+                    existing.move_volume_24h = add_optional(existing.move_volume_24h.take(), record.move_volume_24h);
+                    existing.apt_fee_24h = add_optional(existing.apt_fee_24h.take(), record.apt_fee_24h);
+                    existing.usdc_fee_24h = add_optional(existing.usdc_fee_24h.take(), record.usdc_fee_24h);
+                    existing.usdt_fee_24h = add_optional(existing.usdt_fee_24h.take(), record.usdt_fee_24h);
+                    existing.weth_fee_24h = add_optional(existing.weth_fee_24h.take(), record.weth_fee_24h);
+                    existing.lp_fee_24h = add_optional(existing.lp_fee_24h.take(), record.lp_fee_24h);
+                    existing.protocol_fee_24h = add_optional(existing.protocol_fee_24h.take(), record.protocol_fee_24h);
+                    existing.trade_count += record.trade_count;
+                },
+                None => self.apt_data.push(record),
+            }
+        }
+
+        for record in other.coin_volume_data {
+            match self.coin_volume_data.iter_mut().find(|existing| existing.coin == record.coin) {
+                Some(existing) => {
+                    existing.buy_volume = add_optional(existing.buy_volume.take(), record.buy_volume);
+                    existing.sell_volume = add_optional(existing.sell_volume.take(), record.sell_volume);
+                },
+                None => self.coin_volume_data.push(record),
+            }
+        }
+
+        for bucket in other.coin_volume_buckets {
+            let existing = self.coin_volume_buckets.iter_mut().find(|existing| {
+                existing.coin == bucket.coin && existing.protocol == bucket.protocol && existing.bucket_start == bucket.bucket_start
+            });
+            match existing {
+                Some(existing) => existing.volume = add_optional(existing.volume.take(), bucket.volume),
+                None => self.coin_volume_buckets.push(bucket),
+            }
+        }
+
+        self.protocol_fees.extend(other.protocol_fees);
+        self.coin_volume_daily_buckets.extend(other.coin_volume_daily_buckets);
+        self.anomalous_swaps.extend(other.anomalous_swaps);
+        self.coin_prices.extend(other.coin_prices);
+        self.hyperion_fee_growth.extend(other.hyperion_fee_growth);
+        self.chain_metrics.extend(other.chain_metrics);
+        self.deployed_contracts.extend(other.deployed_contracts);
+        self.pair_volumes.extend(other.pair_volumes);
+        self.vwap_buckets.extend(other.vwap_buckets);
+        self.pool_reserves.extend(other.pool_reserves);
+        self.hyperion_pools.extend(other.hyperion_pools);
+        self.cellana_pools.extend(other.cellana_pools);
+        self.pool_migrations.extend(other.pool_migrations);
+        for pool in other.undiscovered_pools {
+            add_undiscovered_pool(&mut self.undiscovered_pools, pool);
+        }
+        self
+    }
+}
+
+/// Output of processing one protocol's events for a batch
+struct ProtocolEventsResult<V> {
+    volumes: HashMap<String, V>,
+    swap_events: Vec<SwapEventData>,
+    anomalous_swaps: Vec<NewAnomalousSwap>,
+    implied_prices: Vec<ImpliedPrice>,
+    undiscovered_pools: Vec<NewUndiscoveredPool>,
+}
+
+/// Output of `filter_events`: a batch's swap events by protocol, plus the block metadata
+/// and genesis records found alongside them
+#[derive(Default)]
+struct FilteredEventBatch {
+    cellana_events: Vec<ProtocolEvent>,
+    thala_events: Vec<ProtocolEvent>,
+    sushi_events: Vec<ProtocolEvent>,
+    // SushiSwap `SyncEvent`s, which carry pair reserves rather than swaps
+    sushi_sync_events: Vec<ProtocolEvent>,
+    liquid_events: Vec<ProtocolEvent>,
+    hyperion_events: Vec<ProtocolEvent>,
+    chain_metrics: Vec<ChainMetric>,
+    deployed_contracts: Vec<NewDeployedContract>,
+    hyperion_pools: Vec<NewHyperionPool>,
+    cellana_pools: Vec<NewCellanaPool>,
+    pool_migrations: Vec<PoolMigration>,
+    current_timestamp: i64,
+}
+
+/// Output of `dispatch_events`: each protocol's results before they are merged
+struct RawVolumeData {
+    cellana: ProtocolEventsResult<CellanaPoolVolume>,
+    thala: ProtocolEventsResult<ThalaPoolVolume>,
+    sushi: ProtocolEventsResult<SushiPoolVolume>,
+    liquid: ProtocolEventsResult<LiquidPoolVolume>,
+    hyperion: ProtocolEventsResult<HyperionPoolVolume>,
+    apt_data: Vec<NewAptData>,
+    hyperion_fee_growth: Vec<NewHyperionPoolFeeGrowth>,
+    pool_reserves: Vec<NewPoolReserve>,
+    chain_metrics: Vec<ChainMetric>,
+    deployed_contracts: Vec<NewDeployedContract>,
+    hyperion_pools: Vec<NewHyperionPool>,
+    cellana_pools: Vec<NewCellanaPool>,
+    pool_migrations: Vec<PoolMigration>,
+    current_timestamp: i64,
 }
 
 #[async_trait]
@@ -85,35 +507,160 @@ impl Processable for VolumeCalculator {
         &mut self,
         item: TransactionContext<Vec<Transaction>>,
     ) -> Result<Option<TransactionContext<VolumeData>>, ProcessorError> {
-        let transactions = item.data;
-        if transactions.is_empty() {
+        self.process_batch(item, None).await
+    }
+}
+
+impl VolumeCalculator {
+    /// Like `process`, but also sends each protocol's `apt_data` record on `apt_data_sender`
+    /// as soon as that protocol is done. The returned `VolumeData` still holds every record.
+    /// The sender is dropped on return, which closes the channel.
+    pub async fn process_streaming(
+        &mut self,
+        item: TransactionContext<Vec<Transaction>>,
+        apt_data_sender: mpsc::Sender<NewAptData>,
+    ) -> Result<Option<TransactionContext<VolumeData>>, ProcessorError> {
+        self.process_batch(item, Some(apt_data_sender)).await
+    }
+
+    #[tracing::instrument(
+        name = "volume_calculator.process",
+        level = "info",
+        skip_all,
+        fields(batch_start = item.metadata.start_version, batch_end = item.metadata.end_version)
+    )]
+    async fn process_batch(
+        &mut self,
+        item: TransactionContext<Vec<Transaction>>,
+        apt_data_sender: Option<mpsc::Sender<NewAptData>>,
+    ) -> Result<Option<TransactionContext<VolumeData>>, ProcessorError> {
+        if item.data.is_empty() {
             debug!("📭 No transactions to process");
             return Ok(Some(TransactionContext {
-                data: VolumeData {
-                    apt_data: vec![],
-                    coin_volume_data: vec![],
-                    coin_volume_buckets: vec![],
-                },
+                data: VolumeData::default(),
                 metadata: item.metadata,
             }));
         }
 
-        // Track all pool volumes by protocol and pool
-        let mut cellana_volumes: HashMap<String, CellanaPoolVolume> = HashMap::new();
-        let mut thala_volumes: HashMap<String, ThalaPoolVolume> = HashMap::new();
-        let mut sushi_volumes: HashMap<String, SushiPoolVolume> = HashMap::new();
-        let mut liquid_volumes: HashMap<String, LiquidPoolVolume> = HashMap::new();
-        let mut hyperion_volumes: HashMap<String, HyperionPoolVolume> = HashMap::new();
+        let filtered = self.filter_events(item.data);
+        let raw_volume_data = self.dispatch_events(filtered, apt_data_sender.as_ref()).await;
+        Ok(Some(TransactionContext {
+            data: self.aggregate_volumes(raw_volume_data),
+            metadata: item.metadata,
+        }))
+    }
 
-        // Collect swap events for bucket processing
-        let mut swap_events: Vec<SwapEventData> = Vec::new();
-        let current_timestamp = Utc::now().timestamp();
+    /// First stage: record block metadata, genesis deployments and checkpoints, and route
+    /// the in-window swap events of enabled protocols to their protocol
+    fn filter_events(&mut self, transactions: Vec<Transaction>) -> FilteredEventBatch {
+        let mut batch = FilteredEventBatch {
+            current_timestamp: Utc::now().timestamp(),
+            ..FilteredEventBatch::default()
+        };
+
+        for txn in transactions {
+            let txn = Arc::new(txn);
+
+            // Learn swap event types from DEX modules trusted deployers published in this transaction
+            if let Some(info) = &txn.info {
+                for change in &info.changes {
+                    if let Some(Change::WriteModule(write_module)) = &change.change {
+                        if let Some(protocol) = self.contract_registry.register_from_write_module(write_module) {
+                            // Stored so the registration survives a restart
+                            batch.deployed_contracts.extend(deployed_contract(write_module, protocol));
+                        }
+                    }
+                }
+            }
+
+            // Block metadata is recorded regardless of the volume window
+            if let Some(metric) = BlockMetadataProcessor::extract_chain_metric(&txn) {
+                self.block_metadata_processor.record(&metric);
+                batch.chain_metrics.push(metric);
+                continue;
+            }
+
+            // System transactions never carry DEX swaps
+            match &txn.txn_data {
+                Some(TxnData::Genesis(genesis_txn)) => {
+                    match self.process_genesis(genesis_txn) {
+                        Ok(contracts) => batch.deployed_contracts.extend(contracts),
+                        Err(e) => warn!("⚠️ Failed to scan genesis at version {}: {:#}", txn.version, e),
+                    }
+                    continue;
+                },
+                Some(TxnData::StateCheckpoint(_)) => {
+                    if let (Some(sender), Some(event)) = (&self.checkpoint_sender, CheckpointEvent::from_transaction(&txn)) {
+                        // Only fails when no monitor is listening
+                        let _ = sender.send(event);
+                    }
+                    continue;
+                },
+                Some(TxnData::User(user_txn))
+                    if user_txn.request.as_ref().is_some_and(|request| is_skipped_sender(&request.sender)) =>
+                {
+                    debug!("⏭️ Skipping framework transaction at version {}", txn.version);
+                    continue;
+                },
+                _ => {},
+            }
+
+            // Pool creations and migrations are recorded regardless of the volume window
+            if let Some(TxnData::User(user_txn)) = &txn.txn_data {
+                for event in &user_txn.events {
+                    if CellanaProcessor::is_migrate_pool_event(&event.type_str) {
+                        let migration = serde_json::from_str::<serde_json::Value>(&event.data)
+                            .context("Invalid MigratePoolEvent JSON")
+                            .and_then(|event_data| CellanaProcessor::handle_pool_migration(&event_data, &mut self.pool_registry));
+                        match migration {
+                            Ok(migration) => batch.pool_migrations.push(migration),
+                            Err(e) => warn!("⚠️ Failed to read Cellana MigratePoolEvent at version {}: {:#}", txn.version, e),
+                        }
+                        continue;
+                    }
+                    if CellanaProcessor::is_pool_created_event(&event.type_str) {
+                        let pool = serde_json::from_str::<serde_json::Value>(&event.data)
+                            .context("Invalid PoolCreatedEvent JSON")
+                            .and_then(|event_data| CellanaProcessor::handle_pool_created(&event_data));
+                        match pool {
+                            Ok(pool) => {
+                                CellanaProcessor::register_pool(&mut self.pool_registry, &pool.pool_address, &pool.token_x, &pool.token_y);
+                                batch.cellana_pools.push(NewCellanaPool {
+                                    created_at_version: txn.version as i64,
+                                    ..pool
+                                });
+                            },
+                            Err(e) => warn!("⚠️ Failed to read Cellana PoolCreatedEvent at version {}: {:#}", txn.version, e),
+                        }
+                        continue;
+                    }
+                    if !HyperionProcessor::is_initialize_pool_event(&event.type_str) {
+                        continue;
+                    }
+                    let pool = serde_json::from_str::<serde_json::Value>(&event.data)
+                        .context("Invalid InitializePoolEvent JSON")
+                        .and_then(|event_data| HyperionProcessor::handle_initialize_pool(&event_data));
+                    match pool {
+                        Ok(pool) => {
+                            self.hyperion_processor.register_pool(&pool.pool_address, &pool.token0, &pool.token1);
+                            batch.hyperion_pools.push(NewHyperionPool {
+                                created_at_version: txn.version as i64,
+                                ..pool
+                            });
+                        },
+                        Err(e) => warn!("⚠️ Failed to read Hyperion InitializePoolEvent at version {}: {:#}", txn.version, e),
+                    }
+                }
+            }
 
-        for txn in &transactions {
             let txn_timestamp = txn.timestamp.as_ref().unwrap().seconds;
             
-            // Skip transactions not within 24h
-            if !is_within_24h(txn_timestamp) {
+            // Skip transactions outside the current volume window (or the last 24h)
+            let in_window = match &self.volume_window {
+                Some(window) => window.contains(txn_timestamp),
+                None => is_within_24h(txn_timestamp),
+            };
+            if !in_window {
                 continue;
             }
 
@@ -133,196 +680,368 @@ impl Processable for VolumeCalculator {
                     if event_type.contains("31a6675cbe84365bf2b0cbce617ece6c47023ef70826533bde5203d32171dc3c") {
                         tracing::info!("🍣 Found event matching SushiSwap contract: {}", event_type);
                     }
-                    
-                    // Process Cellana events
-                    if event_type == CELLANA_SWAP_EVENT_TYPE {
-                        tracing::debug!("🟢 Processing Cellana event: {}", event_type);
-                        if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
-                            if let Ok(mut swap_data) = self.cellana_processor.extract_swap_data(&event_data) {
-                                // Fill fee information
-                                swap_data.swap_fee_bps = self.cellana_processor.extract_swap_fee_bps(txn, &swap_data.pool);
-                                
-                                // Collect Cellana for bucket processing (aggregated as "aptos")
-                                let coin_volumes = self.extract_coin_volumes_from_cellana(&swap_data);
-                                if !coin_volumes.is_empty() {
-                                    swap_events.push(SwapEventData {
-                                        timestamp_seconds: txn_timestamp,
-                                        coin_volumes,
-                                    });
-                                }
-                                
-                                // Process all Cellana swaps (removed target pool filter)
-                                self.cellana_processor.process_swap(&mut cellana_volumes, swap_data).await;
-                            }
-                        }
-                    }
-                    
-                    // Process Thala events
-                    else if event_type == THALA_SWAP_EVENT_TYPE {
-                        tracing::debug!("🔵 Processing Thala event: {}", event_type);
-                        if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
-                            if let Ok(swap_data) = self.thala_processor.extract_swap_data(&event_data) {
-                                // Collect Thala for bucket processing (aggregated as "aptos")
-                                let coin_volumes = self.extract_coin_volumes_from_thala(&swap_data);
-                                if !coin_volumes.is_empty() {
-                                    swap_events.push(SwapEventData {
-                                        timestamp_seconds: txn_timestamp,
-                                        coin_volumes,
-                                    });
-                                }
-                                
-                                // Process all Thala swaps (removed target pool filter)
-                                self.thala_processor.process_swap(&mut thala_volumes, swap_data).await;
-                            }
-                        }
-                    }
-                    
-                    // Process SushiSwap events
-                    else if self.sushi_swap_processor.is_sushiswap_event(event_type) {
-                        tracing::info!("🟠 FOUND SUSHISWAP EVENT: {}", event_type);
-                        
-                        if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
-                            match self.sushi_swap_processor.extract_sushiswap_data(&event_data, event_type) {
-                                Ok(swap_data) => {
-                                    tracing::info!("🔄 Processing SushiSwap swap: {:?}", swap_data);
-                                    
-                                    // Collect SushiSwap for bucket processing (aggregated as "aptos")
-                                    let coin_volumes = self.extract_coin_volumes_from_sushiswap(&swap_data);
-                                    if !coin_volumes.is_empty() {
-                                        swap_events.push(SwapEventData {
-                                            timestamp_seconds: txn_timestamp,
-                                            coin_volumes,
-                                        });
-                                    }
-                                    
-                                    self.sushi_swap_processor.process_sushiswap(&mut sushi_volumes, swap_data).await;
-                                    tracing::info!("✅ SushiSwap swap processed successfully");
-                                }
-                                Err(e) => {
-                                    tracing::error!("❌ Error extracting SushiSwap data: {}", e);
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Process LiquidSwap events
-                    else if self.liquid_swap_processor.is_liquidswap_event(event_type) {
-                        tracing::info!("🔵 FOUND LIQUIDSWAP EVENT: {}", event_type);
-                        
-                        if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
-                            match self.liquid_swap_processor.extract_liquidswap_data(&event_data, event_type) {
-                                Ok(swap_data) => {
-                                    tracing::info!("🔄 Processing LiquidSwap swap: {:?}", swap_data);
-                                    
-                                    // Collect LiquidSwap for bucket processing (aggregated as "aptos")
-                                    let coin_volumes = self.extract_coin_volumes_from_liquidswap(&swap_data);
-                                    if !coin_volumes.is_empty() {
-                                        swap_events.push(SwapEventData {
-                                            timestamp_seconds: txn_timestamp,
-                                            coin_volumes,
-                                        });
-                                    }
-                                    
-                                    self.liquid_swap_processor.process_liquidswap(&mut liquid_volumes, swap_data).await;
-                                    tracing::info!("✅ LiquidSwap swap processed successfully");
-                                }
-                                Err(e) => {
-                                    tracing::error!("❌ Error extracting LiquidSwap data: {}", e);
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Process Hyperion events
-                    else if event_type == HYPERION_SWAP_EVENT_TYPE {
-                        tracing::info!("🟡 FOUND HYPERION EVENT: {}", event_type);
-                        
-                        if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
-                            match self.hyperion_processor.extract_swap_data(&event_data) {
-                                Ok(swap_data) => {
-                                    tracing::info!("🔄 Processing Hyperion swap: {:?}", swap_data);
-                                    
-                                    // Collect Hyperion for bucket processing (aggregated as "aptos")
-                                    let coin_volumes = self.extract_coin_volumes_from_hyperion(&swap_data);
-                                    if !coin_volumes.is_empty() {
-                                        swap_events.push(SwapEventData {
-                                            timestamp_seconds: txn_timestamp,
-                                            coin_volumes,
-                                        });
-                                    }
-                                    
-                                    // Process all Hyperion swaps (removed target pool filter)
-                                    self.hyperion_processor.process_swap(&mut hyperion_volumes, swap_data).await;
-                                    tracing::info!("✅ Hyperion swap processed successfully");
-                                }
-                                Err(e) => {
-                                    tracing::error!("❌ Error extracting Hyperion data: {}", e);
-                                }
-                            }
+
+                    let (protocol_name, protocol_events) = if event_type == CELLANA_SWAP_EVENT_TYPE {
+                        ("cellana", &mut batch.cellana_events)
+                    } else if self.thala_processor.is_thala_event(event_type) {
+                        ("thala", &mut batch.thala_events)
+                    } else if self.sushi_swap_processor.is_sushiswap_event(event_type) {
+                        ("sushiswap", &mut batch.sushi_events)
+                    } else if self.sushi_swap_processor.is_sync_event(event_type) {
+                        ("sushiswap", &mut batch.sushi_sync_events)
+                    } else if self.liquid_swap_processor.is_liquidswap_event(event_type) {
+                        ("liquidswap", &mut batch.liquid_events)
+                    } else if self.hyperion_processor.is_hyperion_event(event_type) {
+                        ("hyperion", &mut batch.hyperion_events)
+                    } else if let Some(protocol_name) = self.contract_registry.protocol_for_event(event_type) {
+                        match protocol_name {
+                            "cellana" => ("cellana", &mut batch.cellana_events),
+                            "thala" => ("thala", &mut batch.thala_events),
+                            "sushiswap" => ("sushiswap", &mut batch.sushi_events),
+                            "hyperion" => ("hyperion", &mut batch.hyperion_events),
+                            "liquidswap" => ("liquidswap", &mut batch.liquid_events),
+                            unknown => {
+                                warn!("⚠️ Contract registry returned unsupported protocol {} for {}", unknown, event_type);
+                                continue;
+                            },
                         }
-                    }
-                    else {
+                    } else {
                         // Log non-matching events to help debug
                         if event_type.contains("swap") || event_type.contains("Swap") {
                             tracing::info!("❓ Unknown swap event (not Cellana/Thala/SushiSwap/LiquidSwap/Hyperion): {}", event_type);
                         }
+                        continue;
+                    };
+
+                    if !self.should_process(protocol_name) {
+                        continue;
                     }
+
+                    if let Ok(event_data) = serde_json::from_str::<serde_json::Value>(&event.data) {
+                        protocol_events.push(ProtocolEvent {
+                            event_data,
+                            raw_data: event.data.clone(),
+                            type_str: event_type.clone(),
+                            event_key: event.key.clone(),
+                            txn: Arc::clone(&txn),
+                            txn_timestamp,
+                        });
+                    }
+                }
+            }
+        }
+
+        debug!(
+            "🧮 Partitioned events: Cellana={}, Thala={}, SushiSwap={}, LiquidSwap={}, Hyperion={}",
+            batch.cellana_events.len(),
+            batch.thala_events.len(),
+            batch.sushi_events.len(),
+            batch.liquid_events.len(),
+            batch.hyperion_events.len()
+        );
+        batch
+    }
+
+    /// Second stage: run each protocol's handler over its events.
+    /// With `apt_data_sender`, each protocol's `apt_data` record is sent as soon as that protocol finishes.
+    async fn dispatch_events(
+        &self,
+        batch: FilteredEventBatch,
+        apt_data_sender: Option<&mpsc::Sender<NewAptData>>,
+    ) -> RawVolumeData {
+        let FilteredEventBatch {
+            cellana_events,
+            thala_events,
+            sushi_events,
+            sushi_sync_events,
+            liquid_events,
+            hyperion_events,
+            chain_metrics,
+            deployed_contracts,
+            hyperion_pools,
+            cellana_pools,
+            pool_migrations,
+            current_timestamp,
+        } = batch;
+
+        let hyperion_fee_growth = Self::hyperion_fee_growth(&hyperion_events);
+        let mut pool_reserves = Self::cellana_pool_reserves(&cellana_events);
+        pool_reserves.extend(self.sushiswap_pool_reserves(&sushi_sync_events));
+
+        // Drive all protocols as one future; each works on its own pool volume map. join! polls
+        // them on the same task and only switches at await points, so this is not parallel: the
+        // CPU-bound decoding still runs one protocol at a time.
+        // Each protocol gets a child span of the batch span so traces show where the time went.
+        let sender = apt_data_sender;
+        let (cellana_count, thala_count, sushi_count, liquid_count, hyperion_count) = (
+            cellana_events.len(), thala_events.len(), sushi_events.len(), liquid_events.len(), hyperion_events.len(),
+        );
+        let (
+            (cellana, cellana_apt_data),
+            (thala, thala_apt_data),
+            (sushi, sushi_apt_data),
+            (liquid, liquid_apt_data),
+            (hyperion, hyperion_apt_data),
+        ) = tokio::join!(
+            async { Self::finish_protocol(sender, self.process_cellana_events(cellana_events).await, Self::cellana_apt_data).await }
+                .instrument(debug_span!("cellana.process_events", event_count = cellana_count)),
+            async { Self::finish_protocol(sender, self.process_thala_events(thala_events).await, Self::thala_apt_data).await }
+                .instrument(debug_span!("thala.process_events", event_count = thala_count)),
+            async { Self::finish_protocol(sender, self.process_sushiswap_events(sushi_events).await, Self::sushiswap_apt_data).await }
+                .instrument(debug_span!("sushiswap.process_events", event_count = sushi_count)),
+            async { Self::finish_protocol(sender, self.process_liquidswap_events(liquid_events).await, Self::liquidswap_apt_data).await }
+                .instrument(debug_span!("liquidswap.process_events", event_count = liquid_count)),
+            async { Self::finish_protocol(sender, self.process_hyperion_events(hyperion_events).await, Self::hyperion_apt_data).await }
+                .instrument(debug_span!("hyperion.process_events", event_count = hyperion_count)),
+        );
+
+        if let Some(monitor) = &self.health_monitor {
+            let mut monitor = monitor.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            for (protocol, processed_swaps) in [
+                ("cellana", !cellana.volumes.is_empty()),
+                ("thala", !thala.volumes.is_empty()),
+                ("sushiswap", !sushi.volumes.is_empty()),
+                ("liquidswap", !liquid.volumes.is_empty()),
+                ("hyperion", !hyperion.volumes.is_empty()),
+            ] {
+                if processed_swaps {
+                    monitor.record_events(protocol);
                 }
             }
         }
 
+        RawVolumeData {
+            cellana,
+            thala,
+            sushi,
+            liquid,
+            hyperion,
+            apt_data: [cellana_apt_data, thala_apt_data, sushi_apt_data, liquid_apt_data, hyperion_apt_data]
+                .into_iter()
+                .flatten()
+                .collect(),
+            hyperion_fee_growth,
+            pool_reserves,
+            chain_metrics,
+            deployed_contracts,
+            hyperion_pools,
+            cellana_pools,
+            pool_migrations,
+            current_timestamp,
+        }
+    }
+
+    /// Third stage: merge the per-protocol results into buckets, coin and pair volumes and prices
+    fn aggregate_volumes(&self, raw: RawVolumeData) -> VolumeData {
+        let RawVolumeData {
+            cellana,
+            thala,
+            sushi,
+            liquid,
+            hyperion,
+            apt_data,
+            hyperion_fee_growth,
+            pool_reserves,
+            chain_metrics,
+            deployed_contracts,
+            hyperion_pools,
+            cellana_pools,
+            pool_migrations,
+            current_timestamp,
+        } = raw;
+
+        // Per-protocol buckets, written alongside the "all" buckets built from the merged events below
+        let mut protocol_volume_buckets: Vec<NewCoinVolumeBucket> = Vec::new();
+        for (protocol_name, events) in [
+            ("cellana", &cellana.swap_events),
+            ("thala", &thala.swap_events),
+            ("sushiswap", &sushi.swap_events),
+            ("liquidswap", &liquid.swap_events),
+            ("hyperion", &hyperion.swap_events),
+        ] {
+            if !events.is_empty() {
+                protocol_volume_buckets.extend(self.bucket_calculator.group_swaps_into_buckets_by_protocol(
+                    protocol_name,
+                    events.clone(),
+                    current_timestamp,
+                ));
+            }
+        }
+
+        // Merge swap events from all protocols for bucket processing (aggregated as "aptos")
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        swap_events.extend(cellana.swap_events);
+        swap_events.extend(thala.swap_events);
+        swap_events.extend(sushi.swap_events);
+        swap_events.extend(liquid.swap_events);
+        swap_events.extend(hyperion.swap_events);
+        anomalous_swaps.extend(cellana.anomalous_swaps);
+        anomalous_swaps.extend(thala.anomalous_swaps);
+        anomalous_swaps.extend(sushi.anomalous_swaps);
+        anomalous_swaps.extend(liquid.anomalous_swaps);
+        anomalous_swaps.extend(hyperion.anomalous_swaps);
+        let implied_prices: Vec<ImpliedPrice> = [
+            cellana.implied_prices,
+            thala.implied_prices,
+            sushi.implied_prices,
+            liquid.implied_prices,
+            hyperion.implied_prices,
+        ]
+        .concat();
+
+        if !anomalous_swaps.is_empty() {
+            warn!("🚨 Excluded {} anomalous swaps from volume in this batch", anomalous_swaps.len());
+        }
+
+        let divergent_prices = self.price_validator.validate(&implied_prices);
+        if divergent_prices > 0 {
+            warn!("⚠️ {} coin prices diverged across protocols in this batch", divergent_prices);
+        }
+        let vwap_buckets = self.bucket_calculator.group_prices_into_vwap_buckets(&implied_prices, current_timestamp);
+        let coin_prices = Self::to_coin_prices(implied_prices);
+
         // Process bucket data
         info!("🪣 Processing {} swap events into 2-hour buckets", swap_events.len());
-        let coin_volume_buckets = self.bucket_calculator.group_swaps_into_buckets(swap_events.clone(), current_timestamp);
+        let mut coin_volume_buckets = self.bucket_calculator.group_swaps_into_buckets(swap_events.clone(), current_timestamp);
+        coin_volume_buckets.extend(protocol_volume_buckets);
         info!("✅ Created {} bucket records", coin_volume_buckets.len());
+        let coin_volume_daily_buckets = self.bucket_calculator.group_swaps_into_daily_buckets(&swap_events, current_timestamp);
 
-        // Calculate 24h coin volume data from swap events
-        let coin_volume_data = self.calculate_24h_coin_volumes(&swap_events);
+        // Directional 24h coin volumes from each protocol's buy/sell tracking
+        let coin_volume_data = Self::coin_aggregates(
+            &cellana.volumes,
+            &thala.volumes,
+            &sushi.volumes,
+            &liquid.volumes,
+            &hyperion.volumes,
+        );
         info!("📊 Generated {} coin volume 24h records", coin_volume_data.len());
 
-        // Create results for each protocol - aggregate all pools per protocol
-        let mut results = Vec::new();
+        let pair_volumes = Self::pair_volumes(
+            &cellana.volumes,
+            &thala.volumes,
+            &sushi.volumes,
+            &liquid.volumes,
+            &hyperion.volumes,
+        );
+
+        // Fees are also emitted on their own for protocol_fees_24h
+        let protocol_fees: Vec<NewProtocolFee> = apt_data.iter().map(NewProtocolFee::from).collect();
+
+        info!("✅ Successfully processed {} records in batch", apt_data.len());
+
+        VolumeData {
+            apt_data,
+            protocol_fees,
+            coin_volume_data,
+            coin_volume_buckets,
+            coin_volume_daily_buckets,
+            anomalous_swaps,
+            coin_prices,
+            hyperion_fee_growth,
+            chain_metrics,
+            deployed_contracts,
+            pair_volumes,
+            vwap_buckets,
+            pool_reserves,
+            hyperion_pools,
+            cellana_pools,
+            pool_migrations,
+            undiscovered_pools: liquid.undiscovered_pools,
+        }
+    }
+}
+
+impl VolumeCalculator {
+    /// One swap on a pair missing from the registry. Tokens are stored in sorted order so
+    /// both directions of a pair share a row.
+    fn undiscovered_pool(protocol: &str, token_x: &str, token_y: &str, version: i64) -> NewUndiscoveredPool {
+        let (token_x, token_y) = if token_x <= token_y { (token_x, token_y) } else { (token_y, token_x) };
+        NewUndiscoveredPool {
+            protocol: protocol.to_string(),
+            token_x: token_x.to_string(),
+            token_y: token_y.to_string(),
+            first_seen_version: version,
+            last_seen_version: version,
+            swap_count: 1,
+        }
+    }
 
+    /// Build a protocol's `apt_data` record from its result and stream it if requested
+    async fn finish_protocol<V>(
+        sender: Option<&mpsc::Sender<NewAptData>>,
+        result: ProtocolEventsResult<V>,
+        to_apt_data: fn(&HashMap<String, V>) -> Option<NewAptData>,
+    ) -> (ProtocolEventsResult<V>, Option<NewAptData>) {
+        let apt_data = to_apt_data(&result.volumes);
+        if let (Some(sender), Some(record)) = (sender, &apt_data) {
+            if sender.send(record.clone()).await.is_err() {
+                warn!("📨 Streaming receiver dropped before {} data was sent", record.protocol_name);
+            }
+        }
+        (result, apt_data)
+    }
+
+    /// Sum Cellana pool volumes into its `apt_data` record, or None if the batch had no volume
+    fn cellana_apt_data(cellana_volumes: &HashMap<String, CellanaPoolVolume>) -> Option<NewAptData> {
         // Aggregate Cellana volumes across all pools
         let mut cellana_total_apt_volume = BigDecimal::zero();
         let mut cellana_total_usdc_volume = BigDecimal::zero();
         let mut cellana_total_usdt_volume = BigDecimal::zero();
+        let mut cellana_total_btc_volume = BigDecimal::zero();
         let mut cellana_total_apt_fee = BigDecimal::zero();
         let mut cellana_total_usdc_fee = BigDecimal::zero();
         let mut cellana_total_usdt_fee = BigDecimal::zero();
+        let mut cellana_total_lp_fee = BigDecimal::zero();
+        let mut cellana_total_protocol_fee = BigDecimal::zero();
+        let mut cellana_trade_count = 0;
 
-        for (_, pool_volume) in &cellana_volumes {
+        for pool_volume in cellana_volumes.values() {
             cellana_total_apt_volume += &pool_volume.apt_volume_24h;
             cellana_total_usdc_volume += &pool_volume.usdc_volume_24h;
             cellana_total_usdt_volume += &pool_volume.usdt_volume_24h;
+            cellana_total_btc_volume += &pool_volume.abtc_volume_24h;
             cellana_total_apt_fee += &pool_volume.apt_fee_24h;
             cellana_total_usdc_fee += &pool_volume.usdc_fee_24h;
             cellana_total_usdt_fee += &pool_volume.usdt_fee_24h;
+            cellana_total_lp_fee += &pool_volume.lp_fee_24h;
+            cellana_total_protocol_fee += &pool_volume.protocol_fee_24h;
+            cellana_trade_count += pool_volume.trade_count;
         }
 
         // Create Cellana result if there's any volume
-        if cellana_total_apt_volume > BigDecimal::zero() || 
-           cellana_total_usdc_volume > BigDecimal::zero() || 
-           cellana_total_usdt_volume > BigDecimal::zero() {
+        if is_positive(&cellana_total_apt_volume) || 
+           is_positive(&cellana_total_usdc_volume) || 
+           is_positive(&cellana_total_usdt_volume) ||
+           is_positive(&cellana_total_btc_volume) {
             
-            let apt_data = NewAptData {
-                protocol_name: "cellana".to_string(),
-                apt_volume_24h: Some(cellana_total_apt_volume.clone()),
-                usdc_volume_24h: Some(cellana_total_usdc_volume.clone()),
-                usdt_volume_24h: Some(cellana_total_usdt_volume.clone()),
-                weth_volume_24h: None, // Cellana doesn't support WETH yet
-                apt_fee_24h: Some(cellana_total_apt_fee.clone()),
-                usdc_fee_24h: Some(cellana_total_usdc_fee.clone()),
-                usdt_fee_24h: Some(cellana_total_usdt_fee.clone()),
-                weth_fee_24h: None, // Cellana doesn't support WETH yet
-            };
+            let apt_data = NewAptDataBuilder::new()
+                .protocol("cellana")
+                .apt_volume(cellana_total_apt_volume)
+                .usdc_volume(cellana_total_usdc_volume)
+                .usdt_volume(cellana_total_usdt_volume)
+                .btc_volume(cellana_total_btc_volume)
+                .apt_fee(cellana_total_apt_fee)
+                .usdc_fee(cellana_total_usdc_fee)
+                .usdt_fee(cellana_total_usdt_fee)
+                // WETH volume and fees stay None; Cellana doesn't support WETH yet
+                .trade_count(cellana_trade_count)
+                .lp_fee(cellana_total_lp_fee)
+                .protocol_fee(cellana_total_protocol_fee)
+                .build();
             
-            info!("💾 Created Cellana aggregated record: APT={:?}, USDC={:?}, USDT={:?}", 
-                apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h);
+            info!("💾 Created Cellana aggregated record: APT={:?}, USDC={:?}, USDT={:?}, BTC={:?}", 
+                apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h, apt_data.btc_volume_24h);
             
-            results.push(apt_data);
+            return Some(apt_data);
         }
 
+        None
+    }
+
+    /// Sum Thala pool volumes into its `apt_data` record, or None if the batch had no volume
+    fn thala_apt_data(thala_volumes: &HashMap<String, ThalaPoolVolume>) -> Option<NewAptData> {
         // Aggregate Thala volumes across all pools
         let mut thala_total_apt_volume = BigDecimal::zero();
         let mut thala_total_usdc_volume = BigDecimal::zero();
@@ -330,113 +1049,160 @@ impl Processable for VolumeCalculator {
         let mut thala_total_apt_fee = BigDecimal::zero();
         let mut thala_total_usdc_fee = BigDecimal::zero();
         let mut thala_total_usdt_fee = BigDecimal::zero();
+        let mut thala_total_lp_fee = BigDecimal::zero();
+        let mut thala_total_protocol_fee = BigDecimal::zero();
+        let mut thala_trade_count = 0;
+        // APT volume split by pool type (weighted/stable are the v1 events)
+        let mut thala_apt_volume_by_pool_type: HashMap<ThalaPoolType, BigDecimal> = HashMap::new();
 
-        for (_, pool_volume) in &thala_volumes {
+        for pool_volume in thala_volumes.values() {
+            *thala_apt_volume_by_pool_type.entry(pool_volume.pool_type).or_insert_with(BigDecimal::zero) += &pool_volume.apt_volume_24h;
             thala_total_apt_volume += &pool_volume.apt_volume_24h;
             thala_total_usdc_volume += &pool_volume.usdc_volume_24h;
             thala_total_usdt_volume += &pool_volume.usdt_volume_24h;
             thala_total_apt_fee += &pool_volume.apt_fee_24h;
             thala_total_usdc_fee += &pool_volume.usdc_fee_24h;
             thala_total_usdt_fee += &pool_volume.usdt_fee_24h;
+            let (lp_fee, protocol_fee) = pool_volume.fee_split_usd();
+            thala_total_lp_fee += lp_fee;
+            thala_total_protocol_fee += protocol_fee;
+            thala_trade_count += pool_volume.trade_count;
         }
 
         // Create Thala result if there's any volume
-        if thala_total_apt_volume > BigDecimal::zero() || 
-           thala_total_usdc_volume > BigDecimal::zero() ||
-           thala_total_usdt_volume > BigDecimal::zero() {
+        if is_positive(&thala_total_apt_volume) || 
+           is_positive(&thala_total_usdc_volume) ||
+           is_positive(&thala_total_usdt_volume) {
             
-            let apt_data = NewAptData {
-                protocol_name: "thala".to_string(),
-                apt_volume_24h: Some(thala_total_apt_volume.clone()),
-                usdc_volume_24h: Some(thala_total_usdc_volume.clone()),
-                usdt_volume_24h: Some(thala_total_usdt_volume.clone()),
-                weth_volume_24h: None, // Thala doesn't support WETH yet
-                apt_fee_24h: Some(thala_total_apt_fee.clone()),
-                usdc_fee_24h: Some(thala_total_usdc_fee.clone()),
-                usdt_fee_24h: Some(thala_total_usdt_fee.clone()),
-                weth_fee_24h: None, // Thala doesn't support WETH yet
-            };
+            let apt_data = NewAptDataBuilder::new()
+                .protocol("thala")
+                .apt_volume(thala_total_apt_volume)
+                .usdc_volume(thala_total_usdc_volume)
+                .usdt_volume(thala_total_usdt_volume)
+                .apt_fee(thala_total_apt_fee)
+                .usdc_fee(thala_total_usdc_fee)
+                .usdt_fee(thala_total_usdt_fee)
+                .lp_fee(thala_total_lp_fee)
+                .protocol_fee(thala_total_protocol_fee)
+                // WETH volume and fees stay None; Thala doesn't support WETH yet
+                .trade_count(thala_trade_count)
+                .build();
             
             info!("💾 Created Thala aggregated record: APT={:?}, USDC={:?}, USDT={:?}", 
                 apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h);
+            debug!("📊 Thala APT volume by pool type: {:?}", thala_apt_volume_by_pool_type);
             
-            results.push(apt_data);
+            return Some(apt_data);
         }
 
+        None
+    }
+
+    /// Sum SushiSwap pool volumes into its `apt_data` record, or None if the batch had no volume
+    fn sushiswap_apt_data(sushi_volumes: &HashMap<String, SushiPoolVolume>) -> Option<NewAptData> {
         // Aggregate SushiSwap volumes across all pools
         let mut sushi_total_apt_volume = BigDecimal::zero();
         let mut sushi_total_usdc_volume = BigDecimal::zero();
         let mut sushi_total_usdt_volume = BigDecimal::zero();
         let mut sushi_total_weth_volume = BigDecimal::zero();
+        let mut sushi_trade_count = 0;
 
-        for (_, pool_volume) in &sushi_volumes {
+        for pool_volume in sushi_volumes.values() {
             sushi_total_apt_volume += &pool_volume.apt_volume_24h;
             sushi_total_usdc_volume += &pool_volume.usdc_volume_24h;
             sushi_total_usdt_volume += &pool_volume.usdt_volume_24h;
             sushi_total_weth_volume += &pool_volume.weth_volume_24h;
+            sushi_trade_count += pool_volume.trade_count;
         }
 
         // Create SushiSwap result if there's any volume
-        if sushi_total_apt_volume > BigDecimal::zero() || 
-           sushi_total_usdt_volume > BigDecimal::zero() ||
-           sushi_total_usdc_volume > BigDecimal::zero() ||
-           sushi_total_weth_volume > BigDecimal::zero() {
+        if is_positive(&sushi_total_apt_volume) || 
+           is_positive(&sushi_total_usdt_volume) ||
+           is_positive(&sushi_total_usdc_volume) ||
+           is_positive(&sushi_total_weth_volume) {
             
-            let apt_data = NewAptData {
-                protocol_name: "sushiswap".to_string(),
-                apt_volume_24h: Some(sushi_total_apt_volume.clone()),
-                usdc_volume_24h: Some(sushi_total_usdc_volume.clone()),
-                usdt_volume_24h: Some(sushi_total_usdt_volume.clone()),
-                weth_volume_24h: Some(sushi_total_weth_volume.clone()),
-                apt_fee_24h: None,
-                usdc_fee_24h: None,
-                usdt_fee_24h: None,
-                weth_fee_24h: None,
-            };
+            let apt_data = NewAptDataBuilder::new()
+                .protocol("sushiswap")
+                .apt_volume(sushi_total_apt_volume)
+                .usdc_volume(sushi_total_usdc_volume)
+                .usdt_volume(sushi_total_usdt_volume)
+                .weth_volume(sushi_total_weth_volume)
+                .trade_count(sushi_trade_count)
+                .build();
             
             info!("💾 Created SushiSwap aggregated record: APT={:?}, USDT={:?}, USDC={:?}, WETH={:?}", 
                 apt_data.apt_volume_24h, apt_data.usdt_volume_24h, apt_data.usdc_volume_24h, apt_data.weth_volume_24h);
             
-            results.push(apt_data);
+            return Some(apt_data);
         }
 
+        None
+    }
+
+    /// Sum LiquidSwap pool volumes into its `apt_data` record, or None if the batch had no volume
+    fn liquidswap_apt_data(liquid_volumes: &HashMap<String, LiquidPoolVolume>) -> Option<NewAptData> {
         // Aggregate LiquidSwap volumes across all pools
         let mut liquid_total_apt_volume = BigDecimal::zero();
         let mut liquid_total_usdc_volume = BigDecimal::zero();
         let mut liquid_total_usdt_volume = BigDecimal::zero();
         let mut liquid_total_weth_volume = BigDecimal::zero();
+        let mut liquid_total_btc_volume = BigDecimal::zero();
+        let mut liquid_total_bnb_volume = BigDecimal::zero();
+        let mut liquid_total_move_volume = BigDecimal::zero();
+        let mut liquid_total_apt_fee = BigDecimal::zero();
+        let mut liquid_total_usdc_fee = BigDecimal::zero();
+        let mut liquid_total_usdt_fee = BigDecimal::zero();
+        let mut liquid_trade_count = 0;
 
-        for (_, pool_volume) in &liquid_volumes {
+        for pool_volume in liquid_volumes.values() {
             liquid_total_apt_volume += &pool_volume.apt_volume_24h;
             liquid_total_usdc_volume += &pool_volume.usdc_volume_24h;
             liquid_total_usdt_volume += &pool_volume.usdt_volume_24h;
             liquid_total_weth_volume += &pool_volume.weth_volume_24h;
+            liquid_total_btc_volume += &pool_volume.btc_volume_24h;
+            liquid_total_bnb_volume += &pool_volume.bnb_volume_24h;
+            liquid_total_move_volume += &pool_volume.move_volume_24h;
+            liquid_total_apt_fee += &pool_volume.apt_fee_24h;
+            liquid_total_usdc_fee += &pool_volume.usdc_fee_24h;
+            liquid_total_usdt_fee += &pool_volume.usdt_fee_24h;
+            liquid_trade_count += pool_volume.trade_count;
         }
 
         // Create LiquidSwap result if there's any volume
-        if liquid_total_apt_volume > BigDecimal::zero() || 
-           liquid_total_usdc_volume > BigDecimal::zero() ||
-           liquid_total_usdt_volume > BigDecimal::zero() ||
-           liquid_total_weth_volume > BigDecimal::zero() {
+        if is_positive(&liquid_total_apt_volume) || 
+           is_positive(&liquid_total_usdc_volume) ||
+           is_positive(&liquid_total_usdt_volume) ||
+           is_positive(&liquid_total_weth_volume) ||
+           is_positive(&liquid_total_btc_volume) ||
+           is_positive(&liquid_total_bnb_volume) ||
+           is_positive(&liquid_total_move_volume) {
             
-            let apt_data = NewAptData {
-                protocol_name: "liquidswap".to_string(),
-                apt_volume_24h: Some(liquid_total_apt_volume.clone()),
-                usdc_volume_24h: Some(liquid_total_usdc_volume.clone()),
-                usdt_volume_24h: Some(liquid_total_usdt_volume.clone()),
-                weth_volume_24h: Some(liquid_total_weth_volume.clone()),
-                apt_fee_24h: None,
-                usdc_fee_24h: None,
-                usdt_fee_24h: None,
-                weth_fee_24h: None,
-            };
+            let apt_data = NewAptDataBuilder::new()
+                .protocol("liquidswap")
+                .apt_volume(liquid_total_apt_volume)
+                .usdc_volume(liquid_total_usdc_volume)
+                .usdt_volume(liquid_total_usdt_volume)
+                .weth_volume(liquid_total_weth_volume)
+                .btc_volume(liquid_total_btc_volume)
+                .bnb_volume(liquid_total_bnb_volume)
+                .move_volume(liquid_total_move_volume)
+                .apt_fee(liquid_total_apt_fee)
+                .usdc_fee(liquid_total_usdc_fee)
+                .usdt_fee(liquid_total_usdt_fee)
+                .trade_count(liquid_trade_count)
+                .build();
             
-            info!("💾 Created LiquidSwap aggregated record: APT={:?}, USDC={:?}, USDT={:?}, WETH={:?}", 
-                apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h, apt_data.weth_volume_24h);
+            info!("💾 Created LiquidSwap aggregated record: APT={:?}, USDC={:?}, USDT={:?}, WETH={:?}, BTC={:?}, BNB={:?}, MOVE={:?}", 
+                apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h, apt_data.weth_volume_24h, apt_data.btc_volume_24h, apt_data.bnb_volume_24h, apt_data.move_volume_24h);
             
-            results.push(apt_data);
+            return Some(apt_data);
         }
 
+        None
+    }
+
+    /// Sum Hyperion pool volumes into its `apt_data` record, or None if the batch had no volume
+    fn hyperion_apt_data(hyperion_volumes: &HashMap<String, HyperionPoolVolume>) -> Option<NewAptData> {
         // Aggregate Hyperion volumes
         let mut hyperion_total_apt_volume = BigDecimal::zero();
         let mut hyperion_total_usdc_volume = BigDecimal::zero();
@@ -444,54 +1210,539 @@ impl Processable for VolumeCalculator {
         let mut hyperion_total_apt_fee = BigDecimal::zero();
         let mut hyperion_total_usdc_fee = BigDecimal::zero();
         let mut hyperion_total_usdt_fee = BigDecimal::zero();
+        let mut hyperion_trade_count = 0;
 
-        for (_, pool_volume) in &hyperion_volumes {
+        for pool_volume in hyperion_volumes.values() {
             hyperion_total_apt_volume += &pool_volume.apt_volume_24h;
             hyperion_total_usdc_volume += &pool_volume.usdc_volume_24h;
             hyperion_total_usdt_volume += &pool_volume.usdt_volume_24h;
             hyperion_total_apt_fee += &pool_volume.apt_fee_24h;
             hyperion_total_usdc_fee += &pool_volume.usdc_fee_24h;
             hyperion_total_usdt_fee += &pool_volume.usdt_fee_24h;
+            hyperion_trade_count += pool_volume.trade_count;
         }
 
         // Create Hyperion result if there's any volume
-        if hyperion_total_apt_volume > BigDecimal::zero() || 
-           hyperion_total_usdc_volume > BigDecimal::zero() || 
-           hyperion_total_usdt_volume > BigDecimal::zero() {
+        if is_positive(&hyperion_total_apt_volume) || 
+           is_positive(&hyperion_total_usdc_volume) || 
+           is_positive(&hyperion_total_usdt_volume) {
             
-            let apt_data = NewAptData {
-                protocol_name: "hyperion".to_string(),
-                apt_volume_24h: Some(hyperion_total_apt_volume.clone()),
-                usdc_volume_24h: Some(hyperion_total_usdc_volume.clone()),
-                usdt_volume_24h: Some(hyperion_total_usdt_volume.clone()),
-                weth_volume_24h: None, // Hyperion doesn't support WETH
-                apt_fee_24h: Some(hyperion_total_apt_fee.clone()),
-                usdc_fee_24h: Some(hyperion_total_usdc_fee.clone()),
-                usdt_fee_24h: Some(hyperion_total_usdt_fee.clone()),
-                weth_fee_24h: None, // Hyperion doesn't support WETH
-            };
+            let apt_data = NewAptDataBuilder::new()
+                .protocol("hyperion")
+                .apt_volume(hyperion_total_apt_volume)
+                .usdc_volume(hyperion_total_usdc_volume)
+                .usdt_volume(hyperion_total_usdt_volume)
+                .apt_fee(hyperion_total_apt_fee)
+                .usdc_fee(hyperion_total_usdc_fee)
+                .usdt_fee(hyperion_total_usdt_fee)
+                // WETH volume and fees stay None; Hyperion doesn't support WETH
+                .trade_count(hyperion_trade_count)
+                .build();
             
             info!("💾 Created Hyperion aggregated record: APT={:?}, USDC={:?}, USDT={:?}, APT_fee={:?}, USDC_fee={:?}, USDT_fee={:?}", 
                 apt_data.apt_volume_24h, apt_data.usdc_volume_24h, apt_data.usdt_volume_24h, 
                 apt_data.apt_fee_24h, apt_data.usdc_fee_24h, apt_data.usdt_fee_24h);
             
-            results.push(apt_data);
+            return Some(apt_data);
         }
 
-        info!("✅ Successfully processed {} records in batch", results.len());
+        None
+    }
 
-        Ok(Some(TransactionContext {
-            data: VolumeData {
-                apt_data: results,
-                coin_volume_data: coin_volume_data,
-                coin_volume_buckets,
+    /// Returns an `anomalous_swaps` record if any normalized amount of the swap exceeds its
+    /// single-swap threshold. Such swaps are excluded from all volume calculations.
+    fn check_anomaly(&self, protocol_name: &str, event: &ProtocolEvent, coin_volumes: &[CoinVolumeData]) -> Option<NewAnomalousSwap> {
+        let anomalous = coin_volumes
+            .iter()
+            .find(|cv| self.anomaly_detector.is_anomalous(&cv.coin, &cv.volume))?;
+
+        warn!(
+            "🚨 Anomalous {} swap at version {}: {} {} exceeds single-swap threshold ({})",
+            protocol_name, event.txn.version, anomalous.volume, anomalous.coin, event.type_str
+        );
+        ANOMALIES_DETECTED_TOTAL.with_label_values(&[protocol_name]).inc();
+
+        Some(NewAnomalousSwap {
+            protocol_name: protocol_name.to_string(),
+            transaction_version: event.txn.version as i64,
+            event_type: event.type_str.to_string(),
+            coin: anomalous.coin.clone(),
+            amount: anomalous.volume.clone(),
+            event_data: event.event_data.clone(),
+            transaction_timestamp: DateTime::from_timestamp(event.txn_timestamp, 0)
+                .unwrap_or_default()
+                .naive_utc(),
+            user_address: match &event.txn.txn_data {
+                Some(TxnData::User(user_txn)) => extract_primary_signer(user_txn).map(str::to_string),
+                _ => None,
             },
-            metadata: item.metadata,
-        }))
+        })
+    }
+
+    /// Decode an event through the payload cache, calling `extract` only on a miss
+    fn extract_cached(
+        &self,
+        event: &ProtocolEvent,
+        extract: impl FnOnce() -> Result<ProcessedEventResult>,
+    ) -> Result<ProcessedEventResult> {
+        let key = EventCache::key(&event.type_str, &event.raw_data);
+        if let Some(cached) = self.event_cache.lock().unwrap().get(&key) {
+            return Ok(cached);
+        }
+        let result = extract()?;
+        self.event_cache.lock().unwrap().insert(key, result.clone());
+        Ok(result)
+    }
+
+    /// Run one swap's processing, returning false if it didn't finish within
+    /// `event_processing_timeout`. The timeout is only checked when `processing` yields at an
+    /// `.await`, so a processor stuck on I/O costs one event, but a CPU-bound loop is not interrupted.
+    async fn within_event_timeout(&self, protocol: &str, event_type: &str, processing: impl Future<Output = ()>) -> bool {
+        match tokio::time::timeout(self.event_processing_timeout, processing).await {
+            Ok(()) => true,
+            Err(_) => {
+                error!("⏰ {} swap processing timed out after {:?}, skipping {}", protocol, self.event_processing_timeout, event_type);
+                PROCESSING_TIMEOUTS_TOTAL.with_label_values(&[protocol]).inc();
+                false
+            },
+        }
+    }
+
+    /// Attach source, timing and the swapped base amount to a `(base_coin, quote_coin, price)`
+    /// implied by a swap
+    fn implied_price(
+        &self,
+        protocol_name: &str,
+        event: &ProtocolEvent,
+        coin_volumes: &[CoinVolumeData],
+        price: Option<(String, String, BigDecimal)>,
+    ) -> Option<ImpliedPrice> {
+        let (base_coin, quote_coin, price) = price?;
+        let base_volume = coin_volumes
+            .iter()
+            .find(|cv| cv.coin == base_coin)
+            .map(|cv| cv.volume.clone())
+            .unwrap_or_default();
+        Some(ImpliedPrice {
+            source_protocol: protocol_name.to_string(),
+            base_coin,
+            quote_coin,
+            price,
+            base_volume,
+            transaction_version: event.txn.version as i64,
+            timestamp_seconds: event.txn_timestamp,
+        })
+    }
+
+    /// Convert implied prices into `coin_price_usd` rows; stablecoin quotes are taken as USD
+    fn to_coin_prices(implied_prices: Vec<ImpliedPrice>) -> Vec<NewCoinPriceUsd> {
+        implied_prices
+            .into_iter()
+            .map(|price| NewCoinPriceUsd {
+                coin: price.base_coin,
+                quote_coin: price.quote_coin,
+                price_usd: price.price,
+                source_protocol: price.source_protocol,
+                transaction_version: price.transaction_version,
+                transaction_timestamp: DateTime::from_timestamp(price.timestamp_seconds, 0)
+                    .unwrap_or_default()
+                    .naive_utc(),
+            })
+            .collect()
+    }
+
+    /// Convert each protocol's native-unit volumes and fees to USD totals.
+    /// `prices` maps coin names ("APT", "WETH", ...) to USD; USDC and USDT default to 1.0.
+    /// Coins without a price are left out of the totals.
+    pub fn compute_usd_volume(apt_data: &[NewAptData], prices: &HashMap<String, BigDecimal>) -> Vec<NewAptDataUsd> {
+        let one = BigDecimal::from(1);
+        let price_of = |coin: &str| match prices.get(coin) {
+            Some(price) => Some(price),
+            None if USD_QUOTE_COINS.contains(&coin) => Some(&one),
+            None => None,
+        };
+        let to_usd = |coin: &str, amount: &Option<BigDecimal>| -> BigDecimal {
+            match (amount, price_of(coin)) {
+                (Some(amount), Some(price)) => amount * price,
+                (Some(amount), None) if !amount.is_zero() => {
+                    debug!("💲 No USD price for {}, leaving {} {} out of USD totals", coin, amount, coin);
+                    BigDecimal::zero()
+                },
+                _ => BigDecimal::zero(),
+            }
+        };
+
+        apt_data
+            .iter()
+            .map(|data| NewAptDataUsd {
+                protocol_name: data.protocol_name.clone(),
+                total_usd_volume: to_usd("APT", &data.apt_volume_24h)
+                    + to_usd("USDC", &data.usdc_volume_24h)
+                    + to_usd("USDT", &data.usdt_volume_24h)
+                    + to_usd("WETH", &data.weth_volume_24h)
+                    + to_usd("BTC", &data.btc_volume_24h)
+                    + to_usd("BNB", &data.bnb_volume_24h)
+                    + to_usd("MOVE", &data.move_volume_24h),
+                total_usd_fee: to_usd("APT", &data.apt_fee_24h)
+                    + to_usd("USDC", &data.usdc_fee_24h)
+                    + to_usd("USDT", &data.usdt_fee_24h)
+                    + to_usd("WETH", &data.weth_fee_24h),
+            })
+            .collect()
+    }
+
+    /// LP fee growth recorded by each Hyperion swap that reports it
+    fn hyperion_fee_growth(events: &[ProtocolEvent]) -> Vec<NewHyperionPoolFeeGrowth> {
+        events
+            .iter()
+            .filter_map(|event| {
+                let (fee_growth_x, fee_growth_y) = HyperionProcessor::extract_fee_growth_delta(&event.event_data)?;
+                let pool_address = HyperionProcessor::extract_pool_from_type_str(&event.type_str)
+                    .or_else(|| event.event_data.get("pool_id")?.as_str().map(str::to_string))?;
+                Some(NewHyperionPoolFeeGrowth {
+                    pool_address,
+                    fee_growth_x,
+                    fee_growth_y,
+                    txn_version: event.txn.version as i64,
+                })
+            })
+            .collect()
+    }
+
+    /// Latest reserves of each Cellana pool that swapped in a transaction writing its pool resource
+    fn cellana_pool_reserves(events: &[ProtocolEvent]) -> Vec<NewPoolReserve> {
+        let mut latest: HashMap<&str, NewPoolReserve> = HashMap::new();
+        for event in events {
+            let Some(pool_address) = event.event_data.get("pool").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some((reserve_x, reserve_y)) = CellanaProcessor::extract_pool_reserves(&event.txn, pool_address) else {
+                continue;
+            };
+            let txn_version = event.txn.version as i64;
+            if latest.get(pool_address).is_some_and(|reserve| reserve.txn_version > txn_version) {
+                continue;
+            }
+            latest.insert(pool_address, NewPoolReserve {
+                pool_address: pool_address.to_string(),
+                protocol: "cellana".to_string(),
+                reserve_x,
+                reserve_y,
+                txn_version,
+                current_tick: CellanaProcessor::extract_current_tick(&event.txn, pool_address),
+            });
+        }
+        latest.into_values().collect()
+    }
+
+    /// Latest reserves of each SushiSwap pair from its `SyncEvent`s. Pairs are keyed by their
+    /// coin types, like the type parameters of the event.
+    fn sushiswap_pool_reserves(&self, events: &[ProtocolEvent]) -> Vec<NewPoolReserve> {
+        let mut latest: HashMap<String, NewPoolReserve> = HashMap::new();
+        for event in events {
+            let Some((token_x, token_y)) = self.sushi_swap_processor.extract_token_types_from_type_str(&event.type_str) else {
+                continue;
+            };
+            let pair_key = format!("{}, {}", token_x, token_y);
+            let Some((reserve_x, reserve_y)) = SushiSwapProcessor::handle_sync_event(&event.event_data, &pair_key) else {
+                continue;
+            };
+            let txn_version = event.txn.version as i64;
+            if latest.get(&pair_key).is_some_and(|reserve| reserve.txn_version > txn_version) {
+                continue;
+            }
+            latest.insert(pair_key.clone(), NewPoolReserve {
+                pool_address: pair_key,
+                protocol: "sushiswap".to_string(),
+                reserve_x,
+                reserve_y,
+                txn_version,
+                current_tick: None,
+            });
+        }
+        latest.into_values().collect()
+    }
+
+    /// Process partitioned Cellana events into per-pool volumes and bucket swap events
+    async fn process_cellana_events(&self, events: Vec<ProtocolEvent>) -> ProtocolEventsResult<CellanaPoolVolume> {
+        let mut cellana_volumes: HashMap<String, CellanaPoolVolume> = HashMap::new();
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        let mut implied_prices: Vec<ImpliedPrice> = Vec::new();
+
+        for event in events {
+            tracing::debug!("🟢 Processing Cellana event: {}", event.type_str);
+            let extracted = self.extract_cached(&event, || {
+                self.cellana_processor.extract_swap_data(&event.event_data).map(ProcessedEventResult::Cellana)
+            });
+            if let Ok(ProcessedEventResult::Cellana(mut swap_data)) = extracted {
+                // Fill fee information
+                (swap_data.swap_fee_bps, swap_data.protocol_fee_bps) = self.cellana_processor.extract_fee_bps(&event.txn, &swap_data.pool);
+                
+                let coin_volumes = self.extract_coin_volumes_from_cellana(&swap_data);
+                if let Some(anomaly) = self.check_anomaly("cellana", &event, &coin_volumes) {
+                    anomalous_swaps.push(anomaly);
+                    continue;
+                }
+                let implied_price = self.implied_price("cellana", &event, &coin_volumes, implied_price_from_coin_volumes(&coin_volumes));
+
+                // Process all Cellana swaps (removed target pool filter)
+                if !self.within_event_timeout("cellana", &event.type_str, self.cellana_processor.process_swap(&mut cellana_volumes, swap_data)).await {
+                    continue;
+                }
+                implied_prices.extend(implied_price);
+                self.record_pair_metrics("cellana", &coin_volumes);
+                if !coin_volumes.is_empty() {
+                    swap_events.push(SwapEventData {
+                        timestamp_seconds: event.txn_timestamp,
+                        coin_volumes,
+                    });
+                }
+            }
+        }
+
+        ProtocolEventsResult {
+            volumes: cellana_volumes,
+            swap_events,
+            anomalous_swaps,
+            implied_prices,
+            undiscovered_pools: Vec::new(),
+        }
+    }
+
+    /// Process partitioned Thala events into per-pool volumes and bucket swap events
+    async fn process_thala_events(&self, events: Vec<ProtocolEvent>) -> ProtocolEventsResult<ThalaPoolVolume> {
+        let mut thala_volumes: HashMap<String, ThalaPoolVolume> = HashMap::new();
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        let mut implied_prices: Vec<ImpliedPrice> = Vec::new();
+
+        for event in events {
+            tracing::debug!("🔵 Processing Thala event: {}", event.type_str);
+            let extracted = self.extract_cached(&event, || {
+                self.thala_processor.extract_swap_data(&event.event_data, &event.type_str).map(ProcessedEventResult::Thala)
+            });
+            if let Ok(ProcessedEventResult::Thala(mut swap_data)) = extracted {
+                // v1 pools are keyed by their coin types, but their fee config is a resource
+                // on the pool account that emitted the event
+                let fee_pool = match swap_data.pool_type {
+                    ThalaPoolType::Unified => swap_data.pool.clone(),
+                    _ => event.event_key.as_ref().and_then(ThalaProcessor::extract_pool_from_event_key).unwrap_or_else(|| swap_data.pool.clone()),
+                };
+                (swap_data.lp_fee_bps, swap_data.protocol_fee_bps) = ThalaProcessor::extract_fees(&event.txn, &fee_pool);
+
+                let coin_volumes = self.extract_coin_volumes_from_thala(&swap_data);
+                if let Some(anomaly) = self.check_anomaly("thala", &event, &coin_volumes) {
+                    anomalous_swaps.push(anomaly);
+                    continue;
+                }
+                let implied_price = self.implied_price("thala", &event, &coin_volumes, implied_price_from_coin_volumes(&coin_volumes));
+
+                // Process all Thala swaps (removed target pool filter)
+                if !self.within_event_timeout("thala", &event.type_str, self.thala_processor.process_swap(&mut thala_volumes, swap_data)).await {
+                    continue;
+                }
+                implied_prices.extend(implied_price);
+                self.record_pair_metrics("thala", &coin_volumes);
+                if !coin_volumes.is_empty() {
+                    swap_events.push(SwapEventData {
+                        timestamp_seconds: event.txn_timestamp,
+                        coin_volumes,
+                    });
+                }
+            }
+        }
+
+        ProtocolEventsResult {
+            volumes: thala_volumes,
+            swap_events,
+            anomalous_swaps,
+            implied_prices,
+            undiscovered_pools: Vec::new(),
+        }
+    }
+
+    /// Process partitioned SushiSwap events into per-pool volumes and bucket swap events
+    async fn process_sushiswap_events(&self, events: Vec<ProtocolEvent>) -> ProtocolEventsResult<SushiPoolVolume> {
+        let mut sushi_volumes: HashMap<String, SushiPoolVolume> = HashMap::new();
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        let mut implied_prices: Vec<ImpliedPrice> = Vec::new();
+
+        for event in events {
+            tracing::info!("🟠 FOUND SUSHISWAP EVENT: {}", event.type_str);
+            if let Some(version) = sushi_contract_version_from_type_str(&event.type_str) {
+                debug!("🟠 SushiSwap contract v{} event", version);
+            }
+            let extracted = self.extract_cached(&event, || {
+                self.sushi_swap_processor.extract_sushiswap_data(&event.event_data, &event.type_str).map(ProcessedEventResult::SushiSwap)
+            });
+            match extracted {
+                Ok(ProcessedEventResult::SushiSwap(swap_data)) => {
+                    if !SushiSwapProcessor::is_meaningful_swap(&swap_data) {
+                        debug!("⏭️ Skipping zero-amount SushiSwap event in txn {}", event.txn.version);
+                        continue;
+                    }
+                    tracing::info!("🔄 Processing SushiSwap swap: {:?}", swap_data);
+                    
+                    let coin_volumes = self.extract_coin_volumes_from_sushiswap(&swap_data);
+                    if let Some(anomaly) = self.check_anomaly("sushiswap", &event, &coin_volumes) {
+                        anomalous_swaps.push(anomaly);
+                        continue;
+                    }
+                    let implied_price = self.implied_price("sushiswap", &event, &coin_volumes, self.sushi_swap_processor.get_implied_price(&swap_data));
+
+                    if !self.within_event_timeout("sushiswap", &event.type_str, self.sushi_swap_processor.process_sushiswap(&mut sushi_volumes, swap_data)).await {
+                        continue;
+                    }
+                    tracing::info!("✅ SushiSwap swap processed successfully");
+                    implied_prices.extend(implied_price);
+                    self.record_pair_metrics("sushiswap", &coin_volumes);
+                    if !coin_volumes.is_empty() {
+                        swap_events.push(SwapEventData {
+                            timestamp_seconds: event.txn_timestamp,
+                            coin_volumes,
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("❌ Error extracting SushiSwap data: {}", e);
+                }
+                // Cache keys include the event type, so a cached result is always this protocol's
+                Ok(_) => {}
+            }
+        }
+
+        ProtocolEventsResult {
+            volumes: sushi_volumes,
+            swap_events,
+            anomalous_swaps,
+            implied_prices,
+            undiscovered_pools: Vec::new(),
+        }
+    }
+
+    /// Process partitioned LiquidSwap events into per-pool volumes and bucket swap events
+    async fn process_liquidswap_events(&self, events: Vec<ProtocolEvent>) -> ProtocolEventsResult<LiquidPoolVolume> {
+        let mut liquid_volumes: HashMap<String, LiquidPoolVolume> = HashMap::new();
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        let mut implied_prices: Vec<ImpliedPrice> = Vec::new();
+        let mut undiscovered_pools: Vec<NewUndiscoveredPool> = Vec::new();
+
+        for event in events {
+            tracing::info!("🔵 FOUND LIQUIDSWAP EVENT: {}", event.type_str);
+            let extracted = self.extract_cached(&event, || {
+                self.liquid_swap_processor.extract_liquidswap_data(&event.event_data, &event.type_str).map(ProcessedEventResult::LiquidSwap)
+            });
+            match extracted {
+                Ok(ProcessedEventResult::LiquidSwap(swap_data)) => {
+                    if LiquidSwapProcessor::is_flash_loan_swap(&swap_data) {
+                        debug!("⚡ Skipping LiquidSwap flash loan in txn {}", event.txn.version);
+                        continue;
+                    }
+                    if !LiquidSwapProcessor::validate_pair_against_registry(&self.pool_registry, &swap_data.token_x, &swap_data.token_y) {
+                        info!("🔍 Unknown LiquidSwap pair {} / {} in txn {}", swap_data.token_x, swap_data.token_y, event.txn.version);
+                        UNKNOWN_PAIRS_SEEN_TOTAL.with_label_values(&["liquidswap"]).inc();
+                        add_undiscovered_pool(
+                            &mut undiscovered_pools,
+                            Self::undiscovered_pool("liquidswap", &swap_data.token_x, &swap_data.token_y, event.txn.version as i64),
+                        );
+                        continue;
+                    }
+                    tracing::info!("🔄 Processing LiquidSwap swap: {:?}", swap_data);
+                    
+                    let coin_volumes = self.extract_coin_volumes_from_liquidswap(&swap_data);
+                    if let Some(anomaly) = self.check_anomaly("liquidswap", &event, &coin_volumes) {
+                        anomalous_swaps.push(anomaly);
+                        continue;
+                    }
+                    let implied_price = self.implied_price("liquidswap", &event, &coin_volumes, implied_price_from_coin_volumes(&coin_volumes));
+
+                    if !self.within_event_timeout("liquidswap", &event.type_str, self.liquid_swap_processor.process_liquidswap(&mut liquid_volumes, swap_data)).await {
+                        continue;
+                    }
+                    tracing::info!("✅ LiquidSwap swap processed successfully");
+                    implied_prices.extend(implied_price);
+                    self.record_pair_metrics("liquidswap", &coin_volumes);
+                    if !coin_volumes.is_empty() {
+                        swap_events.push(SwapEventData {
+                            timestamp_seconds: event.txn_timestamp,
+                            coin_volumes,
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("❌ Error extracting LiquidSwap data: {}", e);
+                }
+                // Cache keys include the event type, so a cached result is always this protocol's
+                Ok(_) => {}
+            }
+        }
+
+        ProtocolEventsResult {
+            volumes: liquid_volumes,
+            swap_events,
+            anomalous_swaps,
+            implied_prices,
+            undiscovered_pools,
+        }
+    }
+
+    /// Process partitioned Hyperion events into per-pool volumes and bucket swap events
+    async fn process_hyperion_events(&self, events: Vec<ProtocolEvent>) -> ProtocolEventsResult<HyperionPoolVolume> {
+        let mut hyperion_volumes: HashMap<String, HyperionPoolVolume> = HashMap::new();
+        let mut swap_events: Vec<SwapEventData> = Vec::new();
+        let mut anomalous_swaps: Vec<NewAnomalousSwap> = Vec::new();
+        let mut implied_prices: Vec<ImpliedPrice> = Vec::new();
+
+        for event in events {
+            tracing::info!("🟡 FOUND HYPERION EVENT: {}", event.type_str);
+            let extracted = self.extract_cached(&event, || {
+                self.hyperion_processor.extract_swap_data(&event.event_data, &event.type_str).map(ProcessedEventResult::Hyperion)
+            });
+            match extracted {
+                Ok(ProcessedEventResult::Hyperion(swap_data)) => {
+                    tracing::info!("🔄 Processing Hyperion swap: {:?}", swap_data);
+                    
+                    let coin_volumes = self.extract_coin_volumes_from_hyperion(&swap_data);
+                    if let Some(anomaly) = self.check_anomaly("hyperion", &event, &coin_volumes) {
+                        anomalous_swaps.push(anomaly);
+                        continue;
+                    }
+                    let implied_price = self.implied_price("hyperion", &event, &coin_volumes, implied_price_from_coin_volumes(&coin_volumes));
+
+                    // Process all Hyperion swaps (removed target pool filter)
+                    if !self.within_event_timeout("hyperion", &event.type_str, self.hyperion_processor.process_swap(&mut hyperion_volumes, swap_data)).await {
+                        continue;
+                    }
+                    tracing::info!("✅ Hyperion swap processed successfully");
+                    implied_prices.extend(implied_price);
+                    self.record_pair_metrics("hyperion", &coin_volumes);
+                    if !coin_volumes.is_empty() {
+                        swap_events.push(SwapEventData {
+                            timestamp_seconds: event.txn_timestamp,
+                            coin_volumes,
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("❌ Error extracting Hyperion data: {}", e);
+                }
+                // Cache keys include the event type, so a cached result is always this protocol's
+                Ok(_) => {}
+            }
+        }
+
+        ProtocolEventsResult {
+            volumes: hyperion_volumes,
+            swap_events,
+            anomalous_swaps,
+            implied_prices,
+            undiscovered_pools: Vec::new(),
+        }
     }
-}
 
-impl VolumeCalculator {
     /// Extract coin volumes from Cellana swap data for bucket processing
     fn extract_coin_volumes_from_cellana(&self, swap_data: &super::cellana::processor::SwapData) -> Vec<CoinVolumeData> {
         let mut coin_volumes = Vec::new();
@@ -563,7 +1814,7 @@ impl VolumeCalculator {
         ) {
             // Add volume for token X
             if let Some(coin) = self.token_type_to_coin(&swap_data.token_x) {
-                let volume = if amount_x_in > BigDecimal::zero() { amount_x_in } else { amount_x_out };
+                let volume = if is_positive(&amount_x_in) { amount_x_in } else { amount_x_out };
                 coin_volumes.push(CoinVolumeData {
                     coin,
                     volume: self.normalize_token_amount(&swap_data.token_x, &volume),
@@ -572,7 +1823,7 @@ impl VolumeCalculator {
             
             // Add volume for token Y
             if let Some(coin) = self.token_type_to_coin(&swap_data.token_y) {
-                let volume = if amount_y_in > BigDecimal::zero() { amount_y_in } else { amount_y_out };
+                let volume = if is_positive(&amount_y_in) { amount_y_in } else { amount_y_out };
                 coin_volumes.push(CoinVolumeData {
                     coin,
                     volume: self.normalize_token_amount(&swap_data.token_y, &volume),
@@ -596,7 +1847,7 @@ impl VolumeCalculator {
         ) {
             // Add volume for token X
             if let Some(coin) = self.token_type_to_coin(&swap_data.token_x) {
-                let volume = if x_in > BigDecimal::zero() { x_in } else { x_out };
+                let volume = if is_positive(&x_in) { x_in } else { x_out };
                 coin_volumes.push(CoinVolumeData {
                     coin,
                     volume: self.normalize_token_amount(&swap_data.token_x, &volume),
@@ -605,7 +1856,7 @@ impl VolumeCalculator {
             
             // Add volume for token Y
             if let Some(coin) = self.token_type_to_coin(&swap_data.token_y) {
-                let volume = if y_in > BigDecimal::zero() { y_in } else { y_out };
+                let volume = if is_positive(&y_in) { y_in } else { y_out };
                 coin_volumes.push(CoinVolumeData {
                     coin,
                     volume: self.normalize_token_amount(&swap_data.token_y, &volume),
@@ -683,6 +1934,19 @@ impl VolumeCalculator {
                 token_type == super::liquidswap::constants::WHWETH_COIN_TYPE {
             Some("WETH".to_string())
         }
+        // BTC and equivalent tokens
+        else if token_type == super::cellana::constants::ABTC_COIN_TYPE ||
+                token_type == super::liquidswap::constants::IZBTC_COIN_TYPE ||
+                token_type == super::liquidswap::constants::WHBTC_COIN_TYPE {
+            Some("BTC".to_string())
+        }
+        // BNB (Celer-bridged)
+        else if token_type == super::liquidswap::constants::BNB_COIN_TYPE {
+            Some("BNB".to_string())
+        }
+        else if token_type == super::liquidswap::constants::MOVE_COIN_TYPE {
+            Some("MOVE".to_string())
+        }
         else {
             None
         }
@@ -723,6 +1987,15 @@ impl VolumeCalculator {
                 token_type == super::liquidswap::constants::WHWETH_COIN_TYPE {
             // WETH has 6 decimals
             BigDecimal::from(10_u64.pow(6))
+        } else if token_type == super::cellana::constants::ABTC_COIN_TYPE ||
+                token_type == super::liquidswap::constants::IZBTC_COIN_TYPE ||
+                token_type == super::liquidswap::constants::WHBTC_COIN_TYPE {
+            // BTC has 8 decimals
+            BigDecimal::from(10_u64.pow(8))
+        } else if token_type == super::liquidswap::constants::BNB_COIN_TYPE {
+            BigDecimal::from(10_u64.pow(super::liquidswap::constants::BNB_DECIMALS as u32))
+        } else if token_type == super::liquidswap::constants::MOVE_COIN_TYPE {
+            BigDecimal::from(10_u64.pow(super::liquidswap::constants::MOVE_DECIMALS as u32))
         } else {
             // Default to no normalization
             BigDecimal::from(1)
@@ -731,30 +2004,164 @@ impl VolumeCalculator {
         raw_amount / divisor
     }
 
-    /// Calculate 24h coin volume data from swap events
-    fn calculate_24h_coin_volumes(&self, swap_events: &Vec<SwapEventData>) -> Vec<NewCoinVolume24h> {
-        let mut coin_volumes: HashMap<String, BigDecimal> = HashMap::new();
-        
-        // Aggregate volumes by coin
-        for event in swap_events {
-            for coin_volume in &event.coin_volumes {
-                let current_volume = coin_volumes.entry(coin_volume.coin.clone())
-                    .or_insert_with(|| BigDecimal::zero());
-                *current_volume += &coin_volume.volume;
+    /// Sum each coin's buy and sell volumes across every protocol's pools.
+    /// Coins with no volume in either direction are left out.
+    fn coin_aggregates(
+        cellana_volumes: &HashMap<String, CellanaPoolVolume>,
+        thala_volumes: &HashMap<String, ThalaPoolVolume>,
+        sushi_volumes: &HashMap<String, SushiPoolVolume>,
+        liquid_volumes: &HashMap<String, LiquidPoolVolume>,
+        hyperion_volumes: &HashMap<String, HyperionPoolVolume>,
+    ) -> Vec<NewCoinVolume24h> {
+        // coin -> (buy volume, sell volume)
+        let mut coin_aggregates: HashMap<&'static str, (BigDecimal, BigDecimal)> = HashMap::new();
+        let mut add = |coin: &'static str, buy: &BigDecimal, sell: &BigDecimal| {
+            let (buy_total, sell_total) = coin_aggregates
+                .entry(coin)
+                .or_insert_with(|| (BigDecimal::zero(), BigDecimal::zero()));
+            *buy_total += buy;
+            *sell_total += sell;
+        };
+
+        for pool in cellana_volumes.values() {
+            add("APT", &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h);
+            add("USDC", &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h);
+            add("USDT", &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h);
+            add("BTC", &pool.abtc_buy_volume_24h, &pool.abtc_sell_volume_24h);
+        }
+        for pool in thala_volumes.values() {
+            add("APT", &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h);
+            add("USDC", &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h);
+            add("USDT", &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h);
+        }
+        for pool in sushi_volumes.values() {
+            add("APT", &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h);
+            add("USDC", &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h);
+            add("USDT", &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h);
+            add("WETH", &pool.weth_buy_volume_24h, &pool.weth_sell_volume_24h);
+        }
+        for pool in liquid_volumes.values() {
+            add("APT", &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h);
+            add("USDC", &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h);
+            add("USDT", &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h);
+            add("WETH", &pool.weth_buy_volume_24h, &pool.weth_sell_volume_24h);
+            add("BTC", &pool.btc_buy_volume_24h, &pool.btc_sell_volume_24h);
+            add("BNB", &pool.bnb_buy_volume_24h, &pool.bnb_sell_volume_24h);
+            add("MOVE", &pool.move_buy_volume_24h, &pool.move_sell_volume_24h);
+        }
+        for pool in hyperion_volumes.values() {
+            add("APT", &pool.apt_buy_volume_24h, &pool.apt_sell_volume_24h);
+            add("USDC", &pool.usdc_buy_volume_24h, &pool.usdc_sell_volume_24h);
+            add("USDT", &pool.usdt_buy_volume_24h, &pool.usdt_sell_volume_24h);
+        }
+
+        coin_aggregates
+            .into_iter()
+            .filter(|(_, (buy, sell))| !buy.is_zero() || !sell.is_zero())
+            .map(|(coin, (buy, sell))| NewCoinVolume24h {
+                coin: coin.to_string(),
+                buy_volume: Some(buy),
+                sell_volume: Some(sell),
+            })
+            .collect()
+    }
+
+    /// Sum each protocol's pool volumes by pair for `pair_volume_by_protocol`
+    fn pair_volumes(
+        cellana_volumes: &HashMap<String, CellanaPoolVolume>,
+        thala_volumes: &HashMap<String, ThalaPoolVolume>,
+        sushi_volumes: &HashMap<String, SushiPoolVolume>,
+        liquid_volumes: &HashMap<String, LiquidPoolVolume>,
+        hyperion_volumes: &HashMap<String, HyperionPoolVolume>,
+    ) -> Vec<NewPairProtocolVolume> {
+        // (protocol, pair) -> [APT, USDC, USDT, WETH] volume
+        let mut pair_totals: HashMap<(&'static str, String), [BigDecimal; 4]> = HashMap::new();
+        let mut add = |protocol: &'static str, pair: Option<String>, volumes: [&BigDecimal; 4]| {
+            let Some(pair) = pair else {
+                return;
+            };
+            let totals = pair_totals.entry((protocol, pair)).or_default();
+            for (total, volume) in totals.iter_mut().zip(volumes) {
+                *total += volume;
             }
+        };
+
+        let zero = BigDecimal::zero();
+        for pool in cellana_volumes.values() {
+            let volumes = [&pool.apt_volume_24h, &pool.usdc_volume_24h, &pool.usdt_volume_24h, &zero];
+            add("cellana", pool_pair_name(&pool.pool, volumes), volumes);
         }
-        
-        // Convert to NewCoinVolume24h records
-        let mut coin_volume_data = Vec::new();
-        for (coin, volume) in coin_volumes {
-            coin_volume_data.push(NewCoinVolume24h {
-                coin,
-                buy_volume: Some(volume.clone()),
-                sell_volume: Some(volume), // For now, treat all volume as both buy and sell
-            });
+        for pool in thala_volumes.values() {
+            let volumes = [&pool.apt_volume_24h, &pool.usdc_volume_24h, &pool.usdt_volume_24h, &zero];
+            add("thala", pool_pair_name(&pool.pool, volumes), volumes);
         }
-        
-        coin_volume_data
+        for pool in sushi_volumes.values() {
+            add(
+                "sushiswap",
+                Some(pool.pair.clone()),
+                [&pool.apt_volume_24h, &pool.usdc_volume_24h, &pool.usdt_volume_24h, &pool.weth_volume_24h],
+            );
+        }
+        for pool in liquid_volumes.values() {
+            add(
+                "liquidswap",
+                Some(pool.pair.clone()),
+                [&pool.apt_volume_24h, &pool.usdc_volume_24h, &pool.usdt_volume_24h, &pool.weth_volume_24h],
+            );
+        }
+        for pool in hyperion_volumes.values() {
+            let volumes = [&pool.apt_volume_24h, &pool.usdc_volume_24h, &pool.usdt_volume_24h, &zero];
+            add("hyperion", pool_pair_name(&pool.pool, volumes), volumes);
+        }
+
+        pair_totals
+            .into_iter()
+            .filter(|(_, totals)| totals.iter().any(|total| !total.is_zero()))
+            .map(|((protocol, pair), [apt, usdc, usdt, weth])| NewPairProtocolVolume {
+                protocol: protocol.to_string(),
+                pair,
+                apt_volume: Some(apt),
+                usdc_volume: Some(usdc),
+                usdt_volume: Some(usdt),
+                weth_volume: Some(weth),
+            })
+            .collect()
+    }
+}
+
+/// Pair traded by a single swap, from the two coins it moved: APT first and stablecoins last
+/// (e.g. "APT/USDC", "WETH/USDT"). None unless exactly two coins were moved.
+fn swap_pair_name(coin_volumes: &[CoinVolumeData]) -> Option<String> {
+    let rank = |coin: &str| match coin {
+        "APT" => 0,
+        "USDT" => 2,
+        "USDC" => 3,
+        _ => 1,
+    };
+    let [first, second] = coin_volumes else {
+        return None;
+    };
+    let (base, quote) = if rank(&second.coin) < rank(&first.coin) { (second, first) } else { (first, second) };
+    Some(format!("{}/{}", base.coin, quote.coin))
+}
+
+/// Canonical pair name for a pool tracked by address: the registered name, or else the two
+/// coins that carried volume with the base coin first (e.g. "APT/USDC"). `volumes` are
+/// [APT, USDC, USDT, WETH]; None if the pool didn't trade exactly two of them.
+fn pool_pair_name(pool: &str, volumes: [&BigDecimal; 4]) -> Option<String> {
+    if let Some(pair) = PoolRegistry::global().get_pair_name_by_address(pool) {
+        return Some(pair.to_string());
+    }
+
+    let [apt, usdc, usdt, weth] = volumes;
+    let coins: Vec<&str> = [("APT", apt), ("WETH", weth), ("USDT", usdt), ("USDC", usdc)]
+        .into_iter()
+        .filter(|(_, volume)| !volume.is_zero())
+        .map(|(coin, _)| coin)
+        .collect();
+    match coins.as_slice() {
+        [base, quote] => Some(format!("{}/{}", base, quote)),
+        _ => None,
     }
 }
 
@@ -841,4 +2248,260 @@ mod tests {
         
         println!("✅ All token normalization tests passed!");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_compute_usd_volume() {
+        let apt_data = vec![NewAptData {
+            protocol_name: "cellana".to_string(),
+            apt_volume_24h: Some(BigDecimal::from(10)),
+            usdc_volume_24h: Some(BigDecimal::from(50)),
+            apt_fee_24h: Some(BigDecimal::from_str("0.03").unwrap()),
+            usdc_fee_24h: None,
+            usdt_volume_24h: Some(BigDecimal::from(20)),
+            usdt_fee_24h: None,
+            weth_volume_24h: Some(BigDecimal::from(1)),
+            weth_fee_24h: None,
+            btc_volume_24h: None,
+            trade_count: 3,
+            bnb_volume_24h: None,
+            lp_fee_24h: None,
+            protocol_fee_24h: None,
+            move_volume_24h: None,
+        }];
+        let prices = HashMap::from([("APT".to_string(), BigDecimal::from_str("6.5").unwrap())]);
+
+        let usd = VolumeCalculator::compute_usd_volume(&apt_data, &prices);
+
+        // 10 APT * 6.5 + 50 USDC + 20 USDT; WETH has no price and is left out
+        assert_eq!(usd.len(), 1);
+        assert_eq!(usd[0].protocol_name, "cellana");
+        assert_eq!(usd[0].total_usd_volume, BigDecimal::from(135));
+        assert_eq!(usd[0].total_usd_fee, BigDecimal::from_str("0.195").unwrap());
+    }
+
+    #[test]
+    fn test_coin_aggregates_keep_buy_and_sell_separate() {
+        let mut cellana_pool = CellanaPoolVolume::default();
+        cellana_pool.apt_sell_volume_24h = BigDecimal::from(10);
+        cellana_pool.usdc_buy_volume_24h = BigDecimal::from(50);
+        let mut hyperion_pool = HyperionPoolVolume::default();
+        hyperion_pool.apt_buy_volume_24h = BigDecimal::from(4);
+        hyperion_pool.usdc_sell_volume_24h = BigDecimal::from(20);
+
+        let mut aggregates = VolumeCalculator::coin_aggregates(
+            &HashMap::from([("pool_a".to_string(), cellana_pool)]),
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::from([("pool_b".to_string(), hyperion_pool)]),
+        );
+        aggregates.sort_by(|a, b| a.coin.cmp(&b.coin));
+
+        // USDT saw no volume and is left out
+        assert_eq!(aggregates.len(), 2);
+        assert_eq!(aggregates[0].coin, "APT");
+        assert_eq!(aggregates[0].buy_volume, Some(BigDecimal::from(4)));
+        assert_eq!(aggregates[0].sell_volume, Some(BigDecimal::from(10)));
+        assert_eq!(aggregates[1].coin, "USDC");
+        assert_eq!(aggregates[1].buy_volume, Some(BigDecimal::from(50)));
+        assert_eq!(aggregates[1].sell_volume, Some(BigDecimal::from(20)));
+    }
+
+    #[test]
+    fn test_process_genesis_records_dex_modules() {
+        use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{
+            DirectWriteSet, MoveModule, MoveModuleBytecode, MoveStruct, MoveStructField, WriteModule, WriteSet,
+            WriteSetChange,
+        };
+
+        let module = |address: &str, name: &str, event: &str, field: &str| WriteSetChange {
+            change: Some(Change::WriteModule(WriteModule {
+                address: address.to_string(),
+                data: Some(MoveModuleBytecode {
+                    abi: Some(MoveModule {
+                        name: name.to_string(),
+                        structs: vec![MoveStruct {
+                            name: event.to_string(),
+                            fields: vec![MoveStructField { name: field.to_string(), ..Default::default() }],
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let genesis = GenesisTransaction {
+            payload: Some(WriteSet {
+                write_set: Some(write_set::WriteSet::DirectWriteSet(DirectWriteSet {
+                    write_set_change: vec![
+                        module("0xa1", "pool_v3", "SwapEventV3", "from_token"),
+                        module("0xb2", "liquidity_pool", "SwapEvent", "x_in"),
+                        module("0x1", "coin", "CoinInfo", "decimals"),
+                    ],
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut calculator = VolumeCalculator::new();
+        let deployed = calculator.process_genesis(&genesis).unwrap();
+
+        assert_eq!(deployed.len(), 2);
+        assert_eq!(deployed[0].protocol, "hyperion");
+        assert_eq!(deployed[1].protocol, "liquidswap");
+        assert_eq!(deployed[1].module_name, "liquidity_pool");
+        assert!(calculator.is_tracked_swap_event("0xa1::pool_v3::SwapEventV3"));
+    }
+
+    #[test]
+    fn test_primary_signer_ignores_fee_payer() {
+        use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{
+            signature, FeePayerSignature, Signature, UserTransactionRequest,
+        };
+
+        let sender = "0xa11ce";
+        let user_txn = UserTransaction {
+            request: Some(UserTransactionRequest {
+                sender: sender.to_string(),
+                signature: Some(Signature {
+                    signature: Some(signature::Signature::FeePayer(FeePayerSignature {
+                        fee_payer_address: "0xfee".to_string(),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(extract_primary_signer(&user_txn), Some(sender));
+        assert_eq!(extract_primary_signer(&UserTransaction::default()), None);
+    }
+
+    #[test]
+    fn test_pool_pair_name_from_traded_coins() {
+        let (zero, one) = (BigDecimal::zero(), BigDecimal::from(1));
+        assert_eq!(pool_pair_name("0xunregistered", [&one, &one, &zero, &zero]), Some("APT/USDC".to_string()));
+        assert_eq!(pool_pair_name("0xunregistered", [&zero, &one, &one, &zero]), Some("USDT/USDC".to_string()));
+        assert_eq!(pool_pair_name("0xunregistered", [&one, &zero, &zero, &zero]), None);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_event_processing_is_skipped() {
+        let calculator = VolumeCalculator::new().with_event_processing_timeout(std::time::Duration::from_millis(10));
+        assert!(calculator.within_event_timeout("cellana", CELLANA_SWAP_EVENT_TYPE, async {}).await);
+        assert!(!calculator.within_event_timeout("cellana", CELLANA_SWAP_EVENT_TYPE, std::future::pending::<()>()).await);
+        assert_eq!(PROCESSING_TIMEOUTS_TOTAL.with_label_values(&["cellana"]).get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_block_metadata_becomes_a_chain_metric() {
+        use aptos_indexer_processor_sdk::{
+            aptos_protos::{
+                transaction::v1::{transaction::TxnData, BlockMetadataTransaction},
+                util::timestamp::Timestamp,
+            },
+            types::transaction_context::TransactionMetadata,
+        };
+
+        let txn = Transaction {
+            version: 7,
+            epoch: 2,
+            timestamp: Some(Timestamp { seconds: Utc::now().timestamp(), nanos: 0 }),
+            txn_data: Some(TxnData::BlockMetadata(BlockMetadataTransaction {
+                round: 3,
+                proposer: "0x1".to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let volume_data = VolumeCalculator::new()
+            .process(TransactionContext { data: vec![txn], metadata: TransactionMetadata::default() })
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+
+        assert_eq!(volume_data.chain_metrics.len(), 1);
+        assert_eq!(volume_data.chain_metrics[0].round, 3);
+        assert!(volume_data.apt_data.is_empty());
+    }
+
+    #[test]
+    fn test_pair_metrics_use_latest_cached_price() {
+        let coin = |coin: &str, volume: i64| CoinVolumeData { coin: coin.to_string(), volume: BigDecimal::from(volume) };
+        let price = |coin: &str, price_usd: i64, transaction_version: i64| NewCoinPriceUsd {
+            coin: coin.to_string(),
+            quote_coin: "USDC".to_string(),
+            price_usd: BigDecimal::from(price_usd),
+            source_protocol: "cellana".to_string(),
+            transaction_version,
+            transaction_timestamp: DateTime::from_timestamp(1_750_000_000, 0).unwrap().naive_utc(),
+        };
+        assert_eq!(swap_pair_name(&[coin("USDC", 1), coin("APT", 1)]), Some("APT/USDC".to_string()));
+        assert_eq!(swap_pair_name(&[coin("BTC", 1), coin("APT", 1)]), Some("APT/BTC".to_string()));
+        assert_eq!(swap_pair_name(&[coin("APT", 1)]), None);
+
+        let calculator = VolumeCalculator::new();
+        // Unique protocol label, since the metrics are global
+        let labels = ["pair_metrics_test", "APT/BTC"];
+        calculator.record_pair_metrics(labels[0], &[coin("APT", 10), coin("BTC", 1)]);
+        assert_eq!(SWAP_COUNT_BY_PAIR.with_label_values(&labels).get(), 1);
+        assert_eq!(SWAP_VOLUME_BY_PAIR_USD.with_label_values(&labels).get(), 0.0);
+
+        calculator.update_coin_prices(&[price("APT", 6, 2), price("APT", 5, 1)]);
+        calculator.record_pair_metrics(labels[0], &[coin("APT", 10), coin("BTC", 1)]);
+        assert_eq!(SWAP_COUNT_BY_PAIR.with_label_values(&labels).get(), 2);
+        assert_eq!(SWAP_VOLUME_BY_PAIR_USD.with_label_values(&labels).get(), 60.0);
+
+        // A stablecoin side is its own USD value
+        calculator.record_pair_metrics(labels[0], &[coin("USDT", 7), coin("APT", 1)]);
+        assert_eq!(SWAP_VOLUME_BY_PAIR_USD.with_label_values(&["pair_metrics_test", "APT/USDT"]).get(), 7.0);
+    }
+
+    #[test]
+    fn test_merge_sums_matching_entries() {
+        let batch = |apt_volume: i64, trade_count: i64, buy_volume: i64, bucket_volume: i64| VolumeData {
+            apt_data: vec![NewAptDataBuilder::new()
+                .protocol("cellana")
+                .apt_volume(BigDecimal::from(apt_volume))
+                .trade_count(trade_count)
+                .build()],
+            coin_volume_data: vec![NewCoinVolume24h {
+                coin: "APT".to_string(),
+                buy_volume: Some(BigDecimal::from(buy_volume)),
+                sell_volume: None,
+            }],
+            coin_volume_buckets: vec![NewCoinVolumeBucket {
+                coin: "APT".to_string(),
+                bucket_start: DateTime::from_timestamp(1_750_000_000, 0).unwrap().naive_utc(),
+                bucket_end: DateTime::from_timestamp(1_750_007_200, 0).unwrap().naive_utc(),
+                volume: Some(BigDecimal::from(bucket_volume)),
+                protocol: "cellana".to_string(),
+            }],
+            ..VolumeData::default()
+        };
+
+        let mut other = batch(5, 2, 3, 4);
+        other.apt_data.push(NewAptDataBuilder::new().protocol("thala").usdc_volume(BigDecimal::from(7)).trade_count(1).build());
+        let merged = batch(10, 1, 1, 6).merge(other);
+
+        assert_eq!(merged.apt_data.len(), 2);
+        assert_eq!(merged.apt_data[0].apt_volume_24h, Some(BigDecimal::from(15)));
+        assert_eq!(merged.apt_data[0].usdc_volume_24h, None);
+        assert_eq!(merged.apt_data[0].trade_count, 3);
+        assert_eq!(merged.apt_data[1].protocol_name, "thala");
+        assert_eq!(merged.coin_volume_data.len(), 1);
+        assert_eq!(merged.coin_volume_data[0].buy_volume, Some(BigDecimal::from(4)));
+        assert_eq!(merged.coin_volume_data[0].sell_volume, None);
+        assert_eq!(merged.coin_volume_buckets.len(), 1);
+        assert_eq!(merged.coin_volume_buckets[0].volume, Some(BigDecimal::from(10)));
+    }
+}
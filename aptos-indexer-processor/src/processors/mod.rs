@@ -27,6 +27,9 @@
 //!   - LiquidSwap: Native Aptos DEX with multiple pool types
 //!   - Hyperion: V3 protocol with range orders
 //! 
+//! ### `protocol_health_monitor`
+//! Background check that warns when a protocol's swap events stop arriving.
+//! 
 //! ## Data Flow
 //! 
 //! ```text
@@ -43,3 +46,6 @@ pub mod tasmil_processor;
 
 /// Event processing modules for swap data extraction and volume calculation
 pub mod events;
+
+/// Alerts for protocols that have gone quiet
+pub mod protocol_health_monitor;
@@ -0,0 +1,103 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::utils::metrics::PROTOCOL_SILENT_DURATION_SECONDS;
+
+/// A protocol with no swap events for this long is reported as silent
+pub const PROTOCOL_SILENCE_THRESHOLD: Duration = Duration::from_secs(600);
+/// How often the background task checks for silent protocols
+pub const PROTOCOL_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Monitor shared by the volume calculator, which records events, and the background check
+pub type SharedProtocolHealthMonitor = Arc<Mutex<ProtocolHealthMonitor>>;
+
+/// Tracks when each protocol last had a swap processed, to catch event streams going quiet
+#[derive(Debug, Default)]
+pub struct ProtocolHealthMonitor {
+    last_event_timestamps: HashMap<String, Instant>,
+    // Protocols already warned about, so each silence is logged once
+    reported_silent: HashSet<String>,
+}
+
+impl ProtocolHealthMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `protocol` processed at least one swap in the current batch
+    pub fn record_events(&mut self, protocol: &str) {
+        self.last_event_timestamps.insert(protocol.to_string(), Instant::now());
+        if self.reported_silent.remove(protocol) {
+            info!("✅ {} swap events resumed", protocol);
+            PROTOCOL_SILENT_DURATION_SECONDS.with_label_values(&[protocol]).set(0);
+        }
+    }
+
+    /// Protocols without events for longer than `threshold` as of `now`, with how long they
+    /// have been silent. Sets `protocol_silent_duration_seconds` for every tracked protocol
+    /// (0 while under the threshold) and warns when a protocol first crosses it.
+    pub fn check(&mut self, now: Instant, threshold: Duration) -> Vec<(String, Duration)> {
+        let mut silent = Vec::new();
+        for (protocol, last_event) in &self.last_event_timestamps {
+            let elapsed = now.saturating_duration_since(*last_event);
+            if elapsed <= threshold {
+                PROTOCOL_SILENT_DURATION_SECONDS.with_label_values(&[protocol]).set(0);
+                continue;
+            }
+            PROTOCOL_SILENT_DURATION_SECONDS.with_label_values(&[protocol]).set(elapsed.as_secs() as i64);
+            if self.reported_silent.insert(protocol.clone()) {
+                warn!("🔇 No {} swap events for {}s, node connection or protocol may be down", protocol, elapsed.as_secs());
+            }
+            silent.push((protocol.clone(), elapsed));
+        }
+        silent
+    }
+}
+
+/// Check `monitor` every `PROTOCOL_HEALTH_CHECK_INTERVAL` for protocols silent longer than
+/// `PROTOCOL_SILENCE_THRESHOLD`
+pub fn spawn_protocol_health_monitor(monitor: SharedProtocolHealthMonitor) -> JoinHandle<()> {
+    info!(
+        "🩺 Watching protocol event streams, alerting after {}s without swaps",
+        PROTOCOL_SILENCE_THRESHOLD.as_secs()
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PROTOCOL_HEALTH_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let mut monitor = monitor.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            monitor.check(Instant::now(), PROTOCOL_SILENCE_THRESHOLD);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_silent_past_threshold_until_next_event() {
+        let mut monitor = ProtocolHealthMonitor::new();
+        monitor.record_events("cellana");
+        monitor.record_events("thala");
+        let now = Instant::now();
+
+        assert!(monitor.check(now, PROTOCOL_SILENCE_THRESHOLD).is_empty());
+
+        let later = now + PROTOCOL_SILENCE_THRESHOLD + Duration::from_secs(60);
+        monitor.record_events("thala");
+        let silent = monitor.check(later, PROTOCOL_SILENCE_THRESHOLD);
+        assert_eq!(silent.len(), 1);
+        assert_eq!(silent[0].0, "cellana");
+        assert!(silent[0].1 > PROTOCOL_SILENCE_THRESHOLD);
+
+        monitor.record_events("cellana");
+        assert!(monitor.check(Instant::now(), PROTOCOL_SILENCE_THRESHOLD).is_empty());
+    }
+}
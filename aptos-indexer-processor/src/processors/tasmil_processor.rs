@@ -1,256 +1,930 @@
 use anyhow::Result;
 use aptos_indexer_processor_sdk::{
-    aptos_protos::transaction::v1::Transaction,
+    aptos_protos::transaction::v1::{transaction::TxnData, Transaction},
     traits::{async_step::AsyncStep, NamedStep, processable::Processable, AsyncRunType},
-    types::transaction_context::TransactionContext,
+    types::transaction_context::{TransactionContext, TransactionMetadata},
     utils::errors::ProcessorError,
 };
 use async_trait::async_trait;
 use bigdecimal::{BigDecimal, Zero};
 use chrono::{Utc, Duration, DateTime, NaiveDateTime};
-use diesel::{ExpressionMethods, QueryDsl, upsert::excluded, OptionalExtension};
-use diesel_async::RunQueryDsl;
-use std::sync::mpsc;
+use diesel::{ExpressionMethods, NullableExpressionMethods, PgSortExpressionMethods, QueryDsl, upsert::excluded, OptionalExtension};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+use opentelemetry::trace::TraceContextExt;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{mpsc, Arc, Mutex},
+    time::Instant,
+};
 use tracing::{error, info, warn, debug};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 use crate::{
     db::{
         common::models::{
-            apt_models::{AptData, NewAptData},
-            coin_volume_models::{NewCoinVolume24h, CoinVolume24h, NewCoinVolumeBucket, CoinVolumeBucket},
+            apt_models::{AptData, NewAptData, NewAptDataUsd},
+            apt_data_hourly_snapshot_models::HourlySnapshot,
+            batch_statistic_models::{BatchStatistic, NewBatchStatistic},
+            anomalous_swap_models::NewAnomalousSwap,
+            chain_metrics_models::ChainMetric,
+            coin_price_models::NewCoinPriceUsd,
+            deployed_contract_models::{DeployedContract, NewDeployedContract},
+            hyperion_fee_growth_models::NewHyperionPoolFeeGrowth,
+            hyperion_pool_models::{HyperionPool, NewHyperionPool},
+            cellana_pool_models::{CellanaPool, NewCellanaPool},
+            pair_volume_models::{NewPairProtocolVolume, PairProtocolVolume},
+            pool_reserve_models::NewPoolReserve,
+            protocol_fee_models::{NewProtocolFee, ProtocolFee},
+            undiscovered_pool_models::NewUndiscoveredPool,
+            vwap_bucket_models::NewVwapBucket,
+            coin_volume_models::{NewCoinVolume24h, CoinVolume24h, NewCoinVolumeBucket, CoinVolumeBucket, NewCoinVolumeDailyBucket, ALL_PROTOCOLS},
         },
-        postgres::schema::{apt_data, coin_volume_24h, coin_volume_buckets},
+        postgres::schema::{anomalous_swaps, apt_data, apt_data_hourly_snapshots, apt_data_usd, batch_statistics, cellana_pools, chain_metrics, coin_price_usd, coin_volume_24h, coin_volume_buckets, coin_volume_daily_buckets, deployed_contracts, hyperion_pool_fee_growth, hyperion_pools, pair_volume_by_protocol, pool_reserves, protocol_fees_24h, undiscovered_pools, vwap_buckets},
     },
     processors::events::{
-        volume_calculator::VolumeCalculator,
+        bucket_calculator::{daily_bucket_date, validate_bucket, DAILY_BUCKET_RETENTION_DAYS},
+        cellana::processor::PoolMigration,
+        volume_calculator::{VolumeCalculator, VolumeData},
     },
     utils::{
-        database::ArcDbPool,
+        bloom_dedup::{BloomDeduplicator, EventKey},
+        database::{ArcDbPool, MultiPoolConfig, MyDbConnection},
+        errors::{ProcessorResultExt, TransactionError},
+        metrics::{DUPLICATE_EVENTS_SKIPPED, ROWS_PURGED_TOTAL},
+        protocol_config_loader::ProtocolConfigLoader,
+        volume_window::VolumeWindowManager,
     },
 };
 
+// Rows stored before the column was filled in can hold a NULL volume
+diesel::sql_function! {
+    fn coalesce(
+        value: diesel::sql_types::Nullable<diesel::sql_types::Numeric>,
+        fallback: diesel::sql_types::Nullable<diesel::sql_types::Numeric>
+    ) -> diesel::sql_types::Nullable<diesel::sql_types::Numeric>;
+}
+
+/// Metric used to rank protocols in `get_protocol_ranking`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeMetric {
+    AptVolume,
+    UsdcVolume,
+    UsdtVolume,
+    WethVolume,
+    /// 24h fees of all coins in USD, from `apt_data_usd`
+    TotalFees,
+}
+
+/// A protocol's position in a ranking by 24h volume or fees (rank starts at 1)
+#[derive(Clone, Debug)]
+pub struct ProtocolRanking {
+    pub protocol_name: String,
+    pub value: BigDecimal,
+    pub rank: i64,
+}
+
+/// Volume of a coin over `[timestamp, timestamp + granularity)` (GMT+7, like the buckets)
+#[derive(Clone, Debug)]
+pub struct VolumePoint {
+    pub timestamp: NaiveDateTime,
+    pub volume: BigDecimal,
+}
+
+/// A point of the last-24h series returned by `get_volume_time_series_by_coin`
+pub type VolumeTimeSeries = VolumePoint;
+
+/// A protocol's rolling `apt_data` totals before a batch is added (missing values are zero)
+#[derive(Clone, Debug, Default)]
+struct ProtocolVolumeState {
+    apt_volume: BigDecimal,
+    usdc_volume: BigDecimal,
+    usdt_volume: BigDecimal,
+    weth_volume: BigDecimal,
+    btc_volume: BigDecimal,
+    bnb_volume: BigDecimal,
+    move_volume: BigDecimal,
+    apt_fee: BigDecimal,
+    usdc_fee: BigDecimal,
+    usdt_fee: BigDecimal,
+    weth_fee: BigDecimal,
+    lp_fee: BigDecimal,
+    protocol_fee: BigDecimal,
+}
+
+impl From<AptData> for ProtocolVolumeState {
+    fn from(data: AptData) -> Self {
+        Self {
+            apt_volume: data.apt_volume_24h.unwrap_or_default(),
+            usdc_volume: data.usdc_volume_24h.unwrap_or_default(),
+            usdt_volume: data.usdt_volume_24h.unwrap_or_default(),
+            weth_volume: data.weth_volume_24h.unwrap_or_default(),
+            btc_volume: data.btc_volume_24h.unwrap_or_default(),
+            bnb_volume: data.bnb_volume_24h.unwrap_or_default(),
+            move_volume: data.move_volume_24h.unwrap_or_default(),
+            apt_fee: data.apt_fee_24h.unwrap_or_default(),
+            usdc_fee: data.usdc_fee_24h.unwrap_or_default(),
+            usdt_fee: data.usdt_fee_24h.unwrap_or_default(),
+            weth_fee: data.weth_fee_24h.unwrap_or_default(),
+            lp_fee: data.lp_fee_24h.unwrap_or_default(),
+            protocol_fee: data.protocol_fee_24h.unwrap_or_default(),
+        }
+    }
+}
+
+/// Longest range accepted by `get_volume_time_series`
+const MAX_TIME_SERIES_RANGE_DAYS: i64 = 30;
+
+/// Interval bounds accepted by `get_volume_time_series_by_coin`
+pub const MIN_TIME_SERIES_INTERVAL_MINUTES: u32 = 5;
+pub const MAX_TIME_SERIES_INTERVAL_MINUTES: u32 = 1440;
+/// Most points `get_volume_time_series_by_coin` returns (24h at the minimum interval)
+pub const MAX_TIME_SERIES_POINTS: usize = 288;
+
+/// Transactions processed at once; larger batches are split to bound memory use
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 5000;
+
+/// Hour of day (UTC) at which historical data is purged, chosen for low traffic
+const PURGE_HOUR_UTC: u32 = 2;
+
+/// Time until the next daily purge at `PURGE_HOUR_UTC`
+fn duration_until_next_purge(now: DateTime<Utc>) -> std::time::Duration {
+    let today_purge = now.date_naive().and_hms_opt(PURGE_HOUR_UTC, 0, 0).unwrap().and_utc();
+    let next_purge = if today_purge > now { today_purge } else { today_purge + Duration::days(1) };
+    (next_purge - now).to_std().unwrap_or_default()
+}
+
+/// Delete per-swap history (implied prices, anomalous swaps, Hyperion fee growth deltas) older than
+/// `retention_days`, all or nothing. Returns the total number of deleted rows.
+async fn purge_rows_older_than(connection_pool: &ArcDbPool, retention_days: u32) -> Result<u64, ProcessorError> {
+    let mut conn = connection_pool.get().await.processor_err("Failed to get database connection for purge")?;
+    let cutoff = (Utc::now() - Duration::days(retention_days as i64)).naive_utc();
+
+    let (prices_deleted, anomalies_deleted, fee_growth_deleted) = conn.transaction::<_, TransactionError, _>(|conn| {
+        async move {
+            let prices_deleted = diesel::delete(coin_price_usd::table.filter(coin_price_usd::transaction_timestamp.lt(cutoff)))
+                .execute(conn)
+                .await
+                .processor_err("Failed to purge coin_price_usd")?;
+
+            let anomalies_deleted = diesel::delete(anomalous_swaps::table.filter(anomalous_swaps::transaction_timestamp.lt(cutoff)))
+                .execute(conn)
+                .await
+                .processor_err("Failed to purge anomalous_swaps")?;
+
+            // No transaction timestamp is stored for fee growth, so age is measured from insertion
+            let fee_growth_deleted = diesel::delete(hyperion_pool_fee_growth::table.filter(hyperion_pool_fee_growth::inserted_at.lt(cutoff)))
+                .execute(conn)
+                .await
+                .processor_err("Failed to purge hyperion_pool_fee_growth")?;
+
+            Ok((prices_deleted, anomalies_deleted, fee_growth_deleted))
+        }
+        .scope_boxed()
+    })
+    .await
+    .processor_err("Purge transaction failed")?;
+
+    // Counted only once the deletes are committed
+    ROWS_PURGED_TOTAL.with_label_values(&["coin_price_usd"]).inc_by(prices_deleted as u64);
+    ROWS_PURGED_TOTAL.with_label_values(&["anomalous_swaps"]).inc_by(anomalies_deleted as u64);
+    ROWS_PURGED_TOTAL.with_label_values(&["hyperion_pool_fee_growth"]).inc_by(fee_growth_deleted as u64);
+
+    info!("🧹 Purged rows older than {} days (before {}): coin_price_usd={}, anomalous_swaps={}, hyperion_pool_fee_growth={}",
+        retention_days, cutoff, prices_deleted, anomalies_deleted, fee_growth_deleted);
+
+    Ok((prices_deleted + anomalies_deleted + fee_growth_deleted) as u64)
+}
+
+/// Hourly `apt_data` snapshots kept per protocol (7 days)
+pub const MAX_HOURLY_SNAPSHOTS_PER_PROTOCOL: i64 = 168;
+
+/// Start of the UTC hour containing `now`
+fn hour_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    DateTime::from_timestamp(now.timestamp() - now.timestamp().rem_euclid(3600), 0).unwrap()
+}
+
+/// Time until the top of the next UTC hour
+fn duration_until_next_hour(now: DateTime<Utc>) -> std::time::Duration {
+    (hour_start(now) + Duration::hours(1) - now).to_std().unwrap_or_default()
+}
+
+/// Copy every `apt_data` row into `apt_data_hourly_snapshots` for `snapshot_hour`, then drop
+/// snapshots beyond the newest `MAX_HOURLY_SNAPSHOTS_PER_PROTOCOL` of each protocol.
+/// Returns the number of snapshots inserted.
+async fn snapshot_apt_data(connection_pool: &ArcDbPool, snapshot_hour: DateTime<Utc>) -> Result<usize, ProcessorError> {
+    let mut conn = connection_pool.get().await.processor_err("Failed to get database connection for hourly snapshot")?;
+
+    let inserted = diesel::sql_query(
+        "INSERT INTO apt_data_hourly_snapshots (protocol, snapshot_hour, apt_volume, usdc_volume, usdt_volume, weth_volume, \
+         btc_volume, bnb_volume, move_volume, apt_fee, usdc_fee, usdt_fee, weth_fee, lp_fee, protocol_fee, trade_count) \
+         SELECT protocol_name, $1, apt_volume_24h, usdc_volume_24h, usdt_volume_24h, weth_volume_24h, \
+         btc_volume_24h, bnb_volume_24h, move_volume_24h, apt_fee_24h, usdc_fee_24h, usdt_fee_24h, weth_fee_24h, lp_fee_24h, protocol_fee_24h, trade_count \
+         FROM apt_data ON CONFLICT (protocol, snapshot_hour) DO NOTHING",
+    )
+    .bind::<diesel::sql_types::Timestamptz, _>(snapshot_hour)
+    .execute(&mut conn)
+    .await
+    .processor_err("Failed to insert hourly apt_data snapshots")?;
+
+    let pruned = diesel::sql_query(
+        "DELETE FROM apt_data_hourly_snapshots WHERE id IN (\
+         SELECT id FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY protocol ORDER BY snapshot_hour DESC) AS age \
+         FROM apt_data_hourly_snapshots) ranked WHERE age > $1)",
+    )
+    .bind::<diesel::sql_types::BigInt, _>(MAX_HOURLY_SNAPSHOTS_PER_PROTOCOL)
+    .execute(&mut conn)
+    .await
+    .processor_err("Failed to prune hourly apt_data snapshots")?;
+    ROWS_PURGED_TOTAL.with_label_values(&["apt_data_hourly_snapshots"]).inc_by(pruned as u64);
+
+    Ok(inserted)
+}
+
+/// Spread bucket volumes over fixed-size intervals covering `[from, to)`.
+/// Each bucket contributes in proportion to its overlap with an interval, so intervals
+/// finer than a bucket get a uniform share and coarser intervals sum whole buckets.
+fn interpolate_buckets(
+    buckets: &[CoinVolumeBucket],
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    granularity: Duration,
+) -> Vec<VolumePoint> {
+    // Align the first point to a granularity boundary counted from midnight
+    let midnight = from.date().and_hms_opt(0, 0, 0).unwrap();
+    let intervals_since_midnight = (from - midnight).num_seconds() / granularity.num_seconds();
+    let mut timestamp = midnight + granularity * intervals_since_midnight as i32;
+
+    let mut points = Vec::new();
+    while timestamp < to {
+        let interval_end = timestamp + granularity;
+        let volume = overlap_volume(buckets, timestamp, interval_end);
+        points.push(VolumePoint { timestamp, volume });
+        timestamp = interval_end;
+    }
+
+    points
+}
+
+/// The last `MAX_TIME_SERIES_POINTS` of `points`
+fn last_time_series_points(mut points: Vec<VolumePoint>) -> Vec<VolumePoint> {
+    if points.len() > MAX_TIME_SERIES_POINTS {
+        points.drain(..points.len() - MAX_TIME_SERIES_POINTS);
+    }
+    points
+}
+
+fn validate_time_series_interval(interval_minutes: u32) -> Result<(), ProcessorError> {
+    if !(MIN_TIME_SERIES_INTERVAL_MINUTES..=MAX_TIME_SERIES_INTERVAL_MINUTES).contains(&interval_minutes) {
+        return Err(ProcessorError::ProcessError {
+            message: format!(
+                "interval_minutes must be between {} and {} (got {})",
+                MIN_TIME_SERIES_INTERVAL_MINUTES, MAX_TIME_SERIES_INTERVAL_MINUTES, interval_minutes
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Volume falling in `[start, end)`, taking each bucket in proportion to its overlap
+fn overlap_volume(buckets: &[CoinVolumeBucket], start: NaiveDateTime, end: NaiveDateTime) -> BigDecimal {
+    let mut volume = BigDecimal::zero();
+
+    for bucket in buckets {
+        let overlap_start = bucket.bucket_start.max(start);
+        let overlap_end = bucket.bucket_end.min(end);
+        let bucket_seconds = (bucket.bucket_end - bucket.bucket_start).num_seconds();
+        if overlap_end <= overlap_start || bucket_seconds <= 0 {
+            continue;
+        }
+
+        let overlap_seconds = (overlap_end - overlap_start).num_seconds();
+        let bucket_volume = bucket.volume.clone().unwrap_or_else(BigDecimal::zero);
+        volume += bucket_volume * BigDecimal::from(overlap_seconds) / BigDecimal::from(bucket_seconds);
+    }
+
+    volume
+}
+
+/// Percentage change from `previous` to `current`, or None when there is no previous volume
+fn change_rate_pct(previous: &BigDecimal, current: &BigDecimal) -> Option<BigDecimal> {
+    if previous.is_zero() {
+        return None;
+    }
+    Some(((current - previous) * BigDecimal::from(100) / previous).round(2))
+}
+
+/// Percentage change in a coin's volume between the last two complete `window_minutes`
+/// windows before `now` (windows are aligned to the Unix epoch). None if the earlier
+/// window has no volume.
+pub async fn load_volume_change_rate(
+    pool: &ArcDbPool,
+    coin: &str,
+    protocol: &str,
+    window_minutes: u32,
+    now: NaiveDateTime,
+) -> Result<Option<BigDecimal>, ProcessorError> {
+    if window_minutes == 0 {
+        return Err(ProcessorError::ProcessError {
+            message: "window_minutes must be greater than zero".to_string(),
+        });
+    }
+
+    let window_seconds = window_minutes as i64 * 60;
+    let end_ts = now.and_utc().timestamp().div_euclid(window_seconds) * window_seconds;
+    let end = DateTime::from_timestamp(end_ts, 0).unwrap().naive_utc();
+    let window = Duration::minutes(window_minutes as i64);
+    let (previous_start, current_start) = (end - window * 2, end - window);
+
+    let mut conn = pool.get().await.processor_err("Failed to get database connection")?;
+
+    let buckets = coin_volume_buckets::table
+        .filter(coin_volume_buckets::coin.eq(coin))
+        .filter(coin_volume_buckets::protocol.eq(protocol))
+        .filter(coin_volume_buckets::bucket_end.gt(previous_start))
+        .filter(coin_volume_buckets::bucket_start.lt(end))
+        .load::<CoinVolumeBucket>(&mut conn)
+        .await
+        .processor_err(&format!("Failed to query coin volume buckets for {}", coin))?;
+
+    let previous = overlap_volume(&buckets, previous_start, current_start);
+    let current = overlap_volume(&buckets, current_start, end);
+    Ok(change_rate_pct(&previous, &current))
+}
+
+/// `record` with a missing volume stored as zero, so the upsert's
+/// `COALESCE(volume, 0) + excluded.volume` never adds NULL. A batch's buckets only hold that batch's events, so adding them to the
+/// stored volume counts each event once.
+fn with_batch_volume(record: &NewCoinVolumeBucket) -> NewCoinVolumeBucket {
+    NewCoinVolumeBucket {
+        volume: Some(record.volume.clone().unwrap_or_default()),
+        ..record.clone()
+    }
+}
+
+/// Days of `batch_statistics` rows kept
+pub const BATCH_STATISTICS_RETENTION_DAYS: i64 = 7;
+
+/// Swap events counted and time spent writing them in one `process_transactions` call,
+/// or summed over a batch's chunks
+#[derive(Default)]
+struct ChunkStats {
+    swap_event_count: i64,
+    db_write_time: std::time::Duration,
+    // Swap events to remember as counted once the batch's transaction commits
+    new_event_keys: Vec<EventKey>,
+    // `batch_statistics` row written with the batch's volume upserts
+    batch_statistic_id: Option<i64>,
+}
+
 pub struct TasmilProcessor {
-    connection_pool: ArcDbPool,
+    pools: MultiPoolConfig,
     volume_calculator: VolumeCalculator,
     sender: mpsc::Sender<String>,
+    // Swap events already counted, shared so it can be saved on shutdown
+    deduplicator: Arc<Mutex<BloomDeduplicator>>,
+    dedup_state_path: Option<PathBuf>,
+    // Upsert each protocol's apt_data as soon as it is computed instead of after the batch
+    streaming: bool,
+    // 24h window volumes accumulate over; loaded from the database on the first batch
+    volume_window: Option<VolumeWindowManager>,
+    // Batches with more transactions are processed in chunks of this size
+    max_batch_size: usize,
+    // Compute volumes but log them instead of writing to the database
+    dry_run: bool,
+    // Whether hyperion_pools has been loaded into the VolumeCalculator
+    hyperion_pools_loaded: bool,
+    // Whether cellana_pools has been loaded into the VolumeCalculator
+    cellana_pools_loaded: bool,
+    // Whether deployed_contracts has been loaded into the VolumeCalculator
+    deployed_contracts_loaded: bool,
 }
 
 impl TasmilProcessor {
-    pub fn new(connection_pool: ArcDbPool, sender: mpsc::Sender<String>) -> Self {
+    pub fn new(
+        pools: MultiPoolConfig,
+        sender: mpsc::Sender<String>,
+        volume_calculator: VolumeCalculator,
+    ) -> Self {
         info!("🚀 Creating TasmilProcessor with Rolling 24h Volume Logic");
         
-        let processor = Self {
-            connection_pool: connection_pool.clone(),
-            volume_calculator: VolumeCalculator::new(),
+        Self {
+            pools,
+            volume_calculator,
             sender,
-        };
+            deduplicator: Arc::new(Mutex::new(BloomDeduplicator::default())),
+            dedup_state_path: None,
+            streaming: false,
+            volume_window: None,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            dry_run: false,
+            hyperion_pools_loaded: false,
+            cellana_pools_loaded: false,
+            deployed_contracts_loaded: false,
+        }
+    }
 
-        // Reset volume on startup for fresh calculation
-        let pool = connection_pool.clone();
-        tokio::spawn(async move {
-            if let Ok(mut conn) = pool.get().await {
-                info!("🔄 Resetting volume to 0 on startup for fresh 24h calculation...");
-                
-                match diesel::update(apt_data::table)
-                    .set((
-                        apt_data::apt_volume_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::usdc_volume_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::usdt_volume_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::weth_volume_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::apt_fee_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::usdc_fee_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::usdt_fee_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::weth_fee_24h.eq(Some(BigDecimal::zero())),
-                        apt_data::inserted_at.eq(diesel::dsl::now)
-                    ))
-                    .execute(&mut conn)
-                    .await
-                {
-                    Ok(updated_count) => {
-                        info!("✅ Reset {} pool volumes to 0 (including 'aptos' aggregated data)", updated_count);
-                    },
-                    Err(e) => {
-                        error!("❌ Failed to reset volumes: {}", e);
-                    }
-                }
+    /// Reset the 24h volumes, fees and buckets for a fresh calculation on the first startup.
+    /// Once a volume window is stored, a restart keeps what that window has accumulated and the
+    /// volumes are only reset when the window rolls forward. Must finish before the first batch.
+    pub async fn reset_volumes_on_startup(&self) -> Result<(), ProcessorError> {
+        if let Some(window) = VolumeWindowManager::load(&self.pools.write_pool).await? {
+            info!("🪟 Keeping volumes accumulated since {} UTC, the stored window start",
+                window.window_start().format("%Y-%m-%d %H:%M:%S"));
+            return Ok(());
+        }
 
-                // Also reset coin volumes
-                match diesel::update(coin_volume_24h::table)
-                    .set((
-                        coin_volume_24h::buy_volume.eq(Some(BigDecimal::zero())),
-                        coin_volume_24h::sell_volume.eq(Some(BigDecimal::zero())),
-                        coin_volume_24h::inserted_at.eq(diesel::dsl::now)
-                    ))
-                    .execute(&mut conn)
-                    .await
-                {
-                    Ok(updated_count) => {
-                        info!("✅ Reset {} coin volumes to 0", updated_count);
-                    },
-                    Err(e) => {
-                        error!("❌ Failed to reset coin volumes: {}", e);
-                    }
-                }
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for startup reset")?;
+        info!("🔄 Resetting volume to 0 on startup for fresh 24h calculation...");
+        
+        match diesel::update(apt_data::table)
+            .set((
+                apt_data::apt_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::usdc_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::usdt_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::weth_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::apt_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::usdc_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::usdt_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::weth_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::btc_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::bnb_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::move_volume_24h.eq(Some(BigDecimal::zero())),
+                apt_data::lp_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::protocol_fee_24h.eq(Some(BigDecimal::zero())),
+                apt_data::trade_count.eq(0),
+                apt_data::inserted_at.eq(diesel::dsl::now)
+            ))
+            .execute(&mut conn)
+            .await
+        {
+            Ok(updated_count) => {
+                info!("✅ Reset {} pool volumes to 0 (including 'aptos' aggregated data)", updated_count);
+            },
+            Err(e) => {
+                error!("❌ Failed to reset volumes: {}", e);
+            }
+        }
 
-                // Reset coin volume buckets on startup
-                match diesel::delete(coin_volume_buckets::table)
-                    .execute(&mut conn)
-                    .await
-                {
-                    Ok(deleted_count) => {
-                        info!("✅ Deleted {} coin volume bucket records on startup for fresh calculation", deleted_count);
-                    },
-                    Err(e) => {
-                        error!("❌ Failed to reset coin volume buckets on startup: {}", e);
+        // Fees accumulate separately, so reset them with the volumes
+        match diesel::update(protocol_fees_24h::table)
+            .set((
+                protocol_fees_24h::apt_fee.eq(Some(BigDecimal::zero())),
+                protocol_fees_24h::usdc_fee.eq(Some(BigDecimal::zero())),
+                protocol_fees_24h::usdt_fee.eq(Some(BigDecimal::zero())),
+                protocol_fees_24h::weth_fee.eq(Some(BigDecimal::zero())),
+                protocol_fees_24h::updated_at.eq(Utc::now()),
+            ))
+            .execute(&mut conn)
+            .await
+        {
+            Ok(updated_count) => {
+                info!("✅ Reset {} protocol fee records to 0", updated_count);
+            },
+            Err(e) => {
+                error!("❌ Failed to reset protocol fees: {}", e);
+            }
+        }
+
+        match diesel::update(pair_volume_by_protocol::table)
+            .set((
+                pair_volume_by_protocol::apt_volume.eq(Some(BigDecimal::zero())),
+                pair_volume_by_protocol::usdc_volume.eq(Some(BigDecimal::zero())),
+                pair_volume_by_protocol::usdt_volume.eq(Some(BigDecimal::zero())),
+                pair_volume_by_protocol::weth_volume.eq(Some(BigDecimal::zero())),
+                pair_volume_by_protocol::updated_at.eq(Utc::now()),
+            ))
+            .execute(&mut conn)
+            .await
+        {
+            Ok(updated_count) => {
+                info!("✅ Reset {} pair volume records to 0", updated_count);
+            },
+            Err(e) => {
+                error!("❌ Failed to reset pair volumes: {}", e);
+            }
+        }
+
+        // Also reset coin volumes
+        match diesel::update(coin_volume_24h::table)
+            .set((
+                coin_volume_24h::buy_volume.eq(Some(BigDecimal::zero())),
+                coin_volume_24h::sell_volume.eq(Some(BigDecimal::zero())),
+                coin_volume_24h::inserted_at.eq(diesel::dsl::now)
+            ))
+            .execute(&mut conn)
+            .await
+        {
+            Ok(updated_count) => {
+                info!("✅ Reset {} coin volumes to 0", updated_count);
+            },
+            Err(e) => {
+                error!("❌ Failed to reset coin volumes: {}", e);
+            }
+        }
+
+        // Reset coin volume buckets on startup
+        match diesel::delete(coin_volume_buckets::table)
+            .execute(&mut conn)
+            .await
+        {
+            Ok(deleted_count) => {
+                info!("✅ Deleted {} coin volume bucket records on startup for fresh calculation", deleted_count);
+            },
+            Err(e) => {
+                error!("❌ Failed to reset coin volume buckets on startup: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Persist the dedup filter to `path` on shutdown, resuming from it if it already exists
+    pub fn with_dedup_state_path(mut self, path: PathBuf) -> anyhow::Result<Self> {
+        self.deduplicator = Arc::new(Mutex::new(BloomDeduplicator::load_or_default(&path)?));
+        self.dedup_state_path = Some(path);
+        Ok(self)
+    }
+
+    /// Write each protocol's apt_data row as soon as that protocol finishes
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Split batches larger than `max_batch_size` transactions into chunks processed one at a time
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Compute volumes as usual but only log what would be written, leaving the database untouched
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Remove swap events that were already counted in an earlier batch.
+    /// Returns the transactions to process and the keys to record once they are stored.
+    fn filter_duplicate_events(&self, mut transactions: Vec<Transaction>) -> (Vec<Transaction>, Vec<EventKey>) {
+        let deduplicator = self.deduplicator.lock().unwrap();
+        let mut new_keys = Vec::new();
+        let mut skipped = 0;
+
+        for txn in &mut transactions {
+            let version = txn.version;
+            if let Some(TxnData::User(user_txn)) = &mut txn.txn_data {
+                let mut index = 0u64;
+                user_txn.events.retain(|event| {
+                    let key = (version, index);
+                    index += 1;
+                    if !self.volume_calculator.is_tracked_swap_event(&event.type_str) {
+                        return true;
+                    }
+                    if deduplicator.contains(&key) {
+                        skipped += 1;
+                        return false;
                     }
+                    new_keys.push(key);
+                    true
+                });
+            }
+        }
+
+        if skipped > 0 {
+            warn!("♻️ Skipped {} swap events already processed in an earlier batch", skipped);
+            DUPLICATE_EVENTS_SKIPPED.inc_by(skipped);
+        }
+
+        (transactions, new_keys)
+    }
+
+    /// Remove historical per-swap rows older than `retention_days`, returning the number deleted
+    pub async fn purge_historical_data(&self, retention_days: u32) -> Result<u64, ProcessorError> {
+        purge_rows_older_than(&self.pools.write_pool, retention_days).await
+    }
+
+    /// Purge historical data every day at `PURGE_HOUR_UTC`
+    pub fn spawn_data_retention_task(&self, retention_days: u32) {
+        info!("🧹 Purging historical data older than {} days daily at {:02}:00 UTC", retention_days, PURGE_HOUR_UTC);
+
+        let pool = self.pools.write_pool.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(duration_until_next_purge(Utc::now())).await;
+                match purge_rows_older_than(&pool, retention_days).await {
+                    Ok(deleted) => info!("✅ Daily purge removed {} rows", deleted),
+                    Err(e) => error!("❌ Daily purge failed: {:?}", e),
                 }
             }
         });
-        
-        processor
     }
 
-    async fn get_current_volumes(&self, protocol_name: &str) -> Result<(BigDecimal, BigDecimal, BigDecimal, BigDecimal, BigDecimal, BigDecimal, BigDecimal, BigDecimal), ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection: {}", e),
+    /// Snapshot `apt_data` at the top of every UTC hour, so volume spikes can be placed within the 24h window
+    pub fn spawn_hourly_snapshot_task(&self) {
+        info!("📸 Snapshotting apt_data at the top of every UTC hour, keeping {} per protocol", MAX_HOURLY_SNAPSHOTS_PER_PROTOCOL);
+
+        let pool = self.pools.write_pool.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(duration_until_next_hour(Utc::now())).await;
+                let snapshot_hour = hour_start(Utc::now());
+                match snapshot_apt_data(&pool, snapshot_hour).await {
+                    Ok(inserted) => info!("📸 Stored {} apt_data snapshots for {}", inserted, snapshot_hour),
+                    Err(e) => error!("❌ Hourly apt_data snapshot failed: {:?}", e),
+                }
             }
-        })?;
+        });
+    }
 
+    /// Current rolling rows for `protocol_names` in one query; protocols without a row are absent.
+    /// Reads from the write pool: the result is accumulated and written back, so reading a
+    /// lagging replica would lose updates
+    async fn get_current_volumes_batch(conn: &mut MyDbConnection, protocol_names: &[&str]) -> Result<HashMap<String, ProtocolVolumeState>, ProcessorError> {
+        let rows = apt_data::table
+            .filter(apt_data::protocol_name.eq_any(protocol_names))
+            .load::<AptData>(conn)
+            .await
+            .processor_err("Failed to get current protocol volumes")?;
+
+        let current_volumes: HashMap<String, ProtocolVolumeState> = rows
+            .into_iter()
+            .map(|data| (data.protocol_name.clone(), ProtocolVolumeState::from(data)))
+            .collect();
+        for (protocol_name, state) in &current_volumes {
+            debug!("📊 Current volumes for {}: {:?}", protocol_name, state);
+        }
+
+        Ok(current_volumes)
+    }
+
+    /// Add one protocol's batch totals to its rolling apt_data row, whose values are `current`
+    async fn upsert_protocol_volume(conn: &mut MyDbConnection, record: &NewAptData, current: &ProtocolVolumeState) -> Result<(), ProcessorError> {
         let zero_decimal = BigDecimal::zero();
+        let batch_apt_volume = record.apt_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_usdc_volume = record.usdc_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_usdt_volume = record.usdt_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_weth_volume = record.weth_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_apt_fee = record.apt_fee_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_usdc_fee = record.usdc_fee_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_usdt_fee = record.usdt_fee_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_weth_fee = record.weth_fee_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_btc_volume = record.btc_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_bnb_volume = record.bnb_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_move_volume = record.move_volume_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_lp_fee = record.lp_fee_24h.as_ref().unwrap_or(&zero_decimal);
+        let batch_protocol_fee = record.protocol_fee_24h.as_ref().unwrap_or(&zero_decimal);
+
+        // Processors only ever add to these; a negative amount is a processor bug, not bad input
+        for (field, amount) in [
+            ("apt_volume", batch_apt_volume),
+            ("usdc_volume", batch_usdc_volume),
+            ("usdt_volume", batch_usdt_volume),
+            ("weth_volume", batch_weth_volume),
+            ("btc_volume", batch_btc_volume),
+            ("bnb_volume", batch_bnb_volume),
+            ("move_volume", batch_move_volume),
+            ("apt_fee", batch_apt_fee),
+            ("usdc_fee", batch_usdc_fee),
+            ("usdt_fee", batch_usdt_fee),
+            ("weth_fee", batch_weth_fee),
+            ("lp_fee", batch_lp_fee),
+            ("protocol_fee", batch_protocol_fee),
+        ] {
+            assert!(*amount >= zero_decimal, "Negative batch {} for protocol {}: {}", field, record.protocol_name, amount);
+        }
         
-        let data = apt_data::table
-            .filter(apt_data::protocol_name.eq(protocol_name))
-            .first::<AptData>(&mut conn)
+        // Accumulate both volumes and fees
+        let new_apt_volume = &current.apt_volume + batch_apt_volume;
+        let new_usdc_volume = &current.usdc_volume + batch_usdc_volume;
+        let new_usdt_volume = &current.usdt_volume + batch_usdt_volume;
+        let new_weth_volume = &current.weth_volume + batch_weth_volume;
+        let new_apt_fee = &current.apt_fee + batch_apt_fee;
+        let new_usdc_fee = &current.usdc_fee + batch_usdc_fee;
+        let new_usdt_fee = &current.usdt_fee + batch_usdt_fee;
+        let new_weth_fee = &current.weth_fee + batch_weth_fee;
+        let new_btc_volume = &current.btc_volume + batch_btc_volume;
+        let new_bnb_volume = &current.bnb_volume + batch_bnb_volume;
+        let new_move_volume = &current.move_volume + batch_move_volume;
+        let new_lp_fee = &current.lp_fee + batch_lp_fee;
+        let new_protocol_fee = &current.protocol_fee + batch_protocol_fee;
+        
+        // UPSERT: INSERT or UPDATE if protocol exists
+        match diesel::insert_into(apt_data::table)
+            .values(&NewAptData {
+                protocol_name: record.protocol_name.clone(),
+                apt_volume_24h: Some(new_apt_volume.clone()),
+                usdc_volume_24h: Some(new_usdc_volume.clone()),
+                usdt_volume_24h: Some(new_usdt_volume.clone()),
+                weth_volume_24h: Some(new_weth_volume.clone()),
+                apt_fee_24h: Some(new_apt_fee.clone()),
+                usdc_fee_24h: Some(new_usdc_fee.clone()),
+                usdt_fee_24h: Some(new_usdt_fee.clone()),
+                weth_fee_24h: Some(new_weth_fee.clone()),
+                btc_volume_24h: Some(new_btc_volume.clone()),
+                bnb_volume_24h: Some(new_bnb_volume.clone()),
+                trade_count: record.trade_count,
+                lp_fee_24h: Some(new_lp_fee),
+                protocol_fee_24h: Some(new_protocol_fee),
+                move_volume_24h: Some(new_move_volume.clone()),
+            })
+            .on_conflict(apt_data::protocol_name)
+            .do_update()
+            .set((
+                apt_data::apt_volume_24h.eq(excluded(apt_data::apt_volume_24h)),
+                apt_data::usdc_volume_24h.eq(excluded(apt_data::usdc_volume_24h)),
+                apt_data::usdt_volume_24h.eq(excluded(apt_data::usdt_volume_24h)),
+                apt_data::weth_volume_24h.eq(excluded(apt_data::weth_volume_24h)),
+                apt_data::apt_fee_24h.eq(excluded(apt_data::apt_fee_24h)),
+                apt_data::usdc_fee_24h.eq(excluded(apt_data::usdc_fee_24h)),
+                apt_data::usdt_fee_24h.eq(excluded(apt_data::usdt_fee_24h)),
+                apt_data::weth_fee_24h.eq(excluded(apt_data::weth_fee_24h)),
+                apt_data::btc_volume_24h.eq(excluded(apt_data::btc_volume_24h)),
+                apt_data::bnb_volume_24h.eq(excluded(apt_data::bnb_volume_24h)),
+                apt_data::move_volume_24h.eq(excluded(apt_data::move_volume_24h)),
+                apt_data::lp_fee_24h.eq(excluded(apt_data::lp_fee_24h)),
+                apt_data::protocol_fee_24h.eq(excluded(apt_data::protocol_fee_24h)),
+                // Volumes above are already accumulated; the trade count is added in SQL
+                apt_data::trade_count.eq(apt_data::trade_count + excluded(apt_data::trade_count)),
+                apt_data::inserted_at.eq(diesel::dsl::now)
+            ))
+            .execute(conn)
             .await
-            .optional()
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to get current volumes for {}: {}", protocol_name, e),
-            })?;
-
-        let (current_apt_volume, current_usdc_volume, current_usdt_volume, current_weth_volume, current_apt_fee, current_usdc_fee, current_usdt_fee, current_weth_fee) = if let Some(data) = data {
-            let current_apt_volume = data.apt_volume_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_usdc_volume = data.usdc_volume_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_usdt_volume = data.usdt_volume_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_weth_volume = data.weth_volume_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_apt_fee = data.apt_fee_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_usdc_fee = data.usdc_fee_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_usdt_fee = data.usdt_fee_24h.unwrap_or_else(|| zero_decimal.clone());
-            let current_weth_fee = data.weth_fee_24h.unwrap_or_else(|| zero_decimal.clone());
-
-            debug!("📊 Current volumes for {}: APT={}, USDC={}, USDT={}, WETH={}, APT_fee={}, USDC_fee={}, USDT_fee={}, WETH_fee={}",
-                protocol_name, current_apt_volume, current_usdc_volume, current_usdt_volume, current_weth_volume,
-                current_apt_fee, current_usdc_fee, current_usdt_fee, current_weth_fee);
-
-            (current_apt_volume, current_usdc_volume, current_usdt_volume, current_weth_volume, current_apt_fee, current_usdc_fee, current_usdt_fee, current_weth_fee)
-        } else {
-            (zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone(), zero_decimal.clone())
-        };
+        {
+            Ok(_) => {
+                info!("✅ Updated rolling data for protocol {}: trades +{}, APT vol +{} (total: {}), USDC vol +{} (total: {}), USDT vol +{} (total: {}), WETH vol +{} (total: {}), BTC vol +{} (total: {}), BNB vol +{} (total: {}), MOVE vol +{} (total: {}), APT fee +{} (total: {}), USDC fee +{} (total: {}), USDT fee +{} (total: {}), WETH fee +{} (total: {})", 
+                    record.protocol_name, 
+                    record.trade_count,
+                    batch_apt_volume, new_apt_volume, 
+                    batch_usdc_volume, new_usdc_volume,
+                    batch_usdt_volume, new_usdt_volume,
+                    batch_weth_volume, new_weth_volume,
+                    batch_btc_volume, new_btc_volume,
+                    batch_bnb_volume, new_bnb_volume,
+                    batch_move_volume, new_move_volume,
+                    batch_apt_fee, new_apt_fee,
+                    batch_usdc_fee, new_usdc_fee,
+                    batch_usdt_fee, new_usdt_fee,
+                    batch_weth_fee, new_weth_fee);
+            },
+            Err(e) => {
+                error!("❌ Failed to update data for protocol {}: {}", record.protocol_name, e);
+                return Err(ProcessorError::ProcessError {
+                    message: format!("Data update failed: {}", e),
+                });
+            }
+        }
 
-        Ok((current_apt_volume, current_usdc_volume, current_usdt_volume, current_weth_volume, current_apt_fee, current_usdc_fee, current_usdt_fee, current_weth_fee))
+        Ok(())
     }
 
-    async fn upsert_pool_volumes(&self, volume_data: Vec<NewAptData>) -> Result<(), ProcessorError> {
+    async fn upsert_pool_volumes(conn: &mut MyDbConnection, volume_data: Vec<NewAptData>) -> Result<(), ProcessorError> {
         if volume_data.is_empty() {
             info!("📊 No volume data to update");
             return Ok(());
         }
 
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection: {}", e),
-            }
-        })?;
+        // One SELECT for every protocol in the batch instead of one per protocol
+        let protocol_names: Vec<&str> = volume_data.iter().map(|record| record.protocol_name.as_str()).collect();
+        let current_volumes = Self::get_current_volumes_batch(conn, &protocol_names).await?;
+        let no_volume = ProtocolVolumeState::default();
 
         for record in &volume_data {
-            let zero_decimal = BigDecimal::zero();
-            let batch_apt_volume = record.apt_volume_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_usdc_volume = record.usdc_volume_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_usdt_volume = record.usdt_volume_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_weth_volume = record.weth_volume_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_apt_fee = record.apt_fee_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_usdc_fee = record.usdc_fee_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_usdt_fee = record.usdt_fee_24h.as_ref().unwrap_or(&zero_decimal);
-            let batch_weth_fee = record.weth_fee_24h.as_ref().unwrap_or(&zero_decimal);
-            
-            // Get current volumes and fees first
-            let (current_apt_volume, current_usdc_volume, current_usdt_volume, current_weth_volume, current_apt_fee, current_usdc_fee, current_usdt_fee, current_weth_fee) = 
-                self.get_current_volumes(&record.protocol_name).await?;
-            
-            // Accumulate both volumes and fees
-            let new_apt_volume = &current_apt_volume + batch_apt_volume;
-            let new_usdc_volume = &current_usdc_volume + batch_usdc_volume;
-            let new_usdt_volume = &current_usdt_volume + batch_usdt_volume;
-            let new_weth_volume = &current_weth_volume + batch_weth_volume;
-            let new_apt_fee = &current_apt_fee + batch_apt_fee;
-            let new_usdc_fee = &current_usdc_fee + batch_usdc_fee;
-            let new_usdt_fee = &current_usdt_fee + batch_usdt_fee;
-            let new_weth_fee = &current_weth_fee + batch_weth_fee;
-            
-            // UPSERT: INSERT or UPDATE if protocol exists
-            match diesel::insert_into(apt_data::table)
-                .values(&NewAptData {
-                    protocol_name: record.protocol_name.clone(),
-                    apt_volume_24h: Some(new_apt_volume.clone()),
-                    usdc_volume_24h: Some(new_usdc_volume.clone()),
-                    usdt_volume_24h: Some(new_usdt_volume.clone()),
-                    weth_volume_24h: Some(new_weth_volume.clone()),
-                    apt_fee_24h: Some(new_apt_fee.clone()),
-                    usdc_fee_24h: Some(new_usdc_fee.clone()),
-                    usdt_fee_24h: Some(new_usdt_fee.clone()),
-                    weth_fee_24h: Some(new_weth_fee.clone()),
-                })
-                .on_conflict(apt_data::protocol_name)
-                .do_update()
-                .set((
-                    apt_data::apt_volume_24h.eq(excluded(apt_data::apt_volume_24h)),
-                    apt_data::usdc_volume_24h.eq(excluded(apt_data::usdc_volume_24h)),
-                    apt_data::usdt_volume_24h.eq(excluded(apt_data::usdt_volume_24h)),
-                    apt_data::weth_volume_24h.eq(excluded(apt_data::weth_volume_24h)),
-                    apt_data::apt_fee_24h.eq(excluded(apt_data::apt_fee_24h)),
-                    apt_data::usdc_fee_24h.eq(excluded(apt_data::usdc_fee_24h)),
-                    apt_data::usdt_fee_24h.eq(excluded(apt_data::usdt_fee_24h)),
-                    apt_data::weth_fee_24h.eq(excluded(apt_data::weth_fee_24h)),
-                    apt_data::inserted_at.eq(diesel::dsl::now)
-                ))
-                .execute(&mut conn)
-                .await
-            {
-                Ok(_) => {
-                    info!("✅ Updated rolling data for protocol {}: APT vol +{} (total: {}), USDC vol +{} (total: {}), USDT vol +{} (total: {}), WETH vol +{} (total: {}), APT fee +{} (total: {}), USDC fee +{} (total: {}), USDT fee +{} (total: {}), WETH fee +{} (total: {})", 
-                        record.protocol_name, 
-                        batch_apt_volume, new_apt_volume, 
-                        batch_usdc_volume, new_usdc_volume,
-                        batch_usdt_volume, new_usdt_volume,
-                        batch_weth_volume, new_weth_volume,
-                        batch_apt_fee, new_apt_fee,
-                        batch_usdc_fee, new_usdc_fee,
-                        batch_usdt_fee, new_usdt_fee,
-                        batch_weth_fee, new_weth_fee);
-                },
-                Err(e) => {
-                    error!("❌ Failed to update data for protocol {}: {}", record.protocol_name, e);
-                    return Err(ProcessorError::ProcessError {
-                        message: format!("Data update failed: {}", e),
-                    });
-                }
-            }
+            let current = current_volumes.get(&record.protocol_name).unwrap_or(&no_volume);
+            Self::upsert_protocol_volume(conn, record, current).await?;
         }
 
         info!("✅ Successfully processed {} pool records", volume_data.len());
         
         // After updating individual protocols, calculate and update the aggregated "aptos" total
-        self.upsert_aptos_aggregated_data().await?;
+        Self::upsert_aptos_aggregated_data(conn).await?;
         
         Ok(())
     }
 
-    async fn upsert_aptos_aggregated_data(&self) -> Result<(), ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection for aptos aggregation: {}", e),
-            }
-        })?;
+    /// Add each protocol's batch fees to its protocol_fees_24h row
+    async fn upsert_protocol_fees(conn: &mut MyDbConnection, protocol_fees: Vec<NewProtocolFee>) -> Result<(), ProcessorError> {
+        if protocol_fees.is_empty() {
+            return Ok(());
+        }
+
+        let protocol_names: Vec<&str> = protocol_fees.iter().map(|fee| fee.protocol_name.as_str()).collect();
+        let current_fees: HashMap<String, ProtocolFee> = protocol_fees_24h::table
+            .filter(protocol_fees_24h::protocol_name.eq_any(&protocol_names))
+            .load::<ProtocolFee>(conn)
+            .await
+            .processor_err("Failed to get current protocol fees")?
+            .into_iter()
+            .map(|fee| (fee.protocol_name.clone(), fee))
+            .collect();
+
+        let add = |current: Option<&BigDecimal>, batch: &Option<BigDecimal>| {
+            Some(current.cloned().unwrap_or_default() + batch.clone().unwrap_or_default())
+        };
+        let accumulated: Vec<NewProtocolFee> = protocol_fees
+            .iter()
+            .map(|batch| {
+                let current = current_fees.get(&batch.protocol_name);
+                NewProtocolFee {
+                    protocol_name: batch.protocol_name.clone(),
+                    apt_fee: add(current.and_then(|fee| fee.apt_fee.as_ref()), &batch.apt_fee),
+                    usdc_fee: add(current.and_then(|fee| fee.usdc_fee.as_ref()), &batch.usdc_fee),
+                    usdt_fee: add(current.and_then(|fee| fee.usdt_fee.as_ref()), &batch.usdt_fee),
+                    weth_fee: add(current.and_then(|fee| fee.weth_fee.as_ref()), &batch.weth_fee),
+                }
+            })
+            .collect();
+
+        diesel::insert_into(protocol_fees_24h::table)
+            .values(&accumulated)
+            .on_conflict(protocol_fees_24h::protocol_name)
+            .do_update()
+            .set((
+                protocol_fees_24h::apt_fee.eq(excluded(protocol_fees_24h::apt_fee)),
+                protocol_fees_24h::usdc_fee.eq(excluded(protocol_fees_24h::usdc_fee)),
+                protocol_fees_24h::usdt_fee.eq(excluded(protocol_fees_24h::usdt_fee)),
+                protocol_fees_24h::weth_fee.eq(excluded(protocol_fees_24h::weth_fee)),
+                protocol_fees_24h::updated_at.eq(Utc::now()),
+            ))
+            .execute(conn)
+            .await
+            .processor_err("Failed to upsert protocol fees")?;
+
+        info!("💸 Updated 24h fees for {} protocols", accumulated.len());
+        Ok(())
+    }
+
+    /// Add each protocol's batch pair volumes to its pair_volume_by_protocol rows
+    async fn upsert_pair_volumes(conn: &mut MyDbConnection, pair_volumes: Vec<NewPairProtocolVolume>) -> Result<(), ProcessorError> {
+        if pair_volumes.is_empty() {
+            return Ok(());
+        }
+
+        let protocols: Vec<&str> = pair_volumes.iter().map(|volume| volume.protocol.as_str()).collect();
+        let current_volumes: HashMap<(String, String), PairProtocolVolume> = pair_volume_by_protocol::table
+            .filter(pair_volume_by_protocol::protocol.eq_any(&protocols))
+            .load::<PairProtocolVolume>(conn)
+            .await
+            .processor_err("Failed to get current pair volumes")?
+            .into_iter()
+            .map(|volume| ((volume.protocol.clone(), volume.pair.clone()), volume))
+            .collect();
+
+        let add = |current: Option<&BigDecimal>, batch: &Option<BigDecimal>| {
+            Some(current.cloned().unwrap_or_default() + batch.clone().unwrap_or_default())
+        };
+        let accumulated: Vec<NewPairProtocolVolume> = pair_volumes
+            .iter()
+            .map(|batch| {
+                let current = current_volumes.get(&(batch.protocol.clone(), batch.pair.clone()));
+                NewPairProtocolVolume {
+                    protocol: batch.protocol.clone(),
+                    pair: batch.pair.clone(),
+                    apt_volume: add(current.and_then(|volume| volume.apt_volume.as_ref()), &batch.apt_volume),
+                    usdc_volume: add(current.and_then(|volume| volume.usdc_volume.as_ref()), &batch.usdc_volume),
+                    usdt_volume: add(current.and_then(|volume| volume.usdt_volume.as_ref()), &batch.usdt_volume),
+                    weth_volume: add(current.and_then(|volume| volume.weth_volume.as_ref()), &batch.weth_volume),
+                }
+            })
+            .collect();
+
+        diesel::insert_into(pair_volume_by_protocol::table)
+            .values(&accumulated)
+            .on_conflict((pair_volume_by_protocol::protocol, pair_volume_by_protocol::pair))
+            .do_update()
+            .set((
+                pair_volume_by_protocol::apt_volume.eq(excluded(pair_volume_by_protocol::apt_volume)),
+                pair_volume_by_protocol::usdc_volume.eq(excluded(pair_volume_by_protocol::usdc_volume)),
+                pair_volume_by_protocol::usdt_volume.eq(excluded(pair_volume_by_protocol::usdt_volume)),
+                pair_volume_by_protocol::weth_volume.eq(excluded(pair_volume_by_protocol::weth_volume)),
+                pair_volume_by_protocol::updated_at.eq(Utc::now()),
+            ))
+            .execute(conn)
+            .await
+            .processor_err("Failed to upsert pair volumes")?;
+
+        info!("🔀 Updated 24h volumes for {} protocol pairs", accumulated.len());
+        Ok(())
+    }
+
+    /// Copy fees from protocol_fees_24h into apt_data's fee columns, which are kept for
+    /// existing readers
+    async fn sync_apt_data_fees(conn: &mut MyDbConnection) -> Result<(), ProcessorError> {
+        let updated = diesel::sql_query(
+            "UPDATE apt_data SET apt_fee_24h = f.apt_fee, usdc_fee_24h = f.usdc_fee, usdt_fee_24h = f.usdt_fee, weth_fee_24h = f.weth_fee \
+             FROM protocol_fees_24h f WHERE apt_data.protocol_name = f.protocol_name",
+        )
+        .execute(conn)
+        .await
+        .processor_err("Failed to copy protocol fees into apt_data")?;
+
+        debug!("💸 Copied fees into {} apt_data rows", updated);
+        Ok(())
+    }
+
+    async fn upsert_aptos_aggregated_data(conn: &mut MyDbConnection) -> Result<(), ProcessorError> {
+        // Protocol fee columns come from protocol_fees_24h, so refresh them before summing
+        Self::sync_apt_data_fees(conn).await?;
 
         info!("🔄 Calculating aggregated data for 'aptos' protocol from dapps...");
 
@@ -260,11 +934,9 @@ impl TasmilProcessor {
         // Get data for all dapps
         let dapp_data: Vec<AptData> = apt_data::table
             .filter(apt_data::protocol_name.eq_any(&dapp_names))
-            .load(&mut conn)
+            .load(conn)
             .await
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to load dapp data for aggregation: {}", e),
-            })?;
+            .processor_err("Failed to load dapp data for aggregation")?;
 
         if dapp_data.is_empty() {
             info!("📊 No dapp data found for aggregation");
@@ -281,6 +953,12 @@ impl TasmilProcessor {
         let mut total_usdc_fee = zero_decimal.clone();
         let mut total_usdt_fee = zero_decimal.clone();
         let mut total_weth_fee = zero_decimal.clone();
+        let mut total_btc_volume = zero_decimal.clone();
+        let mut total_bnb_volume = zero_decimal.clone();
+        let mut total_move_volume = zero_decimal.clone();
+        let mut total_lp_fee = zero_decimal.clone();
+        let mut total_protocol_fee = zero_decimal.clone();
+        let mut total_trade_count = 0;
 
         for data in &dapp_data {
             total_apt_volume += data.apt_volume_24h.as_ref().unwrap_or(&zero_decimal);
@@ -291,10 +969,16 @@ impl TasmilProcessor {
             total_usdc_fee += data.usdc_fee_24h.as_ref().unwrap_or(&zero_decimal);
             total_usdt_fee += data.usdt_fee_24h.as_ref().unwrap_or(&zero_decimal);
             total_weth_fee += data.weth_fee_24h.as_ref().unwrap_or(&zero_decimal);
+            total_btc_volume += data.btc_volume_24h.as_ref().unwrap_or(&zero_decimal);
+            total_bnb_volume += data.bnb_volume_24h.as_ref().unwrap_or(&zero_decimal);
+            total_move_volume += data.move_volume_24h.as_ref().unwrap_or(&zero_decimal);
+            total_lp_fee += data.lp_fee_24h.as_ref().unwrap_or(&zero_decimal);
+            total_protocol_fee += data.protocol_fee_24h.as_ref().unwrap_or(&zero_decimal);
+            total_trade_count += data.trade_count;
         }
 
-        info!("📊 Aggregated totals: APT vol={}, USDC vol={}, USDT vol={}, WETH vol={}, APT fee={}, USDC fee={}, USDT fee={}, WETH fee={}", 
-            total_apt_volume, total_usdc_volume, total_usdt_volume, total_weth_volume,
+        info!("📊 Aggregated totals: APT vol={}, USDC vol={}, USDT vol={}, WETH vol={}, BTC vol={}, BNB vol={}, MOVE vol={}, APT fee={}, USDC fee={}, USDT fee={}, WETH fee={}", 
+            total_apt_volume, total_usdc_volume, total_usdt_volume, total_weth_volume, total_btc_volume, total_bnb_volume, total_move_volume,
             total_apt_fee, total_usdc_fee, total_usdt_fee, total_weth_fee);
 
         // Upsert the aggregated "aptos" record
@@ -309,6 +993,12 @@ impl TasmilProcessor {
                 usdc_fee_24h: Some(total_usdc_fee.clone()),
                 usdt_fee_24h: Some(total_usdt_fee.clone()),
                 weth_fee_24h: Some(total_weth_fee.clone()),
+                btc_volume_24h: Some(total_btc_volume.clone()),
+                bnb_volume_24h: Some(total_bnb_volume.clone()),
+                trade_count: total_trade_count,
+                lp_fee_24h: Some(total_lp_fee),
+                protocol_fee_24h: Some(total_protocol_fee),
+                move_volume_24h: Some(total_move_volume.clone()),
             })
             .on_conflict(apt_data::protocol_name)
             .do_update()
@@ -321,14 +1011,20 @@ impl TasmilProcessor {
                 apt_data::usdc_fee_24h.eq(excluded(apt_data::usdc_fee_24h)),
                 apt_data::usdt_fee_24h.eq(excluded(apt_data::usdt_fee_24h)),
                 apt_data::weth_fee_24h.eq(excluded(apt_data::weth_fee_24h)),
+                apt_data::btc_volume_24h.eq(excluded(apt_data::btc_volume_24h)),
+                apt_data::bnb_volume_24h.eq(excluded(apt_data::bnb_volume_24h)),
+                apt_data::move_volume_24h.eq(excluded(apt_data::move_volume_24h)),
+                apt_data::lp_fee_24h.eq(excluded(apt_data::lp_fee_24h)),
+                apt_data::protocol_fee_24h.eq(excluded(apt_data::protocol_fee_24h)),
+                apt_data::trade_count.eq(excluded(apt_data::trade_count)),
                 apt_data::inserted_at.eq(diesel::dsl::now)
             ))
-            .execute(&mut conn)
+            .execute(conn)
             .await
         {
             Ok(_) => {
-                info!("✅ Updated aggregated 'aptos' protocol data: APT vol={}, USDC vol={}, USDT vol={}, WETH vol={}, APT fee={}, USDC fee={}, USDT fee={}, WETH fee={}", 
-                    total_apt_volume, total_usdc_volume, total_usdt_volume, total_weth_volume,
+                info!("✅ Updated aggregated 'aptos' protocol data: trades={}, APT vol={}, USDC vol={}, USDT vol={}, WETH vol={}, BTC vol={}, BNB vol={}, MOVE vol={}, APT fee={}, USDC fee={}, USDT fee={}, WETH fee={}", 
+                    total_trade_count, total_apt_volume, total_usdc_volume, total_usdt_volume, total_weth_volume, total_btc_volume, total_bnb_volume, total_move_volume,
                     total_apt_fee, total_usdc_fee, total_usdt_fee, total_weth_fee);
             },
             Err(e) => {
@@ -342,12 +1038,62 @@ impl TasmilProcessor {
         Ok(())
     }
 
-    async fn cleanup_old_data(&self) -> Result<(), ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection for cleanup: {}", e),
-            }
-        })?;
+    /// Latest implied USD price per coin from `coin_price_usd`
+    async fn get_latest_coin_prices(conn: &mut MyDbConnection) -> Result<HashMap<String, BigDecimal>, ProcessorError> {
+        let prices: Vec<(String, BigDecimal)> = coin_price_usd::table
+            .select((coin_price_usd::coin, coin_price_usd::price_usd))
+            .distinct_on(coin_price_usd::coin)
+            .order_by((coin_price_usd::coin, coin_price_usd::transaction_version.desc()))
+            .load(conn)
+            .await
+            .processor_err("Failed to load latest coin prices")?;
+
+        Ok(prices.into_iter().collect())
+    }
+
+    /// Recompute `apt_data_usd` from the current `apt_data` totals and latest coin prices
+    async fn upsert_usd_volumes(conn: &mut MyDbConnection) -> Result<(), ProcessorError> {
+        let apt_data: Vec<NewAptData> = apt_data::table
+            .load::<AptData>(conn)
+            .await
+            .processor_err("Failed to load apt_data for USD volumes")?
+            .into_iter()
+            .map(NewAptData::from)
+            .collect();
+        if apt_data.is_empty() {
+            return Ok(());
+        }
+
+        let prices = Self::get_latest_coin_prices(conn).await?;
+        let usd_data: Vec<NewAptDataUsd> = VolumeCalculator::compute_usd_volume(&apt_data, &prices);
+
+        diesel::insert_into(apt_data_usd::table)
+            .values(&usd_data)
+            .on_conflict(apt_data_usd::protocol_name)
+            .do_update()
+            .set((
+                apt_data_usd::total_usd_volume.eq(excluded(apt_data_usd::total_usd_volume)),
+                apt_data_usd::total_usd_fee.eq(excluded(apt_data_usd::total_usd_fee)),
+                apt_data_usd::inserted_at.eq(diesel::dsl::now)
+            ))
+            .execute(conn)
+            .await
+            .processor_err("Failed to upsert apt_data_usd")?;
+
+        info!("💵 Updated USD volumes for {} protocols ({} coin prices)", usd_data.len(), prices.len());
+        Ok(())
+    }
+
+    async fn cleanup_old_data(&mut self) -> Result<(), ProcessorError> {
+        if self.dry_run {
+            info!(
+                "🧪 Dry run: would delete buckets older than {} and reset volumes if the 24h window ended",
+                (Utc::now() - Duration::hours(24)).format("%Y-%m-%d %H:%M:%S UTC")
+            );
+            return Ok(());
+        }
+
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for cleanup")?;
 
         // Calculate cutoff time (24 hours ago)
         let now = Utc::now();
@@ -357,24 +1103,32 @@ impl TasmilProcessor {
 
         // Clean up old bucket data first (older than 24 hours)
         self.cleanup_old_buckets(cutoff_time).await?;
+        self.cleanup_old_daily_buckets(now).await?;
+
+        // Advance the 24h window if it has ended; volumes are reset exactly at the window end
+        if self.volume_window.is_none() {
+            self.volume_window = Some(VolumeWindowManager::load_or_start(&self.pools.write_pool, now.naive_utc()).await?);
+        }
+        let mut window_rolled = false;
+        if let Some(window) = self.volume_window.as_mut() {
+            if window.roll_forward(now.naive_utc()) {
+                window.save(&self.pools.write_pool).await?;
+                window_rolled = true;
+            }
+            self.volume_calculator.set_volume_window(*window);
+        }
 
         // Get all records to check if we need to reset the rolling window
         let current_records: Vec<AptData> = apt_data::table
             .load(&mut conn)
             .await
-            .map_err(|e| {
-                ProcessorError::ProcessError {
-                    message: format!("Failed to load current records: {}", e),
-                }
-            })?;
+            .processor_err("Failed to load current records")?;
 
         if current_records.is_empty() {
             info!("📝 No existing records found");
             return Ok(());
         }
 
-        // Check if the last update was more than 24 hours ago
-        // Since we update inserted_at on every upsert, if it's old, it means no new data
         let latest_update = current_records
             .iter()
             .map(|r| r.inserted_at)
@@ -383,8 +1137,8 @@ impl TasmilProcessor {
         if let Some(latest) = latest_update {
             let latest_utc = DateTime::<Utc>::from_naive_utc_and_offset(latest, Utc);
             
-            if latest_utc < cutoff_time {
-                info!("🔄 Last update was {} (>24h ago), resetting volumes for new window", 
+            if window_rolled {
+                info!("🔄 Volume window ended (last update {}), resetting volumes for new window", 
                     latest_utc.format("%Y-%m-%d %H:%M:%S UTC"));
                 
                 match diesel::update(apt_data::table)
@@ -397,6 +1151,12 @@ impl TasmilProcessor {
                         apt_data::usdc_fee_24h.eq(Some(BigDecimal::zero())),
                         apt_data::usdt_fee_24h.eq(Some(BigDecimal::zero())),
                         apt_data::weth_fee_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::btc_volume_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::bnb_volume_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::move_volume_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::lp_fee_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::protocol_fee_24h.eq(Some(BigDecimal::zero())),
+                        apt_data::trade_count.eq(0),
                         apt_data::inserted_at.eq(diesel::dsl::now)
                     ))
                     .execute(&mut conn)
@@ -410,6 +1170,45 @@ impl TasmilProcessor {
                     }
                 }
 
+                // Fees accumulate separately, so reset them with the volumes
+                match diesel::update(protocol_fees_24h::table)
+                    .set((
+                        protocol_fees_24h::apt_fee.eq(Some(BigDecimal::zero())),
+                        protocol_fees_24h::usdc_fee.eq(Some(BigDecimal::zero())),
+                        protocol_fees_24h::usdt_fee.eq(Some(BigDecimal::zero())),
+                        protocol_fees_24h::weth_fee.eq(Some(BigDecimal::zero())),
+                        protocol_fees_24h::updated_at.eq(Utc::now()),
+                    ))
+                    .execute(&mut conn)
+                    .await
+                {
+                    Ok(updated_count) => {
+                        info!("✅ Reset {} protocol fee records to 0", updated_count);
+                    },
+                    Err(e) => {
+                        error!("❌ Failed to reset protocol fees: {}", e);
+                    }
+                }
+
+                match diesel::update(pair_volume_by_protocol::table)
+                    .set((
+                        pair_volume_by_protocol::apt_volume.eq(Some(BigDecimal::zero())),
+                        pair_volume_by_protocol::usdc_volume.eq(Some(BigDecimal::zero())),
+                        pair_volume_by_protocol::usdt_volume.eq(Some(BigDecimal::zero())),
+                        pair_volume_by_protocol::weth_volume.eq(Some(BigDecimal::zero())),
+                        pair_volume_by_protocol::updated_at.eq(Utc::now()),
+                    ))
+                    .execute(&mut conn)
+                    .await
+                {
+                    Ok(updated_count) => {
+                        info!("✅ Reset {} pair volume records to 0", updated_count);
+                    },
+                    Err(e) => {
+                        error!("❌ Failed to reset pair volumes: {}", e);
+                    }
+                }
+
                 // Also reset coin volumes for new 24h window
                 match diesel::update(coin_volume_24h::table)
                     .set((
@@ -482,13 +1281,9 @@ impl TasmilProcessor {
     
     /// Clean up old bucket data that is older than 24 hours
     async fn cleanup_old_buckets(&self, cutoff_time: DateTime<Utc>) -> Result<(), ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection for bucket cleanup: {}", e),
-            }
-        })?;
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for bucket cleanup")?;
         
-        // Convert cutoff_time to NaiveDateTime for comparison
+        // Bucket boundaries are stored in UTC, so the UTC cutoff compares directly
         let cutoff_naive = cutoff_time.naive_utc();
         
         // Delete buckets older than cutoff time
@@ -508,22 +1303,22 @@ impl TasmilProcessor {
             }
         }
         
-        // Keep only the latest 12 buckets per coin (for 24h chart with 2h buckets)
-        let coins: Vec<String> = coin_volume_buckets::table
-            .select(coin_volume_buckets::coin)
+        // Keep only the latest 24h worth of buckets per coin and protocol (12 with the default 2h buckets)
+        let max_buckets = self.volume_calculator.buckets_per_24h();
+        let series: Vec<(String, String)> = coin_volume_buckets::table
+            .select((coin_volume_buckets::coin, coin_volume_buckets::protocol))
             .distinct()
             .load(&mut conn)
             .await
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to get distinct coins: {}", e),
-            })?;
+            .processor_err("Failed to get distinct coins")?;
             
         let mut total_deleted = 0;
         
-        for coin in coins {
+        for (coin, protocol) in series {
             // Get all buckets for this coin, ordered by newest first
             let buckets: Vec<(String, NaiveDateTime)> = coin_volume_buckets::table
                 .filter(coin_volume_buckets::coin.eq(&coin))
+                .filter(coin_volume_buckets::protocol.eq(&protocol))
                 .select((
                     coin_volume_buckets::coin,
                     coin_volume_buckets::bucket_start
@@ -531,14 +1326,12 @@ impl TasmilProcessor {
                 .order_by(coin_volume_buckets::bucket_start.desc())
                 .load(&mut conn)
                 .await
-                .map_err(|e| ProcessorError::ProcessError {
-                    message: format!("Failed to get buckets for coin {}: {}", coin, e),
-                })?;
+                .processor_err(&format!("Failed to get buckets for coin {}", coin))?;
                 
-            // If we have more than 12 buckets, delete the oldest ones
-            if buckets.len() > 12 {
-                // Keep only the newest 12 buckets
-                let buckets_to_keep = buckets.iter().take(12).cloned().collect::<Vec<_>>();
+            // If we have more than max_buckets buckets, delete the oldest ones
+            if buckets.len() > max_buckets {
+                // Keep only the newest max_buckets buckets
+                let buckets_to_keep = buckets.iter().take(max_buckets).cloned().collect::<Vec<_>>();
                 
                 // Get the oldest bucket start time that we want to keep
                 let oldest_bucket_to_keep = buckets_to_keep.last().map(|(_coin, start)| start).unwrap();
@@ -546,16 +1339,17 @@ impl TasmilProcessor {
                 // Delete all buckets older than the oldest one we want to keep
                 match diesel::delete(coin_volume_buckets::table)
                     .filter(coin_volume_buckets::coin.eq(&coin))
+                    .filter(coin_volume_buckets::protocol.eq(&protocol))
                     .filter(coin_volume_buckets::bucket_start.lt(oldest_bucket_to_keep))
                     .execute(&mut conn)
                     .await
                 {
                     Ok(deleted_count) => {
-                        info!("🧹 Deleted {} excess bucket records for coin {} (keeping latest 12)", deleted_count, coin);
+                        info!("🧹 Deleted {} excess bucket records for coin {} [{}] (keeping latest {})", deleted_count, coin, protocol, max_buckets);
                         total_deleted += deleted_count;
                     },
                     Err(e) => {
-                        error!("❌ Failed to delete excess bucket records for coin {}: {}", coin, e);
+                        error!("❌ Failed to delete excess bucket records for coin {} [{}]: {}", coin, protocol, e);
                     }
                 }
             }
@@ -568,17 +1362,11 @@ impl TasmilProcessor {
         Ok(())
     }
 
-    async fn upsert_coin_volumes(&self, coin_volume_data: Vec<NewCoinVolume24h>) -> Result<(), ProcessorError> {
+    async fn upsert_coin_volumes(conn: &mut MyDbConnection, coin_volume_data: Vec<NewCoinVolume24h>) -> Result<(), ProcessorError> {
         if coin_volume_data.is_empty() {
             return Ok(());
         }
 
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection for coin volumes: {}", e),
-            }
-        })?;
-
         info!("🪙 Upserting {} aggregated coin volume records", coin_volume_data.len());
 
         for record in &coin_volume_data {
@@ -589,12 +1377,10 @@ impl TasmilProcessor {
             // Get current volumes first
             let current_data = coin_volume_24h::table
                 .filter(coin_volume_24h::coin.eq(&record.coin))
-                .first::<CoinVolume24h>(&mut conn)
+                .first::<CoinVolume24h>(conn)
                 .await
                 .optional()
-                .map_err(|e| ProcessorError::ProcessError {
-                    message: format!("Failed to get current coin volumes for {}: {}", record.coin, e),
-                })?;
+                .processor_err(&format!("Failed to get current coin volumes for {}", record.coin))?;
 
             let (current_buy_volume, current_sell_volume) = if let Some(data) = current_data {
                 let current_buy = data.buy_volume.unwrap_or_else(|| zero_decimal.clone());
@@ -622,7 +1408,7 @@ impl TasmilProcessor {
                     coin_volume_24h::sell_volume.eq(excluded(coin_volume_24h::sell_volume)),
                     coin_volume_24h::inserted_at.eq(diesel::dsl::now)
                 ))
-                .execute(&mut conn)
+                .execute(conn)
                 .await
             {
                 Ok(_) => {
@@ -645,121 +1431,338 @@ impl TasmilProcessor {
         Ok(())
     }
 
-    async fn upsert_coin_volume_buckets(&self, bucket_data: Vec<NewCoinVolumeBucket>) -> Result<(), ProcessorError> {
-        if bucket_data.is_empty() {
-            return Ok(());
-        }
+    /// Delete daily candles older than `DAILY_BUCKET_RETENTION_DAYS` (GMT+7 days)
+    async fn cleanup_old_daily_buckets(&self, now: DateTime<Utc>) -> Result<(), ProcessorError> {
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for daily bucket cleanup")?;
+
+        let oldest_date = daily_bucket_date(now.timestamp()) - Duration::days(DAILY_BUCKET_RETENTION_DAYS);
+        let deleted_count = diesel::delete(coin_volume_daily_buckets::table)
+            .filter(coin_volume_daily_buckets::bucket_date.lt(oldest_date))
+            .execute(&mut conn)
+            .await
+            .processor_err("Failed to delete old daily bucket records")?;
+
+        if deleted_count > 0 {
+            info!("🧹 Deleted {} daily bucket records before {}", deleted_count, oldest_date);
+        }
+
+        Ok(())
+    }
+
+    /// Add each batch's daily volume to the stored candle for that coin and GMT+7 day
+    async fn upsert_coin_volume_daily_buckets(conn: &mut MyDbConnection, daily_data: Vec<NewCoinVolumeDailyBucket>) -> Result<(), ProcessorError> {
+        for record in &daily_data {
+            diesel::insert_into(coin_volume_daily_buckets::table)
+                .values(record)
+                .on_conflict((coin_volume_daily_buckets::coin, coin_volume_daily_buckets::bucket_date))
+                .do_update()
+                .set((
+                    coin_volume_daily_buckets::volume
+                        .eq(coin_volume_daily_buckets::volume + excluded(coin_volume_daily_buckets::volume)),
+                    coin_volume_daily_buckets::inserted_at.eq(diesel::dsl::now),
+                ))
+                .execute(conn)
+                .await
+                .processor_err(&format!("Failed to upsert daily bucket for {}", record.coin))?;
+
+            debug!("📅 Updated daily bucket: {} {} (+{})", record.coin, record.bucket_date, record.volume);
+        }
+
+        info!("✅ Upserted {} daily bucket records", daily_data.len());
+        Ok(())
+    }
+
+    /// Merge each bucket's batch VWAP into the stored one, weighting both by their volume
+    async fn upsert_vwap_buckets(conn: &mut MyDbConnection, vwap_data: Vec<NewVwapBucket>) -> Result<(), ProcessorError> {
+        for record in &vwap_data {
+            diesel::insert_into(vwap_buckets::table)
+                .values(record)
+                .on_conflict((vwap_buckets::protocol, vwap_buckets::pair, vwap_buckets::bucket_start))
+                .do_update()
+                .set((
+                    vwap_buckets::vwap.eq((vwap_buckets::vwap * vwap_buckets::volume
+                        + excluded(vwap_buckets::vwap) * excluded(vwap_buckets::volume))
+                        / (vwap_buckets::volume + excluded(vwap_buckets::volume))),
+                    vwap_buckets::volume.eq(vwap_buckets::volume + excluded(vwap_buckets::volume)),
+                    vwap_buckets::updated_at.eq(Utc::now()),
+                ))
+                .execute(conn)
+                .await
+                .processor_err(&format!("Failed to upsert VWAP bucket for {} {}", record.protocol, record.pair))?;
+
+            debug!("💹 Updated VWAP bucket: {} {} {} (batch VWAP {})", record.protocol, record.pair, record.bucket_start, record.vwap);
+        }
+
+        info!("✅ Upserted {} VWAP bucket records", vwap_data.len());
+        Ok(())
+    }
+
+    async fn upsert_coin_volume_buckets(
+        conn: &mut MyDbConnection,
+        bucket_data: Vec<NewCoinVolumeBucket>,
+        bucket_duration_seconds: i64,
+    ) -> Result<(), ProcessorError> {
+        if bucket_data.is_empty() {
+            return Ok(());
+        }
+
+        // Reject the whole batch rather than store a bucket with broken boundaries
+        for record in &bucket_data {
+            if let Err(e) = validate_bucket(record, bucket_duration_seconds) {
+                error!("❌ Invalid bucket for {} [{}]: {}", record.coin, record.protocol, e);
+                return Err(ProcessorError::ProcessError {
+                    message: format!("Invalid bucket for {}: {}", record.coin, e),
+                });
+            }
+        }
+
+        info!("🪣 Upserting {} bucket records", bucket_data.len());
+
+        for record in &bucket_data {
+            let batch_volume = record.volume.clone().unwrap_or_default();
+
+            // Add to the stored volume in the upsert itself, so two processor instances
+            // writing the same bucket (e.g. during a rolling restart) both count
+            match diesel::insert_into(coin_volume_buckets::table)
+                .values(&with_batch_volume(record))
+                .on_conflict((coin_volume_buckets::coin, coin_volume_buckets::protocol, coin_volume_buckets::bucket_start))
+                .do_update()
+                .set((
+                    coin_volume_buckets::volume.eq(coalesce(coin_volume_buckets::volume, BigDecimal::zero()) + excluded(coin_volume_buckets::volume)),
+                    coin_volume_buckets::bucket_end.eq(excluded(coin_volume_buckets::bucket_end)),
+                    coin_volume_buckets::inserted_at.eq(diesel::dsl::now)
+                ))
+                .returning(coin_volume_buckets::volume)
+                .get_result::<Option<BigDecimal>>(conn)
+                .await
+            {
+                Ok(new_volume) => {
+                    info!("✅ Updated bucket: {} [{}] {} - {} (batch: +{}, total: {})", 
+                        record.coin,
+                        record.protocol,
+                        record.bucket_start.format("%Y-%m-%d %H:%M:%S"), 
+                        record.bucket_end.format("%Y-%m-%d %H:%M:%S"),
+                        batch_volume, new_volume.unwrap_or_default());
+                },
+                Err(e) => {
+                    error!("❌ Failed to upsert bucket for {}: {}", record.coin, e);
+                    return Err(ProcessorError::ProcessError {
+                        message: format!("Bucket upsert failed: {}", e),
+                    });
+                }
+            }
+        }
+
+        info!("✅ Successfully processed {} bucket records", bucket_data.len());
+        
+        Ok(())
+    }
+
+    /// Query coin volume buckets with proper ordering
+    pub async fn get_coin_volume_buckets_ordered(&self) -> Result<Vec<CoinVolumeBucket>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let buckets = coin_volume_buckets::table
+            .filter(coin_volume_buckets::protocol.eq(ALL_PROTOCOLS))
+            .order_by((
+                coin_volume_buckets::coin.asc(),
+                coin_volume_buckets::bucket_start.asc()
+            ))
+            .load::<CoinVolumeBucket>(&mut conn)
+            .await
+            .processor_err("Failed to query coin volume buckets")?;
+
+        info!("📊 Retrieved {} coin volume buckets (ordered by coin, bucket_start)", buckets.len());
+        
+        Ok(buckets)
+    }
+
+    async fn insert_anomalous_swaps(conn: &mut MyDbConnection, swaps: Vec<NewAnomalousSwap>) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(anomalous_swaps::table)
+            .values(&swaps)
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert anomalous swaps")?;
+
+        warn!("🚨 Stored {} anomalous swaps for review", inserted);
+        Ok(())
+    }
+
+    async fn insert_coin_prices(conn: &mut MyDbConnection, prices: &[NewCoinPriceUsd]) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(coin_price_usd::table)
+            .values(prices)
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert coin prices")?;
+
+        debug!("💲 Stored {} implied coin prices", inserted);
+        Ok(())
+    }
+
+    async fn insert_chain_metrics(conn: &mut MyDbConnection, metrics: Vec<ChainMetric>) -> Result<(), ProcessorError> {
+        // Replayed batches carry the same block metadata transactions
+        let inserted = diesel::insert_into(chain_metrics::table)
+            .values(&metrics)
+            .on_conflict(chain_metrics::txn_version)
+            .do_nothing()
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert chain metrics")?;
+
+        debug!("⛓️ Stored {} block metadata records", inserted);
+        Ok(())
+    }
+
+    async fn insert_deployed_contracts(conn: &mut MyDbConnection, contracts: Vec<NewDeployedContract>) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(deployed_contracts::table)
+            .values(&contracts)
+            .on_conflict((deployed_contracts::address, deployed_contracts::module_name))
+            .do_nothing()
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert deployed contracts")?;
+
+        info!("📜 Stored {} deployed DEX contracts", inserted);
+        Ok(())
+    }
+
+    async fn load_deployed_contracts(&self) -> Result<Vec<DeployedContract>, ProcessorError> {
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for deployed contracts")?;
+
+        deployed_contracts::table
+            .load::<DeployedContract>(&mut conn)
+            .await
+            .processor_err("Failed to load deployed contracts")
+    }
+
+    async fn insert_hyperion_fee_growth(conn: &mut MyDbConnection, fee_growth: Vec<NewHyperionPoolFeeGrowth>) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(hyperion_pool_fee_growth::table)
+            .values(&fee_growth)
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert Hyperion fee growth")?;
+
+        debug!("🌱 Stored {} Hyperion fee growth records", inserted);
+        Ok(())
+    }
+
+    /// Replace each pool's stored reserves with the batch's latest ones
+    async fn upsert_pool_reserves(conn: &mut MyDbConnection, reserves: Vec<NewPoolReserve>) -> Result<(), ProcessorError> {
+        let upserted = diesel::insert_into(pool_reserves::table)
+            .values(&reserves)
+            .on_conflict((pool_reserves::protocol, pool_reserves::pool_address))
+            .do_update()
+            .set((
+                pool_reserves::reserve_x.eq(excluded(pool_reserves::reserve_x)),
+                pool_reserves::reserve_y.eq(excluded(pool_reserves::reserve_y)),
+                pool_reserves::txn_version.eq(excluded(pool_reserves::txn_version)),
+                pool_reserves::current_tick.eq(excluded(pool_reserves::current_tick)),
+                pool_reserves::updated_at.eq(Utc::now()),
+            ))
+            .execute(conn)
+            .await
+            .processor_err("Failed to upsert pool reserves")?;
+
+        debug!("🏊 Updated reserves for {} pools", upserted);
+        Ok(())
+    }
+
+    /// Record swaps on pairs missing from the pool registry for operator review.
+    /// The batch holds one entry per pair, so each row is hit at most once.
+    async fn upsert_undiscovered_pools(conn: &mut MyDbConnection, pools: Vec<NewUndiscoveredPool>) -> Result<(), ProcessorError> {
+        let upserted = diesel::insert_into(undiscovered_pools::table)
+            .values(&pools)
+            .on_conflict((undiscovered_pools::protocol, undiscovered_pools::token_x, undiscovered_pools::token_y))
+            .do_update()
+            .set((
+                undiscovered_pools::swap_count.eq(undiscovered_pools::swap_count + excluded(undiscovered_pools::swap_count)),
+                undiscovered_pools::last_seen_version.eq(excluded(undiscovered_pools::last_seen_version)),
+            ))
+            .execute(conn)
+            .await
+            .processor_err("Failed to upsert undiscovered pools")?;
+
+        info!("🔍 Recorded {} token pairs missing from the pool registry", upserted);
+        Ok(())
+    }
+
+    /// Point rows keyed by a migrated Cellana pool's old address at its new address.
+    /// apt_data is keyed by protocol, not pool, so only pool_reserves holds pool addresses.
+    async fn apply_pool_migrations(conn: &mut MyDbConnection, migrations: &[PoolMigration]) -> Result<(), ProcessorError> {
+        for migration in migrations {
+            let updated = diesel::update(pool_reserves::table)
+                .filter(pool_reserves::protocol.eq("cellana"))
+                .filter(pool_reserves::pool_address.eq(&migration.old_pool_address))
+                .set(pool_reserves::pool_address.eq(&migration.new_pool_address))
+                .execute(conn)
+                .await
+                .processor_err(&format!("Failed to migrate pool {}", migration.old_pool_address))?;
+
+            warn!(
+                "🚚 Migrated Cellana pool {} → {} ({} pool_reserves rows)",
+                migration.old_pool_address, migration.new_pool_address, updated
+            );
+        }
+        Ok(())
+    }
+
+    /// Store Hyperion pools from InitializePoolEvents; a pool is only ever initialized once
+    async fn insert_hyperion_pools(conn: &mut MyDbConnection, hyperion_pools_data: Vec<NewHyperionPool>) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(hyperion_pools::table)
+            .values(&hyperion_pools_data)
+            .on_conflict(hyperion_pools::pool_address)
+            .do_nothing()
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert Hyperion pools")?;
 
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection for bucket data: {}", e),
-            }
-        })?;
+        info!("🏊 Registered {} new Hyperion pools", inserted);
+        Ok(())
+    }
 
-        info!("🪣 Upserting {} bucket records", bucket_data.len());
+    async fn load_hyperion_pools(&self) -> Result<Vec<HyperionPool>, ProcessorError> {
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for Hyperion pools")?;
 
-        for record in &bucket_data {
-            let zero_decimal = BigDecimal::zero();
-            let batch_volume = record.volume.as_ref().unwrap_or(&zero_decimal);
-            
-            // Get current volume first
-            let current_data = coin_volume_buckets::table
-                .filter(coin_volume_buckets::coin.eq(&record.coin))
-                .filter(coin_volume_buckets::bucket_start.eq(&record.bucket_start))
-                .first::<crate::db::common::models::coin_volume_models::CoinVolumeBucket>(&mut conn)
-                .await
-                .optional()
-                .map_err(|e| ProcessorError::ProcessError {
-                    message: format!("Failed to get current bucket data for {}: {}", record.coin, e),
-                })?;
+        hyperion_pools::table
+            .load::<HyperionPool>(&mut conn)
+            .await
+            .processor_err("Failed to load Hyperion pools")
+    }
 
-            let current_volume = if let Some(data) = current_data {
-                data.volume.unwrap_or_else(|| zero_decimal.clone())
-            } else {
-                zero_decimal.clone()
-            };
-            
-            // Accumulate volume
-            let new_volume = &current_volume + batch_volume;
-            
-            match diesel::insert_into(coin_volume_buckets::table)
-                .values(&NewCoinVolumeBucket {
-                    coin: record.coin.clone(),
-                    bucket_start: record.bucket_start,
-                    bucket_end: record.bucket_end,
-                    volume: Some(new_volume.clone()),
-                })
-                .on_conflict((coin_volume_buckets::coin, coin_volume_buckets::bucket_start))
-                .do_update()
-                .set((
-                    coin_volume_buckets::volume.eq(excluded(coin_volume_buckets::volume)),
-                    coin_volume_buckets::bucket_end.eq(excluded(coin_volume_buckets::bucket_end)),
-                    coin_volume_buckets::inserted_at.eq(diesel::dsl::now)
-                ))
-                .execute(&mut conn)
-                .await
-            {
-                Ok(_) => {
-                    info!("✅ Updated bucket: {} {} - {} (batch: +{}, total: {})", 
-                        record.coin,
-                        record.bucket_start.format("%Y-%m-%d %H:%M:%S"), 
-                        record.bucket_end.format("%Y-%m-%d %H:%M:%S"),
-                        batch_volume, new_volume);
-                },
-                Err(e) => {
-                    error!("❌ Failed to upsert bucket for {}: {}", record.coin, e);
-                    return Err(ProcessorError::ProcessError {
-                        message: format!("Bucket upsert failed: {}", e),
-                    });
-                }
-            }
-        }
+    /// Store Cellana pools from PoolCreatedEvents; a pool is only ever created once
+    async fn insert_cellana_pools(conn: &mut MyDbConnection, cellana_pools_data: Vec<NewCellanaPool>) -> Result<(), ProcessorError> {
+        let inserted = diesel::insert_into(cellana_pools::table)
+            .values(&cellana_pools_data)
+            .on_conflict(cellana_pools::pool_address)
+            .do_nothing()
+            .execute(conn)
+            .await
+            .processor_err("Failed to insert Cellana pools")?;
 
-        info!("✅ Successfully processed {} bucket records", bucket_data.len());
-        
+        info!("🏊 Registered {} new Cellana pools", inserted);
         Ok(())
     }
 
-    /// Query coin volume buckets with proper ordering
-    pub async fn get_coin_volume_buckets_ordered(&self) -> Result<Vec<CoinVolumeBucket>, ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection: {}", e),
-            }
-        })?;
+    async fn load_cellana_pools(&self) -> Result<Vec<CellanaPool>, ProcessorError> {
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for Cellana pools")?;
 
-        let buckets = coin_volume_buckets::table
-            .order_by((
-                coin_volume_buckets::coin.asc(),
-                coin_volume_buckets::bucket_start.asc()
-            ))
-            .load::<CoinVolumeBucket>(&mut conn)
+        cellana_pools::table
+            .load::<CellanaPool>(&mut conn)
             .await
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to query coin volume buckets: {}", e),
-            })?;
-
-        info!("📊 Retrieved {} coin volume buckets (ordered by coin, bucket_start)", buckets.len());
-        
-        Ok(buckets)
+            .processor_err("Failed to load Cellana pools")
     }
 
     /// Query coin volume buckets for a specific coin with proper ordering
     pub async fn get_coin_volume_buckets_for_coin(&self, coin_name: &str) -> Result<Vec<CoinVolumeBucket>, ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection: {}", e),
-            }
-        })?;
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
 
         let buckets = coin_volume_buckets::table
             .filter(coin_volume_buckets::coin.eq(coin_name))
+            .filter(coin_volume_buckets::protocol.eq(ALL_PROTOCOLS))
             .order_by(coin_volume_buckets::bucket_start.asc())
             .load::<CoinVolumeBucket>(&mut conn)
             .await
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to query coin volume buckets for {}: {}", coin_name, e),
-            })?;
+            .processor_err(&format!("Failed to query coin volume buckets for {}", coin_name))?;
 
         info!("📊 Retrieved {} coin volume buckets for {} (ordered by bucket_start)", buckets.len(), coin_name);
         
@@ -768,32 +1771,219 @@ impl TasmilProcessor {
 
     /// Query recent coin volume buckets (last N hours) with proper ordering
     pub async fn get_recent_coin_volume_buckets(&self, hours: i32) -> Result<Vec<CoinVolumeBucket>, ProcessorError> {
-        let mut conn = self.connection_pool.get().await.map_err(|e| {
-            ProcessorError::ProcessError {
-                message: format!("Failed to get database connection: {}", e),
-            }
-        })?;
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
 
         let cutoff_time = Utc::now() - Duration::hours(hours as i64);
         let cutoff_naive = cutoff_time.naive_utc();
 
         let buckets = coin_volume_buckets::table
             .filter(coin_volume_buckets::bucket_start.ge(cutoff_naive))
+            .filter(coin_volume_buckets::protocol.eq(ALL_PROTOCOLS))
             .order_by((
                 coin_volume_buckets::coin.asc(),
                 coin_volume_buckets::bucket_start.asc()
             ))
             .load::<CoinVolumeBucket>(&mut conn)
             .await
-            .map_err(|e| ProcessorError::ProcessError {
-                message: format!("Failed to query recent coin volume buckets: {}", e),
-            })?;
+            .processor_err("Failed to query recent coin volume buckets")?;
 
         info!("📊 Retrieved {} recent coin volume buckets (last {}h, ordered by coin, bucket_start)", 
             buckets.len(), hours);
         
         Ok(buckets)
     }
+
+    /// Volume time series for `coin` at any granularity that divides, or is a multiple of, the
+    /// bucket size. Finer points split bucket volume uniformly; coarser points sum buckets.
+    /// With no coin, each point adds up every coin's bucket volume as stored, in its own units.
+    pub async fn get_volume_time_series(
+        &self,
+        coin: Option<&str>,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+        granularity_minutes: u32,
+    ) -> Result<Vec<VolumePoint>, ProcessorError> {
+        let bucket_minutes = self.volume_calculator.bucket_duration_minutes();
+        if granularity_minutes == 0
+            || (bucket_minutes % granularity_minutes != 0 && granularity_minutes % bucket_minutes != 0)
+        {
+            return Err(ProcessorError::ProcessError {
+                message: format!(
+                    "granularity_minutes must divide or be a multiple of the {} minute bucket size (got {})",
+                    bucket_minutes, granularity_minutes
+                ),
+            });
+        }
+        if to <= from || to - from > Duration::days(MAX_TIME_SERIES_RANGE_DAYS) {
+            return Err(ProcessorError::ProcessError {
+                message: format!(
+                    "Invalid time series range {} - {}: must be non-empty and at most {} days",
+                    from, to, MAX_TIME_SERIES_RANGE_DAYS
+                ),
+            });
+        }
+
+        self.load_volume_points(coin, from, to, granularity_minutes).await
+    }
+
+    /// Volume of `coin` over the last 24h in `interval_minutes` points, at most
+    /// `MAX_TIME_SERIES_POINTS` of them. Unlike `get_volume_time_series`, the interval need not
+    /// line up with the bucket size.
+    pub async fn get_volume_time_series_by_coin(
+        &self,
+        coin: &str,
+        interval_minutes: u32,
+    ) -> Result<Vec<VolumeTimeSeries>, ProcessorError> {
+        validate_time_series_interval(interval_minutes)?;
+
+        let now = Utc::now().naive_utc();
+        let points = self.load_volume_points(Some(coin), now - Duration::hours(24), now, interval_minutes).await?;
+        Ok(last_time_series_points(points))
+    }
+
+    /// `interpolate_buckets` over the all-protocol buckets of `coin` (every coin when `None`)
+    async fn load_volume_points(
+        &self,
+        coin: Option<&str>,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+        granularity_minutes: u32,
+    ) -> Result<Vec<VolumePoint>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let mut query = coin_volume_buckets::table
+            .filter(coin_volume_buckets::protocol.eq(ALL_PROTOCOLS))
+            .filter(coin_volume_buckets::bucket_end.gt(from))
+            .filter(coin_volume_buckets::bucket_start.lt(to))
+            .order_by(coin_volume_buckets::bucket_start.asc())
+            .into_boxed();
+        if let Some(coin) = coin {
+            query = query.filter(coin_volume_buckets::coin.eq(coin));
+        }
+        let coin = coin.unwrap_or("all coins");
+        let buckets = query
+            .load::<CoinVolumeBucket>(&mut conn)
+            .await
+            .processor_err(&format!("Failed to query coin volume buckets for {}", coin))?;
+
+        let points = interpolate_buckets(&buckets, from, to, Duration::minutes(granularity_minutes as i64));
+
+        info!("📈 Built {} volume points for {} from {} buckets ({}m granularity)",
+            points.len(), coin, buckets.len(), granularity_minutes);
+
+        Ok(points)
+    }
+
+    /// Percentage change in a coin's volume between the last two complete `window_minutes` windows
+    pub async fn get_volume_change_rate(
+        &self,
+        coin: &str,
+        protocol: &str,
+        window_minutes: u32,
+    ) -> Result<Option<BigDecimal>, ProcessorError> {
+        load_volume_change_rate(self.pools.read_pool(), coin, protocol, window_minutes, Utc::now().naive_utc()).await
+    }
+
+    /// 24h volume of `pair` (e.g. "APT/USDC") on every protocol that trades it
+    pub async fn get_pair_competition(&self, pair: &str) -> Result<Vec<PairProtocolVolume>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let volumes = pair_volume_by_protocol::table
+            .filter(pair_volume_by_protocol::pair.eq(pair))
+            .order_by(pair_volume_by_protocol::protocol.asc())
+            .load::<PairProtocolVolume>(&mut conn)
+            .await
+            .processor_err(&format!("Failed to query pair volumes for {}", pair))?;
+
+        debug!("🔀 Retrieved {} protocol volumes for {}", volumes.len(), pair);
+        Ok(volumes)
+    }
+
+    /// Hourly `apt_data` snapshots of `protocol` from the last `hours` hours, oldest first
+    pub async fn get_hourly_volume_trend(&self, protocol: &str, hours: i32) -> Result<Vec<HourlySnapshot>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let snapshots = apt_data_hourly_snapshots::table
+            .filter(apt_data_hourly_snapshots::protocol.eq(protocol))
+            .filter(apt_data_hourly_snapshots::snapshot_hour.ge(Utc::now() - Duration::hours(hours as i64)))
+            .order_by(apt_data_hourly_snapshots::snapshot_hour.asc())
+            .load::<HourlySnapshot>(&mut conn)
+            .await
+            .processor_err(&format!("Failed to query hourly volume trend for {}", protocol))?;
+
+        debug!("📸 Retrieved {} hourly snapshots for {} (last {}h)", snapshots.len(), protocol, hours);
+        Ok(snapshots)
+    }
+
+    /// Most recent `batch_statistics` rows, newest first
+    pub async fn get_batch_statistics(&self, limit: i32) -> Result<Vec<BatchStatistic>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let statistics = batch_statistics::table
+            .order_by(batch_statistics::id.desc())
+            .limit(limit as i64)
+            .load::<BatchStatistic>(&mut conn)
+            .await
+            .processor_err("Failed to query batch statistics")?;
+
+        debug!("⏱️ Retrieved {} batch statistics", statistics.len());
+        Ok(statistics)
+    }
+
+    /// Rank individual protocols (excluding the aggregated "aptos" row) by a 24h metric, highest first
+    pub async fn get_protocol_ranking(&self, metric: VolumeMetric) -> Result<Vec<ProtocolRanking>, ProcessorError> {
+        let mut conn = self.pools.read_pool().get().await.processor_err("Failed to get database connection")?;
+
+        let protocols = apt_data::table.filter(apt_data::protocol_name.ne("aptos"));
+        let rows: Vec<(String, Option<BigDecimal>)> = match metric {
+            VolumeMetric::AptVolume => protocols
+                .select((apt_data::protocol_name, apt_data::apt_volume_24h))
+                .order_by(apt_data::apt_volume_24h.desc().nulls_last())
+                .load(&mut conn)
+                .await,
+            VolumeMetric::UsdcVolume => protocols
+                .select((apt_data::protocol_name, apt_data::usdc_volume_24h))
+                .order_by(apt_data::usdc_volume_24h.desc().nulls_last())
+                .load(&mut conn)
+                .await,
+            VolumeMetric::UsdtVolume => protocols
+                .select((apt_data::protocol_name, apt_data::usdt_volume_24h))
+                .order_by(apt_data::usdt_volume_24h.desc().nulls_last())
+                .load(&mut conn)
+                .await,
+            VolumeMetric::WethVolume => protocols
+                .select((apt_data::protocol_name, apt_data::weth_volume_24h))
+                .order_by(apt_data::weth_volume_24h.desc().nulls_last())
+                .load(&mut conn)
+                .await,
+            // The per-coin fee columns are in different units, so fees are compared in USD
+            VolumeMetric::TotalFees => apt_data_usd::table
+                .filter(apt_data_usd::protocol_name.ne("aptos"))
+                .select((apt_data_usd::protocol_name, apt_data_usd::total_usd_fee.nullable()))
+                .order_by(apt_data_usd::total_usd_fee.desc())
+                .load(&mut conn)
+                .await,
+        }
+        .processor_err(&format!("Failed to query protocol ranking by {:?}", metric))?;
+
+        let ranking = Self::rank_protocols(rows);
+
+        info!("🏆 Retrieved protocol ranking by {:?} ({} protocols)", metric, ranking.len());
+
+        Ok(ranking)
+    }
+
+    /// Number `(protocol, value)` rows already ordered highest first by the query, counting missing values as zero
+    fn rank_protocols(rows: Vec<(String, Option<BigDecimal>)>) -> Vec<ProtocolRanking> {
+        rows.into_iter()
+            .enumerate()
+            .map(|(index, (protocol_name, value))| ProtocolRanking {
+                protocol_name,
+                value: value.unwrap_or_else(BigDecimal::zero),
+                rank: index as i64 + 1,
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -802,64 +1992,638 @@ impl Processable for TasmilProcessor {
     type Output = ();
     type RunType = AsyncRunType;
 
+    #[tracing::instrument(
+        name = "tasmil_batch",
+        skip_all,
+        fields(
+            span_id = tracing::field::Empty,
+            trace_id = tracing::field::Empty,
+            batch_start_version = tracing::field::Empty,
+            batch_end_version = tracing::field::Empty,
+        )
+    )]
     async fn process(
         &mut self,
         item: TransactionContext<Vec<Transaction>>,
     ) -> Result<Option<TransactionContext<()>>, ProcessorError> {
+        // Attach batch identifiers to every log line emitted while processing this batch.
+        // The trace id is the exported OpenTelemetry trace's, so it is only set when OTLP export is on.
+        let span = tracing::Span::current();
+        if let Some(span_id) = span.id() {
+            span.record("span_id", span_id.into_u64());
+        }
+        let span_context = span.context().span().span_context().clone();
+        if span_context.is_valid() {
+            span.record("trace_id", span_context.trace_id().to_string().as_str());
+        }
+        span.record("batch_start_version", item.metadata.start_version);
+        span.record("batch_end_version", item.metadata.end_version);
+
         info!(
             "🔥 TasmilProcessor processing batch: versions [{}, {}], {} transactions",
             item.metadata.start_version, item.metadata.end_version, item.data.len()
         );
 
-        // Cleanup old data (older than 24 hours) FIRST before processing new data
-        self.cleanup_old_data().await?;
+        let started = Instant::now();
+        let TransactionContext { data: transactions, metadata } = item;
+
+        // An upstream retry can deliver a batch that was already written; writing it again
+        // would count its volume twice
+        if !self.dry_run && self.batch_already_processed(&metadata).await? {
+            warn!(
+                "🔁 Versions [{}, {}] are already in batch_statistics, skipping duplicate batch",
+                metadata.start_version, metadata.end_version
+            );
+            return Ok(Some(TransactionContext {
+                data: (),
+                metadata,
+            }));
+        }
 
-        // Calculate volume data using VolumeCalculator (with 24h filtering)
-        let volume_context = match self.volume_calculator.process(item.clone()).await? {
-            Some(ctx) => ctx,
-            None => {
-                info!("📊 No volume data calculated");
-                return Ok(Some(TransactionContext {
-                    data: (),
-                    metadata: item.metadata,
-                }));
-            }
-        };
+        let transaction_count = transactions.len();
+        if transactions.len() > self.max_batch_size {
+            warn!(
+                "⚠️ Batch of {} transactions exceeds max_batch_size {}, processing it in chunks",
+                transactions.len(), self.max_batch_size
+            );
+        }
 
-        // Insert APT data
-        self.upsert_pool_volumes(volume_context.data.apt_data).await?;
+        self.prepare_batch().await?;
 
-        // Insert coin volume data
-        if !volume_context.data.coin_volume_data.is_empty() {
-            self.upsert_coin_volumes(volume_context.data.coin_volume_data).await?;
+        if self.dry_run {
+            for chunk in transactions.chunks(self.max_batch_size) {
+                self.process_transactions(None, chunk.to_vec(), &metadata).await?;
+            }
+            return Ok(Some(TransactionContext {
+                data: (),
+                metadata,
+            }));
         }
 
-        // Insert bucket data
-        if !volume_context.data.coin_volume_buckets.is_empty() {
-            self.upsert_coin_volume_buckets(volume_context.data.coin_volume_buckets).await?;
+        // Every chunk's writes and the batch_statistics row share one transaction, so the batch is
+        // only recorded as processed if all of its volumes were stored, and a failed batch leaves
+        // nothing behind to be counted again on retry
+        let pool = self.pools.write_pool.clone();
+        let mut conn = pool.get().await.processor_err("Failed to get database connection for volume upserts")?;
+        let batch_metadata = &metadata;
+        let stats = conn.transaction::<_, TransactionError, _>(|conn| {
+            async move {
+                let mut stats = ChunkStats::default();
+                for chunk in transactions.chunks(self.max_batch_size) {
+                    let chunk_stats = self.process_transactions(Some(&mut *conn), chunk.to_vec(), batch_metadata).await?;
+                    stats.swap_event_count += chunk_stats.swap_event_count;
+                    stats.db_write_time += chunk_stats.db_write_time;
+                    stats.new_event_keys.extend(chunk_stats.new_event_keys);
+                }
+                let statistic = Self::batch_statistic(batch_metadata, transaction_count, &stats, started.elapsed());
+                stats.batch_statistic_id = Some(Self::insert_batch_statistic(conn, &statistic).await?);
+                Ok(stats)
+            }
+            .scope_boxed()
+        })
+        .await
+        .processor_err("Batch write transaction failed")?;
+        drop(conn);
+
+        // Only remember events once their volumes are stored, so a failed batch is retried in full
+        if !stats.new_event_keys.is_empty() {
+            let mut deduplicator = self.deduplicator.lock().unwrap();
+            for key in &stats.new_event_keys {
+                deduplicator.insert(key);
+            }
         }
 
         // Send notification
         if let Err(e) = self.sender.send(format!(
             "Processed {} transactions (versions {}-{})",
-            item.data.len(),
-            item.metadata.start_version,
-            item.metadata.end_version
+            transaction_count,
+            metadata.start_version,
+            metadata.end_version
         )) {
             warn!("📨 Failed to send notification: {}", e);
         }
 
+        self.record_batch_statistics(&metadata, transaction_count, stats, started.elapsed()).await;
+
         Ok(Some(TransactionContext {
             data: (),
-            metadata: item.metadata,
+            metadata,
         }))
     }
 }
 
+impl TasmilProcessor {
+    /// Cleanup and in-memory refreshes done once per batch before any of its chunks
+    async fn prepare_batch(&mut self) -> Result<(), ProcessorError> {
+        // Cleanup old data (older than 24 hours) FIRST before processing new data
+        self.cleanup_old_data().await?;
+
+        // Refresh runtime fee overrides; keep the previous ones if the table can't be read
+        match ProtocolConfigLoader::load(&self.pools.write_pool).await {
+            Ok(fee_overrides) => self.volume_calculator.set_cellana_fee_overrides(fee_overrides),
+            Err(e) => warn!("⚠️ Failed to load protocol_config fee overrides: {:#}", e),
+        }
+
+        // Pools registered by earlier runs, so their pairs are supported before their next InitializePoolEvent or PoolCreatedEvent would be
+        if !self.hyperion_pools_loaded {
+            match self.load_hyperion_pools().await {
+                Ok(pools) => {
+                    info!("🏊 Loaded {} Hyperion pools", pools.len());
+                    self.volume_calculator.register_hyperion_pools(&pools);
+                    self.hyperion_pools_loaded = true;
+                },
+                Err(e) => warn!("⚠️ Failed to load hyperion_pools: {:#}", e),
+            }
+        }
+        if !self.cellana_pools_loaded {
+            match self.load_cellana_pools().await {
+                Ok(pools) => {
+                    info!("🏊 Loaded {} Cellana pools", pools.len());
+                    self.volume_calculator.register_cellana_pools(&pools);
+                    self.cellana_pools_loaded = true;
+                },
+                Err(e) => warn!("⚠️ Failed to load cellana_pools: {:#}", e),
+            }
+        }
+        // DEX modules learned by earlier runs, from genesis or trusted deployers
+        if !self.deployed_contracts_loaded {
+            match self.load_deployed_contracts().await {
+                Ok(contracts) => {
+                    info!("📜 Loaded {} deployed DEX contracts", contracts.len());
+                    self.volume_calculator.register_deployed_contracts(&contracts);
+                    self.deployed_contracts_loaded = true;
+                },
+                Err(e) => warn!("⚠️ Failed to load deployed_contracts: {:#}", e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute volumes for `transactions`, all or part of the batch described by `metadata`, and
+    /// write them on `conn`, which the caller holds inside the batch's transaction. With no
+    /// connection (dry run) the rows are logged instead.
+    async fn process_transactions(
+        &mut self,
+        conn: Option<&mut MyDbConnection>,
+        transactions: Vec<Transaction>,
+        metadata: &TransactionMetadata,
+    ) -> Result<ChunkStats, ProcessorError> {
+        // Drop swap events counted in earlier batches (e.g. replays after a restart)
+        let (transactions, new_event_keys) = self.filter_duplicate_events(transactions);
+        let volume_input = TransactionContext {
+            data: transactions,
+            metadata: metadata.clone(),
+        };
+
+        let Some(conn) = conn else {
+            if let Some(volume_context) = self.volume_calculator.process(volume_input).await? {
+                Self::log_dry_run(&volume_context.data, metadata);
+            }
+            return Ok(ChunkStats::default());
+        };
+
+        // Calculate volume data using VolumeCalculator (with 24h filtering)
+        let mut streamed_records = 0;
+        let volume_result = if self.streaming {
+            // Protocol rows are upserted on the batch's connection as they arrive, so they
+            // commit or roll back with the rest of the batch
+            let (apt_data_tx, mut apt_data_rx) = tokio::sync::mpsc::channel::<NewAptData>(5);
+            let writer = async {
+                let mut written = 0;
+                while let Some(record) = apt_data_rx.recv().await {
+                    let current_volumes = Self::get_current_volumes_batch(conn, &[record.protocol_name.as_str()]).await?;
+                    let current = current_volumes.get(&record.protocol_name).cloned().unwrap_or_default();
+                    Self::upsert_protocol_volume(conn, &record, &current).await?;
+                    written += 1;
+                }
+                Ok::<usize, ProcessorError>(written)
+            };
+            let (volume_result, written) = tokio::join!(
+                self.volume_calculator.process_streaming(volume_input, apt_data_tx),
+                writer
+            );
+            streamed_records = written?;
+            if streamed_records > 0 {
+                info!("✅ Streamed {} pool records", streamed_records);
+            }
+            volume_result?
+        } else {
+            self.volume_calculator.process(volume_input).await?
+        };
+
+        let volume_context = match volume_result {
+            Some(ctx) => ctx,
+            None => {
+                info!("📊 No volume data calculated");
+                return Ok(ChunkStats::default());
+            }
+        };
+
+        // Every counted swap adds one to its protocol's trade count
+        let swap_event_count = volume_context.data.apt_data.iter().map(|record| record.trade_count).sum();
+        let db_write_started = Instant::now();
+
+        // Store per-protocol implied prices for cross-source comparison; stored first so
+        // this batch's prices are used for the USD volumes below
+        if !volume_context.data.coin_prices.is_empty() {
+            Self::insert_coin_prices(conn, &volume_context.data.coin_prices).await?;
+            // Values swaps without a stablecoin side in the per-pair USD volume metric
+            self.volume_calculator.update_coin_prices(&volume_context.data.coin_prices);
+        }
+
+        // Everything below is written on the batch's connection, so it commits together with the
+        // batch_statistics row; the daily candles and VWAPs are additive and must not be applied twice.
+        // In streaming mode the protocol rows are already written, so only the aggregate is refreshed here.
+        Self::upsert_protocol_fees(conn, volume_context.data.protocol_fees).await?;
+        if !self.streaming {
+            Self::upsert_pool_volumes(conn, volume_context.data.apt_data).await?;
+        } else if streamed_records > 0 {
+            Self::upsert_aptos_aggregated_data(conn).await?;
+        }
+        Self::upsert_coin_volumes(conn, volume_context.data.coin_volume_data).await?;
+        let bucket_duration_seconds = self.volume_calculator.bucket_duration_minutes() as i64 * 60;
+        Self::upsert_coin_volume_buckets(conn, volume_context.data.coin_volume_buckets, bucket_duration_seconds).await?;
+        Self::upsert_pair_volumes(conn, volume_context.data.pair_volumes).await?;
+        Self::upsert_usd_volumes(conn).await?;
+
+        // Insert daily candle data
+        if !volume_context.data.coin_volume_daily_buckets.is_empty() {
+            Self::upsert_coin_volume_daily_buckets(conn, volume_context.data.coin_volume_daily_buckets).await?;
+        }
+
+        // Volume-weighted prices per protocol, pair and bucket
+        if !volume_context.data.vwap_buckets.is_empty() {
+            Self::upsert_vwap_buckets(conn, volume_context.data.vwap_buckets).await?;
+        }
+
+        // Record swaps that were excluded from volume as anomalous
+        if !volume_context.data.anomalous_swaps.is_empty() {
+            Self::insert_anomalous_swaps(conn, volume_context.data.anomalous_swaps).await?;
+        }
+
+        // Store block metadata for chain health monitoring
+        if !volume_context.data.chain_metrics.is_empty() {
+            Self::insert_chain_metrics(conn, volume_context.data.chain_metrics).await?;
+        }
+
+        // Record DEX contracts found in the genesis write set
+        if !volume_context.data.deployed_contracts.is_empty() {
+            Self::insert_deployed_contracts(conn, volume_context.data.deployed_contracts).await?;
+        }
+
+        // Register Hyperion pools created in this batch
+        if !volume_context.data.hyperion_pools.is_empty() {
+            Self::insert_hyperion_pools(conn, volume_context.data.hyperion_pools).await?;
+        }
+
+        // Register Cellana pools created in this batch
+        if !volume_context.data.cellana_pools.is_empty() {
+            Self::insert_cellana_pools(conn, volume_context.data.cellana_pools).await?;
+        }
+
+        // Move stored pool rows to their new addresses before this batch's reserves are written
+        if !volume_context.data.pool_migrations.is_empty() {
+            Self::apply_pool_migrations(conn, &volume_context.data.pool_migrations).await?;
+        }
+
+        // Store pool depth for slippage estimates
+        if !volume_context.data.pool_reserves.is_empty() {
+            Self::upsert_pool_reserves(conn, volume_context.data.pool_reserves).await?;
+        }
+
+        // Store Hyperion LP fee growth for LP analytics
+        if !volume_context.data.hyperion_fee_growth.is_empty() {
+            Self::insert_hyperion_fee_growth(conn, volume_context.data.hyperion_fee_growth).await?;
+        }
+
+        // Pairs we could not price, for operators deciding which pools to support next
+        if !volume_context.data.undiscovered_pools.is_empty() {
+            Self::upsert_undiscovered_pools(conn, volume_context.data.undiscovered_pools).await?;
+        }
+
+        let db_write_time = db_write_started.elapsed();
+
+        Ok(ChunkStats {
+            swap_event_count,
+            db_write_time,
+            new_event_keys,
+            batch_statistic_id: None,
+        })
+    }
+
+    /// Whether a batch with the same version range was already stored. Only finds batches
+    /// within `BATCH_STATISTICS_RETENTION_DAYS`, which is far longer than any upstream retry.
+    async fn batch_already_processed(&self, metadata: &TransactionMetadata) -> Result<bool, ProcessorError> {
+        let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for duplicate batch check")?;
+        let existing = batch_statistics::table
+            .filter(batch_statistics::start_version.eq(metadata.start_version as i64))
+            .filter(batch_statistics::end_version.eq(metadata.end_version as i64))
+            .select(batch_statistics::id)
+            .first::<i64>(&mut conn)
+            .await
+            .optional()
+            .processor_err("Failed to check batch_statistics for a duplicate batch")?;
+        Ok(existing.is_some())
+    }
+
+    /// `batch_statistics` row for the batch described by `metadata`
+    fn batch_statistic(
+        metadata: &TransactionMetadata,
+        transaction_count: usize,
+        stats: &ChunkStats,
+        processing_time: std::time::Duration,
+    ) -> NewBatchStatistic {
+        NewBatchStatistic {
+            start_version: metadata.start_version as i64,
+            end_version: metadata.end_version as i64,
+            transaction_count: i32::try_from(transaction_count).unwrap_or(i32::MAX),
+            swap_event_count: i32::try_from(stats.swap_event_count).unwrap_or(i32::MAX),
+            processing_time_ms: i32::try_from(processing_time.as_millis()).unwrap_or(i32::MAX),
+            db_write_time_ms: i32::try_from(stats.db_write_time.as_millis()).unwrap_or(i32::MAX),
+        }
+    }
+
+    async fn insert_batch_statistic(conn: &mut MyDbConnection, statistic: &NewBatchStatistic) -> Result<i64, ProcessorError> {
+        diesel::insert_into(batch_statistics::table)
+            .values(statistic)
+            .returning(batch_statistics::id)
+            .get_result::<i64>(conn)
+            .await
+            .processor_err("Failed to insert batch statistics")
+    }
+
+    /// Log the batch to `batch_statistics` and prune rows older than `BATCH_STATISTICS_RETENTION_DAYS`.
+    /// A row already written with the volume upserts gets its final counts and timings.
+    /// Failures are only logged; statistics never fail a batch.
+    async fn record_batch_statistics(
+        &self,
+        metadata: &TransactionMetadata,
+        transaction_count: usize,
+        stats: ChunkStats,
+        processing_time: std::time::Duration,
+    ) {
+        let statistic = Self::batch_statistic(metadata, transaction_count, &stats, processing_time);
+
+        let result = async {
+            let mut conn = self.pools.write_pool.get().await.processor_err("Failed to get database connection for batch statistics")?;
+            match stats.batch_statistic_id {
+                Some(id) => {
+                    diesel::update(batch_statistics::table.filter(batch_statistics::id.eq(id)))
+                        .set((
+                            batch_statistics::swap_event_count.eq(statistic.swap_event_count),
+                            batch_statistics::processing_time_ms.eq(statistic.processing_time_ms),
+                            batch_statistics::db_write_time_ms.eq(statistic.db_write_time_ms),
+                        ))
+                        .execute(&mut conn)
+                        .await
+                        .processor_err("Failed to update batch statistics")?;
+                },
+                None => {
+                    Self::insert_batch_statistic(&mut conn, &statistic).await?;
+                },
+            }
+            diesel::delete(batch_statistics::table)
+                .filter(batch_statistics::created_at.lt(Utc::now() - Duration::days(BATCH_STATISTICS_RETENTION_DAYS)))
+                .execute(&mut conn)
+                .await
+                .processor_err("Failed to prune batch statistics")
+        }
+        .await;
+
+        match result {
+            Ok(pruned) if pruned > 0 => debug!("🧹 Pruned {} batch statistics rows", pruned),
+            Ok(_) => {},
+            Err(e) => warn!("⚠️ Failed to record batch statistics: {:#}", e),
+        }
+    }
+}
+
+impl TasmilProcessor {
+    /// Log the rows a batch would have written in place of the upserts
+    fn log_dry_run(data: &VolumeData, metadata: &TransactionMetadata) {
+        info!(
+            "🧪 Dry run for versions {}-{}: skipping all database writes",
+            metadata.start_version, metadata.end_version
+        );
+        for record in &data.apt_data {
+            info!(
+                "🧪 Would upsert apt_data for {}: {} APT, {} USDC, {} USDT, {} WETH volume, {} trades",
+                record.protocol_name,
+                record.apt_volume_24h.clone().unwrap_or_default(),
+                record.usdc_volume_24h.clone().unwrap_or_default(),
+                record.usdt_volume_24h.clone().unwrap_or_default(),
+                record.weth_volume_24h.clone().unwrap_or_default(),
+                record.trade_count
+            );
+        }
+        if !data.apt_data.is_empty() {
+            info!("🧪 Would refresh the 'aptos' aggregated row");
+        }
+        for record in &data.coin_volume_data {
+            info!(
+                "🧪 Would upsert coin_volume_24h for {}: buy {}, sell {}",
+                record.coin,
+                record.buy_volume.clone().unwrap_or_default(),
+                record.sell_volume.clone().unwrap_or_default()
+            );
+        }
+        info!(
+            "🧪 Would also write {} protocol fee, {} bucket, {} daily bucket, {} pair volume, {} VWAP, {} price, {} anomalous swap, {} pool reserve, {} Hyperion pool, {} Cellana pool and {} chain metric records",
+            data.protocol_fees.len(),
+            data.coin_volume_buckets.len(),
+            data.coin_volume_daily_buckets.len(),
+            data.pair_volumes.len(),
+            data.vwap_buckets.len(),
+            data.coin_prices.len(),
+            data.anomalous_swaps.len(),
+            data.pool_reserves.len(),
+            data.hyperion_pools.len(),
+            data.cellana_pools.len(),
+            data.chain_metrics.len()
+        );
+    }
+}
+
+impl Drop for TasmilProcessor {
+    fn drop(&mut self) {
+        if let Some(path) = &self.dedup_state_path {
+            let deduplicator = self.deduplicator.lock().unwrap_or_else(|e| e.into_inner());
+            if let Err(e) = deduplicator.save(path) {
+                error!("❌ Failed to save dedup state to {}: {:?}", path.display(), e);
+            }
+        }
+    }
+}
+
 impl AsyncStep for TasmilProcessor {}
 
 impl NamedStep for TasmilProcessor {
     fn name(&self) -> String {
         "TasmilProcessor".to_string()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processors::events::bucket_calculator::{BucketCalculator, CoinVolumeData, SwapEventData};
+
+    #[test]
+    fn test_consecutive_batches_hit_the_same_bucket_with_their_own_volume() {
+        // 2025-06-16 07:00 UTC = 14:00 GMT+7, the start of the [14:00, 16:00] bucket
+        let bucket_start = 1750057200;
+        let now = bucket_start + 3 * 3600;
+        let swap = |timestamp_seconds: i64, volume: u64| SwapEventData {
+            timestamp_seconds,
+            coin_volumes: vec![CoinVolumeData { coin: "APT".to_string(), volume: BigDecimal::from(volume) }],
+        };
+        let calculator = BucketCalculator::new();
+
+        // The upsert adds each record to the stored row, so every batch must land on the same
+        // primary key and carry only the volume of its own swaps
+        let first = calculator.group_swaps_into_buckets(vec![swap(bucket_start + 600, 10), swap(bucket_start + 1800, 5)], now);
+        let second = calculator.group_swaps_into_buckets(vec![swap(bucket_start + 5400, 7)], now);
+        assert_eq!((first.len(), second.len()), (1, 1));
+        let key = |record: &NewCoinVolumeBucket| (record.coin.clone(), record.protocol.clone(), record.bucket_start);
+        assert_eq!(key(&first[0]), key(&second[0]));
+        assert_eq!(first[0].bucket_start, DateTime::from_timestamp(bucket_start, 0).unwrap().naive_utc());
+        assert_eq!(with_batch_volume(&first[0]).volume, Some(BigDecimal::from(15)));
+        assert_eq!(with_batch_volume(&second[0]).volume, Some(BigDecimal::from(7)));
+
+        let empty = NewCoinVolumeBucket { volume: None, ..first[0].clone() };
+        assert_eq!(with_batch_volume(&empty).volume, Some(BigDecimal::zero()));
+    }
+
+    /// Upserts two batches into the same bucket of a real database and checks the stored volume is
+    /// their sum. Run it with `--ignored` and TEST_DATABASE_URL pointing at a Postgres database.
+    #[tokio::test]
+    #[ignore = "needs a Postgres database in TEST_DATABASE_URL"]
+    async fn test_consecutive_batches_accumulate_in_the_stored_bucket() {
+        let database_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set for the bucket upsert check");
+        let pool = crate::utils::database::new_db_pool(&database_url, Some(1)).await.unwrap();
+        crate::utils::database::run_migrations(database_url, pool.clone()).await;
+        let mut conn = pool.get().await.unwrap();
+        // Rolled back when the connection is dropped, so the test leaves no rows behind
+        conn.begin_test_transaction().await.unwrap();
+
+        // 2025-06-16 07:00 UTC = 14:00 GMT+7, the start of the [14:00, 16:00] bucket
+        let bucket_start = 1750057200;
+        let now = bucket_start + 3 * 3600;
+        let swap = |timestamp_seconds: i64, volume: u64| SwapEventData {
+            timestamp_seconds,
+            coin_volumes: vec![CoinVolumeData { coin: "APT".to_string(), volume: BigDecimal::from(volume) }],
+        };
+        let calculator = BucketCalculator::new();
+        let bucket_duration_seconds = calculator.duration_minutes() as i64 * 60;
+        // A protocol no real row uses, so only this test's volume is in the bucket
+        let batch = |swaps: Vec<SwapEventData>| -> Vec<NewCoinVolumeBucket> {
+            calculator
+                .group_swaps_into_buckets(swaps, now)
+                .into_iter()
+                .map(|record| NewCoinVolumeBucket { protocol: "bucket_upsert_test".to_string(), ..record })
+                .collect()
+        };
+
+        TasmilProcessor::upsert_coin_volume_buckets(&mut conn, batch(vec![swap(bucket_start + 600, 10), swap(bucket_start + 1800, 5)]), bucket_duration_seconds)
+            .await
+            .unwrap();
+        TasmilProcessor::upsert_coin_volume_buckets(&mut conn, batch(vec![swap(bucket_start + 5400, 7)]), bucket_duration_seconds)
+            .await
+            .unwrap();
+
+        let stored: Option<BigDecimal> = coin_volume_buckets::table
+            .filter(coin_volume_buckets::coin.eq("APT"))
+            .filter(coin_volume_buckets::protocol.eq("bucket_upsert_test"))
+            .filter(coin_volume_buckets::bucket_start.eq(DateTime::from_timestamp(bucket_start, 0).unwrap().naive_utc()))
+            .select(coin_volume_buckets::volume)
+            .first(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(stored, Some(BigDecimal::from(22)));
+    }
+
+    #[test]
+    fn test_change_rate_compares_overlapping_windows() {
+        let at = |seconds: i64| DateTime::from_timestamp(seconds, 0).unwrap().naive_utc();
+        let bucket = |start: i64, volume: u64| CoinVolumeBucket {
+            coin: "APT".to_string(),
+            bucket_start: at(start),
+            bucket_end: at(start + 7200),
+            volume: Some(BigDecimal::from(volume)),
+            inserted_at: at(start),
+            protocol: ALL_PROTOCOLS.to_string(),
+        };
+        // Two-hour buckets at odd UTC hours, one-hour windows ending on the hour
+        let buckets = [bucket(1750057200, 100), bucket(1750064400, 300)];
+        let end = 1750057200 + 7200 + 3600;
+
+        let previous = overlap_volume(&buckets, at(end - 7200), at(end - 3600));
+        let current = overlap_volume(&buckets, at(end - 3600), at(end));
+        assert_eq!(previous, BigDecimal::from(50));
+        assert_eq!(current, BigDecimal::from(150));
+        assert_eq!(change_rate_pct(&previous, &current), Some(BigDecimal::from(200)));
+        assert_eq!(change_rate_pct(&BigDecimal::zero(), &current), None);
+    }
+
+    #[test]
+    fn test_last_24h_series_interval_and_point_limit() {
+        assert!(validate_time_series_interval(4).is_err());
+        assert!(validate_time_series_interval(1441).is_err());
+        assert!(validate_time_series_interval(7).is_ok());
+
+        let at = |seconds: i64| DateTime::from_timestamp(seconds, 0).unwrap().naive_utc();
+        // One two-hour bucket, and a `now` that is not on a 5-minute boundary
+        let bucket_start = 1750057200;
+        let buckets = [CoinVolumeBucket {
+            coin: "APT".to_string(),
+            bucket_start: at(bucket_start),
+            bucket_end: at(bucket_start + 7200),
+            volume: Some(BigDecimal::from(240)),
+            inserted_at: at(bucket_start),
+            protocol: ALL_PROTOCOLS.to_string(),
+        }];
+        let now = at(bucket_start + 7200 + 90);
+
+        let last_24h_points = |interval_minutes: i64| {
+            last_time_series_points(interpolate_buckets(&buckets, now - Duration::hours(24), now, Duration::minutes(interval_minutes)))
+        };
+
+        let five_minute = last_24h_points(5);
+        assert_eq!(five_minute.len(), MAX_TIME_SERIES_POINTS);
+        assert_eq!(five_minute.iter().map(|point| point.volume.clone()).sum::<BigDecimal>(), BigDecimal::from(240));
+        assert!(five_minute.iter().filter(|point| !point.volume.is_zero()).all(|point| point.volume == BigDecimal::from(10)));
+
+        let four_hour = last_24h_points(240);
+        assert_eq!(four_hour.iter().map(|point| point.volume.clone()).sum::<BigDecimal>(), BigDecimal::from(240));
+    }
+
+    #[test]
+    fn test_hourly_snapshot_runs_at_top_of_next_hour() {
+        // 2025-06-16 07:59:30 UTC
+        let now = DateTime::from_timestamp(1750060770, 0).unwrap();
+        assert_eq!(hour_start(now), DateTime::from_timestamp(1750057200, 0).unwrap());
+        assert_eq!(duration_until_next_hour(now), std::time::Duration::from_secs(30));
+
+        // Exactly on the hour waits a full hour rather than snapshotting twice
+        let on_the_hour = DateTime::from_timestamp(1750057200, 0).unwrap();
+        assert_eq!(hour_start(on_the_hour), on_the_hour);
+        assert_eq!(duration_until_next_hour(on_the_hour), std::time::Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_protocol_ranking_by_usd_fees() {
+        let usd = |value: &str| Some(value.parse::<BigDecimal>().unwrap());
+        // In the order `ORDER BY ... DESC NULLS LAST` returns them
+        let rows = vec![
+            ("cellana".to_string(), usd("140")),
+            ("thala".to_string(), usd("12.5")),
+            ("sushiswap".to_string(), usd("0.75")),
+            ("liquidswap".to_string(), None),
+        ];
+
+        let ranking = TasmilProcessor::rank_protocols(rows);
+        let order: Vec<(&str, i64)> = ranking.iter().map(|r| (r.protocol_name.as_str(), r.rank)).collect();
+        assert_eq!(order, vec![("cellana", 1), ("thala", 2), ("sushiswap", 3), ("liquidswap", 4)]);
+        assert_eq!(ranking[3].value, BigDecimal::zero());
+    }
+}
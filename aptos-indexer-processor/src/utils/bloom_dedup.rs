@@ -0,0 +1,223 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-batch duplicate detection for swap events.
+//!
+//! A Bloom filter keyed by `(transaction_version, event_index)` remembers which events have
+//! already been counted. False positives (an unseen event reported as seen) are bounded by the
+//! configured rate; false negatives cannot happen for recent events.
+//!
+//! A single filter's false positive rate keeps climbing as events are added, until real swaps
+//! are dropped as duplicates. Events are therefore recorded in generations: once the current
+//! filter holds `expected_events`, it becomes the previous generation and a fresh one is started.
+//! Both generations are checked, so at least the last `expected_events` events are always
+//! remembered, far more than any replay after a restart re-delivers.
+
+use anyhow::{bail, Context, Result};
+use bloomfilter::Bloom;
+use std::{
+    fs,
+    io::{Read, Write},
+    path::Path,
+};
+use tracing::info;
+
+/// Number of events each filter generation is sized for before it is rotated out
+pub const DEFAULT_EXPECTED_EVENTS: usize = 5_000_000;
+/// Target false positive rate of each generation (0.01%)
+pub const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.0001;
+
+const STATE_FILE_MAGIC: &[u8; 4] = b"TBF2";
+// Single-filter format written before generations were added
+const LEGACY_STATE_FILE_MAGIC: &[u8; 4] = b"TBF1";
+
+/// Identifies an event across batches. The index within the transaction is used instead of
+/// the event sequence number, which is 0 for all module events.
+pub type EventKey = (u64, u64);
+
+pub struct BloomDeduplicator {
+    current: Bloom<EventKey>,
+    // Generation rotated out last, still checked so events just before a rotation are remembered
+    previous: Option<Bloom<EventKey>>,
+    // Events recorded in `current`
+    current_events: u64,
+    expected_events: usize,
+    false_positive_rate: f64,
+}
+
+impl Default for BloomDeduplicator {
+    fn default() -> Self {
+        Self::new(DEFAULT_EXPECTED_EVENTS, DEFAULT_FALSE_POSITIVE_RATE)
+    }
+}
+
+impl BloomDeduplicator {
+    pub fn new(expected_events: usize, false_positive_rate: f64) -> Self {
+        Self {
+            current: Bloom::new_for_fp_rate(expected_events, false_positive_rate),
+            previous: None,
+            current_events: 0,
+            expected_events,
+            false_positive_rate,
+        }
+    }
+
+    /// Returns true if the event has (probably) been recorded before
+    pub fn contains(&self, key: &EventKey) -> bool {
+        self.current.check(key) || self.previous.as_ref().is_some_and(|previous| previous.check(key))
+    }
+
+    pub fn insert(&mut self, key: &EventKey) {
+        if self.current_events >= self.expected_events as u64 {
+            let fresh = Bloom::new_for_fp_rate(self.expected_events, self.false_positive_rate);
+            self.previous = Some(std::mem::replace(&mut self.current, fresh));
+            self.current_events = 0;
+            info!("🌸 Rotated dedup filter after {} events", self.expected_events);
+        }
+        self.current.set(key);
+        self.current_events += 1;
+    }
+
+    /// Load a filter saved with `save`, or start empty if the file does not exist
+    pub fn load_or_default(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            info!("🌸 No dedup state at {}, starting with an empty filter", path.display());
+            return Ok(Self::default());
+        }
+
+        let mut bytes = Vec::new();
+        fs::File::open(path)
+            .and_then(|mut file| file.read_to_end(&mut bytes))
+            .with_context(|| format!("Failed to read dedup state from {}", path.display()))?;
+
+        let mut dedup = Self::default();
+        if bytes.len() >= 4 && &bytes[..4] == LEGACY_STATE_FILE_MAGIC {
+            // Its fill is unknown, so keep it only as the previous generation
+            let (bloom, _) = read_filter(&bytes[4..], true)
+                .with_context(|| format!("{} is not a dedup state file", path.display()))?;
+            dedup.previous = Some(bloom);
+            info!("🌸 Loaded single-filter dedup state from {} as the previous generation", path.display());
+            return Ok(dedup);
+        }
+
+        // magic | current_events (u64) | generation count (u8) | current filter | previous filter
+        if bytes.len() < 13 || &bytes[..4] != STATE_FILE_MAGIC {
+            bail!("{} is not a dedup state file", path.display());
+        }
+        dedup.current_events = u64::from_le_bytes(bytes[4..12].try_into().unwrap());
+        let generations = bytes[12];
+        let (current, read) = read_filter(&bytes[13..], false)
+            .with_context(|| format!("Dedup state file {} is truncated", path.display()))?;
+        dedup.current = current;
+        if generations > 1 {
+            let (previous, _) = read_filter(&bytes[13 + read..], false)
+                .with_context(|| format!("Dedup state file {} is truncated", path.display()))?;
+            dedup.previous = Some(previous);
+        }
+
+        info!(
+            "🌸 Loaded dedup state from {} ({} generations, {} events in the current one)",
+            path.display(), generations, dedup.current_events
+        );
+        Ok(dedup)
+    }
+
+    /// Write the filter to `path`, replacing any previous state atomically
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp_path = path.with_extension("tmp");
+
+        let mut file = fs::File::create(&tmp_path)
+            .with_context(|| format!("Failed to create {}", tmp_path.display()))?;
+        file.write_all(STATE_FILE_MAGIC)?;
+        file.write_all(&self.current_events.to_le_bytes())?;
+        file.write_all(&[1 + self.previous.is_some() as u8])?;
+        write_filter(&mut file, &self.current)?;
+        if let Some(previous) = &self.previous {
+            write_filter(&mut file, previous)?;
+        }
+        file.sync_all()?;
+        fs::rename(&tmp_path, path).with_context(|| format!("Failed to move dedup state to {}", path.display()))?;
+
+        info!("🌸 Saved dedup state to {}", path.display());
+        Ok(())
+    }
+}
+
+// bitmap_bits (u64) | k_num (u32) | 4 sip keys (u64) | bitmap_len (u64) | bitmap
+fn write_filter(file: &mut fs::File, bloom: &Bloom<EventKey>) -> Result<()> {
+    let bitmap = bloom.bitmap();
+    file.write_all(&bloom.number_of_bits().to_le_bytes())?;
+    file.write_all(&bloom.number_of_hash_functions().to_le_bytes())?;
+    for (k0, k1) in bloom.sip_keys() {
+        file.write_all(&k0.to_le_bytes())?;
+        file.write_all(&k1.to_le_bytes())?;
+    }
+    file.write_all(&(bitmap.len() as u64).to_le_bytes())?;
+    file.write_all(&bitmap)?;
+    Ok(())
+}
+
+/// Read a filter written by `write_filter`, returning it and the bytes consumed.
+/// `legacy` filters have no bitmap length and run to the end of `bytes`.
+fn read_filter(bytes: &[u8], legacy: bool) -> Result<(Bloom<EventKey>, usize)> {
+    const HEADER_LEN: usize = 8 + 4 + 4 * 8;
+    let header_len = if legacy { HEADER_LEN } else { HEADER_LEN + 8 };
+    if bytes.len() < header_len {
+        bail!("filter header is truncated");
+    }
+    let read_u64 = |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+    let bitmap_bits = read_u64(0);
+    let k_num = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+    let sip_keys = [(read_u64(12), read_u64(20)), (read_u64(28), read_u64(36))];
+    let bitmap_len = if legacy { bytes.len() - header_len } else { read_u64(HEADER_LEN) as usize };
+
+    let bitmap = bytes.get(header_len..header_len.saturating_add(bitmap_len)).context("filter bitmap is truncated")?;
+    if (bitmap.len() as u64) * 8 < bitmap_bits {
+        bail!("filter bitmap is truncated");
+    }
+    Ok((Bloom::from_existing(bitmap, bitmap_bits, k_num, sip_keys), header_len + bitmap_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_reload_keeps_seen_events() {
+        let path = std::env::temp_dir().join(format!("tasmil-bloom-{}.bin", std::process::id()));
+        let mut dedup = BloomDeduplicator::new(2, DEFAULT_FALSE_POSITIVE_RATE);
+        // Rotates after the first two, so the reload covers both generations
+        dedup.insert(&(40, 0));
+        dedup.insert(&(41, 0));
+        dedup.insert(&(42, 0));
+        assert!(dedup.contains(&(42, 0)));
+        assert!(!dedup.contains(&(42, 1)));
+
+        dedup.save(&path).unwrap();
+        let reloaded = BloomDeduplicator::load_or_default(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(reloaded.contains(&(40, 0)));
+        assert!(reloaded.contains(&(42, 0)));
+        assert!(!reloaded.contains(&(42, 1)));
+    }
+
+    #[test]
+    fn test_rotation_forgets_events_two_generations_old() {
+        let mut dedup = BloomDeduplicator::new(100, 0.000001);
+        for version in 0..100 {
+            dedup.insert(&(version, 0));
+        }
+        // The 101st event starts a new generation; the first 100 are still remembered
+        dedup.insert(&(100, 0));
+        assert!(dedup.contains(&(0, 0)));
+        assert!(dedup.contains(&(100, 0)));
+
+        for version in 101..201 {
+            dedup.insert(&(version, 0));
+        }
+        assert!(!dedup.contains(&(0, 0)));
+        assert!(dedup.contains(&(100, 0)));
+        assert!(dedup.contains(&(200, 0)));
+    }
+}
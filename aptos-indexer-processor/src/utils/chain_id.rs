@@ -1,9 +1,100 @@
-use super::database::ArcDbPool;
+use super::{database::ArcDbPool, errors::ProcessorResultExt};
+use crate::db::postgres::schema::ledger_infos;
 use anyhow::Result;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::fmt;
 use tracing::info;
 
-/// Verify the chain id from GRPC (simplified for Tasmil project).
-pub async fn check_or_update_chain_id(grpc_chain_id: i64, _db_pool: ArcDbPool) -> Result<u64> {
+/// The database or stream belongs to a different chain than the one configured
+#[derive(Debug, PartialEq)]
+pub enum ChainIdError {
+    NotRecorded,
+    Mismatch { expected: u64, actual: u64 },
+}
+
+impl fmt::Display for ChainIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainIdError::NotRecorded => write!(f, "no chain ID is recorded in ledger_infos"),
+            ChainIdError::Mismatch { expected, actual } => write!(
+                f,
+                "chain ID {} does not match expected_chain_id {} (is the indexer pointed at the wrong network?)",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChainIdError {}
+
+/// Record the chain id from GRPC in `ledger_infos` on first start, and fail if a
+/// different chain was recorded before.
+pub async fn check_or_update_chain_id(grpc_chain_id: i64, db_pool: ArcDbPool) -> Result<u64> {
+    let mut conn = db_pool.get().await.processor_err("Failed to get database connection for chain ID check")?;
+
+    let stored_chain_id = ledger_infos::table
+        .select(ledger_infos::chain_id)
+        .first::<i64>(&mut conn)
+        .await
+        .optional()
+        .processor_err("Failed to load chain ID from ledger_infos")?;
+
+    match stored_chain_id {
+        Some(chain_id) if chain_id != grpc_chain_id => {
+            return Err(ChainIdError::Mismatch {
+                expected: chain_id as u64,
+                actual: grpc_chain_id as u64,
+            }
+            .into());
+        },
+        Some(_) => {},
+        None => {
+            diesel::insert_into(ledger_infos::table)
+                .values(ledger_infos::chain_id.eq(grpc_chain_id))
+                .on_conflict_do_nothing()
+                .execute(&mut conn)
+                .await
+                .processor_err("Failed to record chain ID in ledger_infos")?;
+            info!("📝 Recorded chain ID {} in ledger_infos", grpc_chain_id);
+        },
+    }
+
     info!("✅ Using chain ID: {} for Tasmil indexer", grpc_chain_id);
     Ok(grpc_chain_id as u64)
 }
+
+/// Fail unless the chain id in `ledger_infos` is `expected_chain_id`
+pub async fn verify_chain_id(pool: &ArcDbPool, expected_chain_id: u64) -> Result<()> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for chain ID check")?;
+
+    let chain_id = ledger_infos::table
+        .select(ledger_infos::chain_id)
+        .first::<i64>(&mut conn)
+        .await
+        .optional()
+        .processor_err("Failed to load chain ID from ledger_infos")?
+        .ok_or(ChainIdError::NotRecorded)?;
+
+    if chain_id as u64 != expected_chain_id {
+        return Err(ChainIdError::Mismatch {
+            expected: expected_chain_id,
+            actual: chain_id as u64,
+        }
+        .into());
+    }
+
+    info!("✅ Chain ID {} matches expected_chain_id", chain_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mismatch_names_both_chains() {
+        let error = ChainIdError::Mismatch { expected: 1, actual: 2 };
+        assert!(error.to_string().starts_with("chain ID 2 does not match expected_chain_id 1"));
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Swap event types learned from module deployments.
+//!
+//! When a transaction publishes a module that looks like a tracked DEX contract (same
+//! module name, declaring the swap event struct with the fields the extractor reads), the
+//! module's address is recorded so its swap events are routed without a hardcoded
+//! contract address, e.g. on testnet where the mainnet constants do not apply.
+//!
+//! Anyone can publish a module with the same names, so outside genesis only modules published
+//! at a configured trusted deployer address are learned. Registrations are stored in
+//! `deployed_contracts` and restored from it on startup.
+
+use crate::db::common::models::deployed_contract_models::DeployedContract;
+use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::WriteModule;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+/// A protocol's swap event, identified by the module that declares it
+struct TrackedModule {
+    protocol: &'static str,
+    module_name: &'static str,
+    swap_event: &'static str,
+    // Field of the swap event the protocol's extractor requires
+    required_field: &'static str,
+}
+
+const TRACKED_MODULES: [TrackedModule; 5] = [
+    TrackedModule { protocol: "cellana", module_name: "liquidity_pool", swap_event: "SwapEvent", required_field: "from_token" },
+    TrackedModule { protocol: "thala", module_name: "pool", swap_event: "SwapEvent", required_field: "idx_in" },
+    TrackedModule { protocol: "sushiswap", module_name: "swap", swap_event: "SwapEvent", required_field: "amount_x_in" },
+    TrackedModule { protocol: "liquidswap", module_name: "liquidity_pool", swap_event: "SwapEvent", required_field: "x_in" },
+    TrackedModule { protocol: "hyperion", module_name: "pool_v3", swap_event: "SwapEventV3", required_field: "from_token" },
+];
+
+/// ContractRegistry maps swap event types of deployed DEX modules to their protocol
+#[derive(Clone, Debug, Default)]
+pub struct ContractRegistry {
+    // Swap event type without generics -> protocol name
+    swap_event_types: HashMap<String, &'static str>,
+    // Addresses (short form) whose module deployments are trusted outside genesis
+    trusted_deployers: HashSet<String>,
+}
+
+impl ContractRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also learn modules published after genesis at one of `addresses`
+    pub fn with_trusted_deployers(addresses: &[String]) -> Self {
+        Self {
+            trusted_deployers: addresses.iter().map(|address| short_address(address)).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Record the module's swap event if it matches a tracked DEX module and was published at
+    /// a trusted deployer address. Returns the protocol it was registered for.
+    pub fn register_from_write_module(&mut self, change: &WriteModule) -> Option<&'static str> {
+        if !self.trusted_deployers.contains(&short_address(&change.address)) {
+            return None;
+        }
+        self.register_module(change)
+    }
+
+    /// Record a module published by the genesis write set, which only the chain itself can write
+    pub fn register_from_genesis_module(&mut self, change: &WriteModule) -> Option<&'static str> {
+        self.register_module(change)
+    }
+
+    /// Restore a module stored in `deployed_contracts` by an earlier run
+    pub fn register_deployed_contract(&mut self, contract: &DeployedContract) {
+        let Some(tracked) = TRACKED_MODULES
+            .iter()
+            .find(|tracked| tracked.protocol == contract.protocol && tracked.module_name == contract.module_name)
+        else {
+            return;
+        };
+        let event_type = format!("{}::{}::{}", short_address(&contract.address), tracked.module_name, tracked.swap_event);
+        self.swap_event_types.insert(event_type, tracked.protocol);
+    }
+
+    fn register_module(&mut self, change: &WriteModule) -> Option<&'static str> {
+        let abi = change.data.as_ref()?.abi.as_ref()?;
+        let tracked = TRACKED_MODULES.iter().find(|tracked| {
+            tracked.module_name == abi.name
+                && abi.structs.iter().any(|move_struct| {
+                    move_struct.name == tracked.swap_event
+                        && move_struct.fields.iter().any(|field| field.name == tracked.required_field)
+                })
+        })?;
+
+        let event_type = format!("{}::{}::{}", short_address(&change.address), tracked.module_name, tracked.swap_event);
+        if self.swap_event_types.insert(event_type.clone(), tracked.protocol).is_none() {
+            info!("📜 Registered {} swap event {} from module deployment", tracked.protocol, event_type);
+        }
+        Some(tracked.protocol)
+    }
+
+    /// Protocol of a registered swap event type, ignoring its generic parameters
+    pub fn protocol_for_event(&self, type_str: &str) -> Option<&'static str> {
+        let event_type = type_str.split('<').next().unwrap_or(type_str).trim();
+        self.swap_event_types.get(event_type).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.swap_event_types.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.swap_event_types.is_empty()
+    }
+}
+
+/// Address without leading zeros, the form used in event type strings
+fn short_address(address: &str) -> String {
+    let trimmed = address.trim_start_matches("0x").trim_start_matches('0');
+    format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::{
+        MoveModule, MoveModuleBytecode, MoveStruct, MoveStructField,
+    };
+
+    fn write_module(address: &str, module_name: &str, event_field: &str) -> WriteModule {
+        WriteModule {
+            address: address.to_string(),
+            data: Some(MoveModuleBytecode {
+                abi: Some(MoveModule {
+                    address: address.to_string(),
+                    name: module_name.to_string(),
+                    structs: vec![MoveStruct {
+                        name: "SwapEvent".to_string(),
+                        fields: vec![MoveStructField { name: event_field.to_string(), ..Default::default() }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_registers_swap_event_of_deployed_module() {
+        let address = "0x00000000000000000000000000000000000000000000000000000000000abc12";
+        let mut registry = ContractRegistry::with_trusted_deployers(&["0xabc12".to_string(), "0xdef".to_string()]);
+
+        assert_eq!(registry.register_from_write_module(&write_module(address, "swap", "amount_x_in")), Some("sushiswap"));
+        assert_eq!(registry.protocol_for_event("0xabc12::swap::SwapEvent<0x1::aptos_coin::AptosCoin, 0x2::coin::T>"), Some("sushiswap"));
+
+        // Same module name without the fields the extractor needs is ignored
+        assert_eq!(registry.register_from_write_module(&write_module("0xdef", "swap", "amount")), None);
+        assert_eq!(registry.protocol_for_event("0xdef::swap::SwapEvent"), None);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_ignores_lookalike_module_from_untrusted_deployer() {
+        let mut registry = ContractRegistry::with_trusted_deployers(&["0xabc12".to_string()]);
+        let spoof = write_module("0xbad", "liquidity_pool", "from_token");
+
+        assert_eq!(registry.register_from_write_module(&spoof), None);
+        assert_eq!(registry.protocol_for_event("0xbad::liquidity_pool::SwapEvent"), None);
+
+        // The same module in genesis can only have been written by the chain
+        assert_eq!(registry.register_from_genesis_module(&spoof), Some("cellana"));
+        assert_eq!(registry.protocol_for_event("0xbad::liquidity_pool::SwapEvent"), Some("cellana"));
+    }
+
+    #[test]
+    fn test_restores_stored_deployments() {
+        let mut registry = ContractRegistry::new();
+        let contract = |module_name: &str, protocol: &str| DeployedContract {
+            address: "0x0abc12".to_string(),
+            module_name: module_name.to_string(),
+            protocol: protocol.to_string(),
+            inserted_at: chrono::NaiveDateTime::default(),
+        };
+
+        registry.register_deployed_contract(&contract("pool_v3", "hyperion"));
+        // Rows for modules this version no longer tracks are skipped
+        registry.register_deployed_contract(&contract("router", "hyperion"));
+
+        assert_eq!(registry.protocol_for_event("0xabc12::pool_v3::SwapEventV3"), Some("hyperion"));
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -19,7 +19,7 @@ use diesel_async::{
 };
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use futures_util::{future::BoxFuture, FutureExt};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tracing::{info, warn};
 
 pub type Backend = diesel::pg::Pg;
@@ -29,9 +29,48 @@ pub type DbPool = Pool<MyDbConnection>;
 pub type ArcDbPool = Arc<DbPool>;
 pub type DbPoolConnection<'a> = PooledConnection<'a, MyDbConnection>;
 
+/// Primary pool for writes plus an optional read replica for queries
+#[derive(Clone)]
+pub struct MultiPoolConfig {
+    pub write_pool: ArcDbPool,
+    pub read_pool: Option<ArcDbPool>,
+}
+
+impl MultiPoolConfig {
+    pub fn new(write_pool: ArcDbPool, read_pool: Option<ArcDbPool>) -> Self {
+        Self { write_pool, read_pool }
+    }
+
+    /// Pool for SELECT queries, falling back to the write pool when no replica is configured
+    pub fn read_pool(&self) -> &ArcDbPool {
+        self.read_pool.as_ref().unwrap_or(&self.write_pool)
+    }
+}
+
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/db/postgres/migrations");
 
 pub const DEFAULT_MAX_POOL_SIZE: u32 = 150;
+pub const DEFAULT_CONNECTION_TIMEOUT_SECS: u64 = 30;
+
+/// Connection pool settings passed to the bb8 pool builder
+#[derive(Clone, Debug)]
+pub struct DbPoolOptions {
+    pub max_pool_size: u32,
+    pub min_idle: Option<u32>,
+    pub connection_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+}
+
+impl Default for DbPoolOptions {
+    fn default() -> Self {
+        Self {
+            max_pool_size: DEFAULT_MAX_POOL_SIZE,
+            min_idle: None,
+            connection_timeout: Duration::from_secs(DEFAULT_CONNECTION_TIMEOUT_SECS),
+            idle_timeout: None,
+        }
+    }
+}
 
 #[derive(QueryId)]
 #[allow(clippy::too_long_first_doc_paragraph)]
@@ -108,6 +147,17 @@ fn parse_and_clean_db_url(url: &str) -> (String, Option<String>) {
 pub async fn new_db_pool(
     database_url: &str,
     max_pool_size: Option<u32>,
+) -> Result<ArcDbPool, PoolError> {
+    let options = DbPoolOptions {
+        max_pool_size: max_pool_size.unwrap_or(DEFAULT_MAX_POOL_SIZE),
+        ..Default::default()
+    };
+    new_db_pool_with_options(database_url, &options).await
+}
+
+pub async fn new_db_pool_with_options(
+    database_url: &str,
+    options: &DbPoolOptions,
 ) -> Result<ArcDbPool, PoolError> {
     let (_url, cert_path) = parse_and_clean_db_url(database_url);
 
@@ -119,7 +169,10 @@ pub async fn new_db_pool(
         AsyncDieselConnectionManager::<MyDbConnection>::new(database_url)
     };
     let pool = Pool::builder()
-        .max_size(max_pool_size.unwrap_or(DEFAULT_MAX_POOL_SIZE))
+        .max_size(options.max_pool_size)
+        .min_idle(options.min_idle)
+        .connection_timeout(options.connection_timeout)
+        .idle_timeout(options.idle_timeout)
         .build(config)
         .await?;
     Ok(Arc::new(pool))
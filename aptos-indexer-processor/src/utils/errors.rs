@@ -0,0 +1,92 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Error conversion helpers for processor steps.
+//!
+//! `ProcessorError`, `diesel::result::Error` and `bb8::RunError` are all defined in
+//! external crates, so the orphan rule rules out `impl From<_> for ProcessorError`
+//! here. Instead, [`IntoProcessorError`] describes how each database-layer error is
+//! turned into a `ProcessorError`, and [`ProcessorResultExt`] lets call sites attach
+//! a short context message and propagate with `?`:
+//!
+//! ```ignore
+//! let mut conn = self.connection_pool.get().await.processor_err("Failed to get database connection")?;
+//! ```
+//!
+//! `ProcessorError` already implements `std::error::Error`, so `anyhow`'s blanket
+//! `From<E: Error>` impl covers the conversion into `anyhow::Error` and `?` works
+//! in functions returning `anyhow::Result` without any extra glue.
+
+use aptos_indexer_processor_sdk::utils::errors::ProcessorError;
+use bb8::RunError;
+use diesel::result::Error as DieselError;
+use diesel_async::pooled_connection::PoolError;
+
+/// Converts a database-layer error into a `ProcessorError` with a context prefix.
+pub trait IntoProcessorError {
+    fn into_processor_error(self, context: &str) -> ProcessorError;
+}
+
+impl IntoProcessorError for DieselError {
+    fn into_processor_error(self, context: &str) -> ProcessorError {
+        let message = match &self {
+            DieselError::NotFound => format!("{}: record not found", context),
+            DieselError::DatabaseError(kind, info) => {
+                format!("{}: database error ({:?}): {}", context, kind, info.message())
+            },
+            other => format!("{}: {}", context, other),
+        };
+        ProcessorError::ProcessError { message }
+    }
+}
+
+impl IntoProcessorError for RunError<PoolError> {
+    fn into_processor_error(self, context: &str) -> ProcessorError {
+        let message = match &self {
+            RunError::User(e) => format!("{}: {}", context, e),
+            RunError::TimedOut => format!("{}: timed out waiting for a pooled connection", context),
+        };
+        ProcessorError::ProcessError { message }
+    }
+}
+
+/// Error type for `AsyncConnection::transaction` closures. diesel needs `From<DieselError>`
+/// for the rollback path, which `ProcessorError` cannot provide, so both are carried here.
+#[derive(Debug)]
+pub enum TransactionError {
+    Diesel(DieselError),
+    Processor(ProcessorError),
+}
+
+impl From<DieselError> for TransactionError {
+    fn from(e: DieselError) -> Self {
+        TransactionError::Diesel(e)
+    }
+}
+
+impl From<ProcessorError> for TransactionError {
+    fn from(e: ProcessorError) -> Self {
+        TransactionError::Processor(e)
+    }
+}
+
+impl IntoProcessorError for TransactionError {
+    fn into_processor_error(self, context: &str) -> ProcessorError {
+        match self {
+            TransactionError::Diesel(e) => e.into_processor_error(context),
+            // Already carries the context of the step that failed
+            TransactionError::Processor(e) => e,
+        }
+    }
+}
+
+/// Extension for `Result`s carrying a database-layer error.
+pub trait ProcessorResultExt<T> {
+    fn processor_err(self, context: &str) -> Result<T, ProcessorError>;
+}
+
+impl<T, E: IntoProcessorError> ProcessorResultExt<T> for Result<T, E> {
+    fn processor_err(self, context: &str) -> Result<T, ProcessorError> {
+        self.map_err(|e| e.into_processor_error(context))
+    }
+}
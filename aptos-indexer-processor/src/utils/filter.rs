@@ -0,0 +1,30 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transaction filters applied before events are routed to the protocol processors.
+
+/// Framework addresses whose transactions are never DEX swaps.
+/// Their events can still look like DEX events by type string, so they are skipped.
+pub const SKIP_SENDER_ADDRESSES: &[&str] = &["0x1", "0x3", "0x4"];
+
+/// Returns true if `sender` is one of `SKIP_SENDER_ADDRESSES`.
+/// Accepts both the short (`0x1`) and the zero-padded 64 character form.
+pub fn is_skipped_sender(sender: &str) -> bool {
+    let trimmed = sender.trim_start_matches("0x").trim_start_matches('0');
+    SKIP_SENDER_ADDRESSES
+        .iter()
+        .any(|address| address.trim_start_matches("0x") == trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_framework_senders_are_skipped() {
+        assert!(is_skipped_sender("0x1"));
+        assert!(is_skipped_sender("0x0000000000000000000000000000000000000000000000000000000000000003"));
+        assert!(!is_skipped_sender("0x10"));
+        assert!(!is_skipped_sender("0x4a1e0bcd5c10c5b7a4d2b1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f6"));
+    }
+}
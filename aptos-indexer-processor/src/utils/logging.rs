@@ -0,0 +1,96 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Logging setup.
+//!
+//! The output format is picked at startup from the `LOG_FORMAT` environment
+//! variable: `json` produces one JSON object per line (for Datadog, CloudWatch
+//! Logs, etc.), anything else produces human-readable output. `RUST_LOG` still
+//! takes precedence over the default log level.
+//!
+//! When `OTEL_EXPORTER_OTLP_ENDPOINT` is set, spans are also exported over OTLP
+//! (e.g. to Jaeger or an OpenTelemetry collector), giving one trace per batch.
+
+use opentelemetry::{trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry};
+
+pub const LOG_FORMAT_ENV_VAR: &str = "LOG_FORMAT";
+pub const DEFAULT_LOG_LEVEL: &str = "info";
+pub const OTLP_ENDPOINT_ENV_VAR: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+/// `service.name` reported on exported traces
+pub const OTEL_SERVICE_NAME: &str = "tasmil-indexer";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+impl LogFormat {
+    /// Reads `LOG_FORMAT`, defaulting to `Pretty` when unset or unrecognized
+    pub fn from_env() -> Self {
+        match std::env::var(LOG_FORMAT_ENV_VAR) {
+            Ok(value) if value.eq_ignore_ascii_case("json") => LogFormat::Json,
+            _ => LogFormat::Pretty,
+        }
+    }
+}
+
+/// Installs the global tracing subscriber. Must be called once, before any
+/// other subscriber is set, and from within the Tokio runtime when exporting traces.
+pub fn init_logging(log_level: &str, format: LogFormat) {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(log_level));
+
+    let fmt_layer: Box<dyn Layer<Registry> + Send + Sync> = match format {
+        LogFormat::Json => {
+            // Span fields (batch versions, span/trace ids) are emitted with every event
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_current_span(true)
+                .with_span_list(false)
+                .with_file(true)
+                .with_line_number(true)
+                .with_thread_ids(true)
+                .with_target(false)
+                .flatten_event(true)
+                .boxed()
+        },
+        LogFormat::Pretty => tracing_subscriber::fmt::layer().with_target(false).boxed(),
+    };
+
+    let otel_layer = std::env::var(OTLP_ENDPOINT_ENV_VAR)
+        .ok()
+        .and_then(|endpoint| match otlp_tracer_provider(&endpoint) {
+            Ok(provider) => {
+                let tracer = provider.tracer(OTEL_SERVICE_NAME);
+                opentelemetry::global::set_tracer_provider(provider);
+                Some(tracing_opentelemetry::layer().with_tracer(tracer))
+            },
+            Err(e) => {
+                // The subscriber is not installed yet, so this cannot go through tracing
+                eprintln!("Failed to create OTLP exporter for {}: {:#}", endpoint, e);
+                None
+            },
+        });
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer)
+        .with(env_filter)
+        .init();
+}
+
+/// Batch-exports spans to the OTLP gRPC endpoint
+fn otlp_tracer_provider(endpoint: &str) -> anyhow::Result<TracerProvider> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    Ok(TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new([KeyValue::new("service.name", OTEL_SERVICE_NAME)]))
+        .build())
+}
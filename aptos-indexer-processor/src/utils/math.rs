@@ -0,0 +1,65 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Precision limits and zero checks for `BigDecimal` amounts
+
+use bigdecimal::{BigDecimal, Zero};
+use once_cell::sync::Lazy;
+
+/// Shared zero, so sign checks do not build a new `BigDecimal` each time
+static ZERO: Lazy<BigDecimal> = Lazy::new(BigDecimal::zero);
+
+/// Decimal places kept on accumulated volumes and fees, matching the `NUMERIC(38, 18)` columns
+pub const MAX_VOLUME_SCALE: i64 = 18;
+
+/// Round `value` to at most `max_scale` decimal places. Divisions by coin decimals leave long
+/// fractions, and summing them grows the scale with every addition.
+pub fn clamp_precision(value: BigDecimal, max_scale: i64) -> BigDecimal {
+    if scale(&value) <= max_scale {
+        return value;
+    }
+    value.round(max_scale)
+}
+
+/// True when `v` is strictly greater than zero
+pub fn is_positive(v: &BigDecimal) -> bool {
+    v > &*ZERO
+}
+
+/// True when `v` equals zero, at any scale
+pub fn is_zero(v: &BigDecimal) -> bool {
+    v == &*ZERO
+}
+
+fn scale(value: &BigDecimal) -> i64 {
+    value.as_bigint_and_exponent().1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_accumulated_amounts_stay_within_max_scale() {
+        let mut total = BigDecimal::from(0);
+        for i in 1..=10_000u64 {
+            let amount = BigDecimal::from(i) / BigDecimal::from(3);
+            total = clamp_precision(total + amount, MAX_VOLUME_SCALE);
+        }
+        assert!(scale(&total) <= MAX_VOLUME_SCALE);
+        assert_eq!(total.round(6), BigDecimal::from_str("16668333.333333").unwrap());
+
+        let exact = BigDecimal::from_str("1.5").unwrap();
+        assert_eq!(clamp_precision(exact.clone(), MAX_VOLUME_SCALE), exact);
+    }
+
+    #[test]
+    fn test_zero_checks() {
+        assert!(is_positive(&BigDecimal::from_str("0.000001").unwrap()));
+        assert!(!is_positive(&BigDecimal::from(0)));
+        assert!(!is_positive(&BigDecimal::from(-1)));
+        assert!(is_zero(&BigDecimal::from_str("0.000").unwrap()));
+        assert!(!is_zero(&BigDecimal::from(1)));
+    }
+}
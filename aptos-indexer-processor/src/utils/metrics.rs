@@ -0,0 +1,147 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus metrics. Everything is registered in the default registry, which the
+//! server framework serves on the health check port at `/metrics`.
+
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_counter_vec, register_gauge, register_int_counter, register_int_counter_vec, register_int_gauge,
+    register_int_gauge_vec, CounterVec, Gauge, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+};
+
+/// Swaps excluded from volume because a single-swap amount exceeded its threshold
+pub static ANOMALIES_DETECTED_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "anomalies_detected_total",
+        "Number of swaps flagged as anomalous and excluded from volume",
+        &["protocol"]
+    )
+    .unwrap()
+});
+
+/// Swap events skipped because their protocol processor was still awaiting after `event_processing_timeout_secs`
+pub static PROCESSING_TIMEOUTS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "processing_timeouts_total",
+        "Number of swap events skipped because processing them timed out",
+        &["protocol"]
+    )
+    .unwrap()
+});
+
+/// Swap events skipped because their protocol is disabled in `protocol_toggles`
+pub static DISABLED_PROTOCOL_EVENTS_SKIPPED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "disabled_protocol_events_skipped",
+        "Number of swap events skipped because their protocol is disabled",
+        &["protocol"]
+    )
+    .unwrap()
+});
+
+/// Swap events for token pairs that are not in the pool registry
+pub static UNKNOWN_PAIRS_SEEN_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "unknown_pairs_seen_total",
+        "Number of swap events seen for pairs missing from the pool registry",
+        &["protocol"]
+    )
+    .unwrap()
+});
+
+/// Swaps counted towards volume, by the pair they traded
+pub static SWAP_COUNT_BY_PAIR: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "swap_count_by_pair",
+        "Number of swaps counted towards volume per protocol and pair",
+        &["protocol", "pair"]
+    )
+    .unwrap()
+});
+
+/// USD value of swaps counted towards volume, by the pair they traded. Swaps with no
+/// stablecoin side are valued at the latest cached `coin_price_usd` of their base coin.
+pub static SWAP_VOLUME_BY_PAIR_USD: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "swap_volume_by_pair_usd",
+        "USD volume of swaps counted towards volume per protocol and pair",
+        &["protocol", "pair"]
+    )
+    .unwrap()
+});
+
+/// Rows deleted by the daily historical data purge
+pub static ROWS_PURGED_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "rows_purged_total",
+        "Number of historical rows deleted by the data retention purge",
+        &["table"]
+    )
+    .unwrap()
+});
+
+/// Swap events dropped because the Bloom filter had already seen them
+pub static DUPLICATE_EVENTS_SKIPPED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "duplicate_events_skipped",
+        "Number of swap events skipped as duplicates of an earlier batch"
+    )
+    .unwrap()
+});
+
+/// Share of swap event payloads answered from the VolumeCalculator decode cache
+pub static EVENT_CACHE_HIT_RATE: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "event_cache_hit_rate",
+        "Fraction of swap event lookups served from the event payload cache since startup"
+    )
+    .unwrap()
+});
+
+/// Rolling average block rate from block metadata transactions
+pub static BLOCKS_PER_SECOND: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "blocks_per_second",
+        "Average blocks per second over the most recent block metadata transactions"
+    )
+    .unwrap()
+});
+
+/// 1 while no state checkpoint has been seen for the stall timeout, 0 otherwise
+pub static CHAIN_STALLED: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "chain_stalled",
+        "Whether the indexer has gone too long without a state checkpoint transaction"
+    )
+    .unwrap()
+});
+
+/// Seconds since a protocol's last swap event once past the silence threshold, 0 otherwise
+pub static PROTOCOL_SILENT_DURATION_SECONDS: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "protocol_silent_duration_seconds",
+        "How long a protocol has gone without swap events, set once it exceeds the silence threshold",
+        &["protocol"]
+    )
+    .unwrap()
+});
+
+/// Decimal places configured per token, set once at startup so operators can check them
+pub static TOKEN_DECIMAL_CONFIG: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "token_decimal_config",
+        "Configured decimal places per token",
+        &["token"]
+    )
+    .unwrap()
+});
+
+/// Publish the Cellana token decimals through `token_decimal_config`
+pub fn record_token_decimals() {
+    use crate::processors::events::cellana::constants::{ABTC_DECIMALS, APT_DECIMALS, USDC_DECIMALS, USDT_DECIMALS};
+
+    for (token, decimals) in [("APT", APT_DECIMALS), ("USDC", USDC_DECIMALS), ("USDT", USDT_DECIMALS), ("BTC", ABTC_DECIMALS)] {
+        TOKEN_DECIMAL_CONFIG.with_label_values(&[token]).set(decimals as i64);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Startup check that the tables the indexer writes to exist, so a database without
+//! migrations fails before the first batch instead of with a diesel error mid-batch.
+
+use super::{database::ArcDbPool, errors::ProcessorResultExt};
+use anyhow::Result;
+use diesel::{
+    sql_types::{Integer, Text},
+    OptionalExtension, QueryableByName,
+};
+use diesel_async::RunQueryDsl;
+use std::fmt;
+use tracing::info;
+
+/// Tables that must exist before processing starts
+pub const REQUIRED_TABLES: [&str; 5] = ["apt_data", "coin_volume_24h", "coin_volume_buckets", "ledger_infos", "processor_status"];
+
+#[derive(Debug, PartialEq)]
+pub enum MigrationError {
+    MissingTables(Vec<String>),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::MissingTables(tables) => write!(
+                f,
+                "required tables are missing: {} (run the diesel migrations against this database)",
+                tables.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+#[derive(QueryableByName)]
+struct TableFound {
+    #[diesel(sql_type = Integer)]
+    #[allow(dead_code)]
+    found: i32,
+}
+
+/// Fail with `MigrationError::MissingTables` if any of `REQUIRED_TABLES` is missing
+pub async fn verify_required_tables(pool: &ArcDbPool) -> Result<()> {
+    let mut conn = pool.get().await.processor_err("Failed to get database connection for migration check")?;
+
+    let mut missing = Vec::new();
+    for table in REQUIRED_TABLES {
+        let found = diesel::sql_query("SELECT 1 AS found FROM information_schema.tables WHERE table_name = $1")
+            .bind::<Text, _>(table)
+            .get_result::<TableFound>(&mut conn)
+            .await
+            .optional()
+            .processor_err(&format!("Failed to check for table {}", table))?;
+        if found.is_none() {
+            missing.push(table.to_string());
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(MigrationError::MissingTables(missing).into());
+    }
+
+    info!("✅ All {} required tables exist", REQUIRED_TABLES.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_tables_are_listed() {
+        let error = MigrationError::MissingTables(vec!["apt_data".to_string(), "processor_status".to_string()]);
+        assert!(error.to_string().contains("apt_data, processor_status"));
+    }
+}
@@ -17,11 +17,46 @@
 //! ### Chain Validation (`chain_id`)
 //! - Validates the indexer is connected to the correct Aptos network
 //! - Prevents accidental indexing of wrong chain data
+//! - Startup fails unless `ledger_infos` holds the configured `expected_chain_id`
 //! 
 //! ### Version Management (`starting_version`)
 //! - Determines the starting block version for indexing
 //! - Handles resume from last processed version
 //! - Supports both fresh starts and continuation from checkpoints
+//! 
+//! ### Protocol Config (`protocol_config_loader`)
+//! - Loads per-pool fee overrides from the `protocol_config` table
+//! 
+//! ### Logging (`logging`)
+//! - Pretty or JSON log output selected via `LOG_FORMAT`
+//! 
+//! ### Pool Registry (`pool_registry`)
+//! - Canonical pair names for pools across all five protocols
+//! 
+//! ### Event Deduplication (`bloom_dedup`)
+//! - Bloom filter of already-counted swap events, persisted across restarts
+//! 
+//! ### Volume Window (`volume_window`)
+//! - Persisted 24h window that rolling volumes accumulate over and reset at
+//! 
+//! ### Transaction Filter (`filter`)
+//! - Skips transactions sent by the Aptos framework addresses
+//! 
+//! ### Contract Registry (`contract_registry`)
+//! - Learns DEX swap event types from genesis and trusted deployers instead of hardcoded addresses
+//! 
+//! ### Migration Check (`migration_checker`)
+//! - Verifies the required tables exist before processing starts
+//! 
+//! ### Math (`math`)
+//! - Caps the scale of accumulated `BigDecimal` volumes at the database's `NUMERIC(38, 18)`
+//! 
+//! ### Metrics (`metrics`)
+//! - Prometheus counters served by the framework's `/metrics` endpoint
+//! 
+//! ### Error Handling (`errors`)
+//! - Converts diesel and connection pool errors into `ProcessorError`
+//! - Lets processor methods propagate database failures with `?`
 
 /// Database connection management, pooling, and utility functions
 pub mod database;
@@ -31,3 +66,36 @@ pub mod chain_id;
 
 /// Transaction version management and starting point determination
 pub mod starting_version;
+
+/// Conversions from database-layer errors into `ProcessorError`
+pub mod errors;
+
+/// Tracing subscriber setup with pretty or structured JSON output
+pub mod logging;
+
+/// Runtime fee-rate overrides stored in the `protocol_config` table
+pub mod protocol_config_loader;
+
+/// Prometheus metrics registered in the default registry
+pub mod metrics;
+
+/// Canonical pair names for tracked pools across protocols
+pub mod pool_registry;
+
+/// Bloom filter used to skip swap events counted in an earlier batch
+pub mod bloom_dedup;
+
+/// Persisted 24h accumulation window for rolling volumes
+pub mod volume_window;
+
+/// Startup check for tables created by the migrations
+pub mod migration_checker;
+
+/// Sender filter for Aptos framework transactions
+pub mod filter;
+
+/// Swap event types of DEX modules seen being deployed
+pub mod contract_registry;
+
+/// Precision limits for accumulated decimal amounts
+pub mod math;
@@ -0,0 +1,159 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Canonical pair names for every tracked pool, shared by all protocol processors.
+//!
+//! Pools are keyed by their on-chain address when a protocol exposes one, otherwise by
+//! the protocol name and the (unordered) pair of coin types.
+
+use crate::processors::events::{cellana, hyperion, liquidswap, sushiswap, thala};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+static POOL_REGISTRY: Lazy<PoolRegistry> = Lazy::new(PoolRegistry::with_known_pools);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolInfo {
+    pub protocol: String,
+    pub canonical_pair: String,
+    pub token_x: String,
+    pub token_y: String,
+}
+
+/// PoolRegistry maps pool addresses or coin-type pairs to their canonical pair name
+#[derive(Clone, Debug, Default)]
+pub struct PoolRegistry {
+    entries: HashMap<String, PoolInfo>,
+}
+
+impl PoolRegistry {
+    /// Registry populated with the pools tracked by this indexer
+    pub fn global() -> &'static PoolRegistry {
+        &POOL_REGISTRY
+    }
+
+    /// Register a pool identified by its on-chain address
+    pub fn register_pool(&mut self, pool_address: &str, info: PoolInfo) {
+        self.entries.insert(pool_address.to_string(), info);
+    }
+
+    /// Register a pool identified by its protocol and coin types (in either order)
+    pub fn register_pair(&mut self, protocol: &str, token_x: &str, token_y: &str, canonical_pair: &str) {
+        self.entries.insert(
+            Self::token_pair_key(protocol, token_x, token_y),
+            PoolInfo {
+                protocol: protocol.to_string(),
+                canonical_pair: canonical_pair.to_string(),
+                token_x: token_x.to_string(),
+                token_y: token_y.to_string(),
+            },
+        );
+    }
+
+    /// Move the entry of a pool that was migrated to a new address; false if it was not registered
+    pub fn migrate_pool(&mut self, old_pool_address: &str, new_pool_address: &str) -> bool {
+        match self.entries.remove(old_pool_address) {
+            Some(info) => {
+                self.entries.insert(new_pool_address.to_string(), info);
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn get_by_address(&self, pool_address: &str) -> Option<&PoolInfo> {
+        self.entries.get(pool_address)
+    }
+
+    pub fn get_by_tokens(&self, protocol: &str, token_x: &str, token_y: &str) -> Option<&PoolInfo> {
+        self.entries.get(&Self::token_pair_key(protocol, token_x, token_y))
+    }
+
+    /// Canonical pair name (e.g. "APT/USDC") for a registered pool address
+    pub fn get_pair_name_by_address(&self, pool_address: &str) -> Option<&str> {
+        self.get_by_address(pool_address).map(|info| info.canonical_pair.as_str())
+    }
+
+    /// Canonical pair name (e.g. "APT/USDC") for a protocol's coin pair, in either order
+    pub fn get_pair_name(&self, protocol: &str, token_x: &str, token_y: &str) -> Option<&str> {
+        self.get_by_tokens(protocol, token_x, token_y).map(|info| info.canonical_pair.as_str())
+    }
+
+    fn token_pair_key(protocol: &str, token_x: &str, token_y: &str) -> String {
+        let (first, second) = if token_x <= token_y { (token_x, token_y) } else { (token_y, token_x) };
+        format!("{}:{}|{}", protocol, first, second)
+    }
+
+    /// All pools tracked by the indexer. Bridged variants (iz*/wh*) share the pair name of
+    /// the coin they are stored as in the database.
+    fn with_known_pools() -> Self {
+        let mut registry = Self::default();
+
+        {
+            use cellana::constants::*;
+            registry.register_pair("cellana", APT_COIN_TYPE, USDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("cellana", APT_COIN_TYPE, USDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("cellana", USDT_COIN_TYPE, USDC_COIN_TYPE, "USDT/USDC");
+        }
+
+        {
+            use thala::constants::*;
+            registry.register_pair("thala", APT_COIN_TYPE, USDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("thala", APT_COIN_TYPE, USDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("thala", USDT_COIN_TYPE, USDC_COIN_TYPE, "USDT/USDC");
+        }
+
+        {
+            use sushiswap::constants::*;
+            registry.register_pair("sushiswap", APT_COIN_TYPE, IZUSDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("sushiswap", APT_COIN_TYPE, IZUSDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("sushiswap", APT_COIN_TYPE, WHUSDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("sushiswap", APT_COIN_TYPE, IZWETH_COIN_TYPE, "APT/WETH");
+            registry.register_pair("sushiswap", IZWETH_COIN_TYPE, IZUSDC_COIN_TYPE, "WETH/USDC");
+            registry.register_pair("sushiswap", WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE, "whUSDC/izUSDC");
+        }
+
+        {
+            use liquidswap::constants::*;
+            registry.register_pair("liquidswap", APT_COIN_TYPE, IZUSDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, IZUSDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, WHUSDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, IZWETH_COIN_TYPE, "APT/WETH");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, WHWETH_COIN_TYPE, "APT/WETH");
+            registry.register_pair("liquidswap", WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE, "USDC/USDC");
+            registry.register_pair("liquidswap", IZUSDT_COIN_TYPE, WHUSDT_COIN_TYPE, "USDT/USDT");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, IZBTC_COIN_TYPE, "APT/BTC");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, WHBTC_COIN_TYPE, "APT/BTC");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, BNB_COIN_TYPE, "APT/BNB");
+            registry.register_pair("liquidswap", APT_COIN_TYPE, MOVE_COIN_TYPE, "APT/MOVE");
+        }
+
+        {
+            use hyperion::constants::*;
+            registry.register_pair("hyperion", APT_COIN_TYPE, USDC_COIN_TYPE, "APT/USDC");
+            registry.register_pair("hyperion", APT_COIN_TYPE, USDT_COIN_TYPE, "APT/USDT");
+            registry.register_pair("hyperion", USDT_COIN_TYPE, USDC_COIN_TYPE, "USDT/USDC");
+        }
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_pair_name_either_token_order() {
+        use sushiswap::constants::{APT_COIN_TYPE, IZUSDC_COIN_TYPE, WHUSDC_COIN_TYPE};
+        let registry = PoolRegistry::global();
+
+        assert_eq!(registry.get_pair_name("sushiswap", APT_COIN_TYPE, IZUSDC_COIN_TYPE), Some("APT/USDC"));
+        assert_eq!(registry.get_pair_name("sushiswap", IZUSDC_COIN_TYPE, APT_COIN_TYPE), Some("APT/USDC"));
+        assert_eq!(registry.get_pair_name("sushiswap", WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE), Some("whUSDC/izUSDC"));
+
+        // Pairs are scoped to their protocol
+        assert_eq!(registry.get_pair_name("liquidswap", WHUSDC_COIN_TYPE, IZUSDC_COIN_TYPE), Some("USDC/USDC"));
+        assert_eq!(registry.get_pair_name("cellana", APT_COIN_TYPE, IZUSDC_COIN_TYPE), None);
+    }
+}
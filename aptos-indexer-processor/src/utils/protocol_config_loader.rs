@@ -0,0 +1,89 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reads and writes runtime fee-rate overrides stored in the `protocol_config` table.
+
+use super::database::ArcDbPool;
+use crate::db::{
+    common::models::protocol_config_models::NewProtocolConfigEntry,
+    postgres::schema::protocol_config,
+};
+use anyhow::{Context, Result};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+/// Fee overrides (pool address -> fee bps) loaded from `protocol_config`
+#[derive(Clone, Debug, Default)]
+pub struct ProtocolFeeConfig {
+    fees: HashMap<String, u32>,
+}
+
+impl ProtocolFeeConfig {
+    pub fn new(fees: HashMap<String, u32>) -> Self {
+        Self { fees }
+    }
+
+    pub fn fee_bps(&self, pool_address: &str) -> Option<u32> {
+        self.fees.get(pool_address).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.fees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fees.is_empty()
+    }
+}
+
+pub struct ProtocolConfigLoader;
+
+impl ProtocolConfigLoader {
+    /// Returns the most recent fee (in bps) for each pool address, i.e. the row with
+    /// the highest `effective_from_version` per pool.
+    pub async fn load(pool: &ArcDbPool) -> Result<ProtocolFeeConfig> {
+        let mut conn = pool.get().await.context("Failed to get database connection")?;
+
+        let rows: Vec<(String, i32)> = protocol_config::table
+            .select((protocol_config::pool_address, protocol_config::fee_bps))
+            .distinct_on(protocol_config::pool_address)
+            .order_by((
+                protocol_config::pool_address.asc(),
+                protocol_config::effective_from_version.desc(),
+            ))
+            .load(&mut conn)
+            .await
+            .context("Failed to load protocol_config")?;
+
+        let mut fees = HashMap::with_capacity(rows.len());
+        for (pool_address, fee_bps) in rows {
+            match u32::try_from(fee_bps) {
+                Ok(fee_bps) => {
+                    fees.insert(pool_address, fee_bps);
+                },
+                Err(_) => warn!("⚠️ Ignoring negative fee_bps {} for pool {}", fee_bps, pool_address),
+            }
+        }
+
+        Ok(ProtocolFeeConfig::new(fees))
+    }
+
+    /// Inserts a new fee rate for a pool, effective from the given transaction version
+    pub async fn insert(pool: &ArcDbPool, entry: NewProtocolConfigEntry) -> Result<()> {
+        let mut conn = pool.get().await.context("Failed to get database connection")?;
+
+        diesel::insert_into(protocol_config::table)
+            .values(&entry)
+            .execute(&mut conn)
+            .await
+            .context("Failed to insert protocol_config row")?;
+
+        info!(
+            "✅ Set {} pool {} fee to {} bps from version {}",
+            entry.protocol_name, entry.pool_address, entry.fee_bps, entry.effective_from_version
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The 24h window that `apt_data` and `coin_volume_24h` accumulate over.
+//!
+//! The window start is stored in `volume_window_state` so that a restart mid-window keeps the
+//! same boundaries. Only events inside `[window_start, window_start + 24h)` are counted, and
+//! volumes are reset exactly when the window ends rather than 24h after the last update.
+
+use super::{database::ArcDbPool, errors::ProcessorResultExt};
+use crate::db::postgres::schema::volume_window_state;
+use aptos_indexer_processor_sdk::utils::errors::ProcessorError;
+use chrono::{DateTime, Duration, NaiveDateTime};
+use diesel::{upsert::excluded, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use tracing::info;
+
+const WINDOW_STATE_ID: i32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolumeWindowManager {
+    window_start: NaiveDateTime,
+}
+
+impl VolumeWindowManager {
+    pub fn new(window_start: NaiveDateTime) -> Self {
+        Self { window_start }
+    }
+
+    /// Read the stored window, or start a new one at `now` if there is none yet.
+    /// Starting at `now` means events from before the first startup are not counted, so the
+    /// first window is a full 24h instead of a partial one followed by an extra day.
+    pub async fn load_or_start(pool: &ArcDbPool, now: NaiveDateTime) -> Result<Self, ProcessorError> {
+        if let Some(window) = Self::load(pool).await? {
+            info!("🪟 Resuming volume window started at {} UTC", window.window_start.format("%Y-%m-%d %H:%M:%S"));
+            return Ok(window);
+        }
+
+        let window = Self::new(now);
+        window.save(pool).await?;
+        info!("🪟 Started new volume window at {} UTC", now.format("%Y-%m-%d %H:%M:%S"));
+        Ok(window)
+    }
+
+    /// Read the stored window, if one has been started
+    pub async fn load(pool: &ArcDbPool) -> Result<Option<Self>, ProcessorError> {
+        let mut conn = pool.get().await.processor_err("Failed to get database connection for volume window")?;
+
+        let stored: Option<NaiveDateTime> = volume_window_state::table
+            .select(volume_window_state::window_start)
+            .filter(volume_window_state::id.eq(WINDOW_STATE_ID))
+            .first(&mut conn)
+            .await
+            .optional()
+            .processor_err("Failed to load volume window state")?;
+
+        Ok(stored.map(Self::new))
+    }
+
+    pub async fn save(&self, pool: &ArcDbPool) -> Result<(), ProcessorError> {
+        let mut conn = pool.get().await.processor_err("Failed to get database connection for volume window")?;
+
+        diesel::insert_into(volume_window_state::table)
+            .values((
+                volume_window_state::id.eq(WINDOW_STATE_ID),
+                volume_window_state::window_start.eq(self.window_start),
+            ))
+            .on_conflict(volume_window_state::id)
+            .do_update()
+            .set((
+                volume_window_state::window_start.eq(excluded(volume_window_state::window_start)),
+                volume_window_state::updated_at.eq(diesel::dsl::now),
+            ))
+            .execute(&mut conn)
+            .await
+            .processor_err("Failed to save volume window state")?;
+
+        Ok(())
+    }
+
+    pub fn window_start(&self) -> NaiveDateTime {
+        self.window_start
+    }
+
+    pub fn window_end(&self) -> NaiveDateTime {
+        self.window_start + Duration::hours(24)
+    }
+
+    /// Whether a transaction timestamp (in seconds) falls inside the current window
+    pub fn contains(&self, timestamp_seconds: i64) -> bool {
+        match DateTime::from_timestamp(timestamp_seconds, 0) {
+            Some(time) => {
+                let time = time.naive_utc();
+                time >= self.window_start && time < self.window_end()
+            },
+            None => false,
+        }
+    }
+
+    /// Move to the window containing `now` if the current one has ended.
+    /// Windows stay aligned to the original start. Returns true if the window moved.
+    pub fn roll_forward(&mut self, now: NaiveDateTime) -> bool {
+        if now < self.window_end() {
+            return false;
+        }
+
+        let elapsed_windows = (now - self.window_start).num_hours() / 24;
+        self.window_start += Duration::hours(24 * elapsed_windows);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 6, day).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_window_counts_exactly_24h_from_start() {
+        // Indexer first started at 14:00; a backfilled 13:00 event is outside the window
+        let mut window = VolumeWindowManager::new(at(10, 14));
+        assert!(!window.contains(at(10, 13).and_utc().timestamp()));
+        assert!(window.contains(at(10, 14).and_utc().timestamp()));
+        assert!(window.contains(at(11, 13).and_utc().timestamp()));
+        assert!(!window.contains(at(11, 14).and_utc().timestamp()));
+
+        assert!(!window.roll_forward(at(11, 13)));
+        assert!(window.roll_forward(at(11, 14)));
+        assert_eq!(window.window_start(), at(11, 14));
+
+        // After a long outage the window skips ahead but keeps the 14:00 alignment
+        assert!(window.roll_forward(at(14, 9)));
+        assert_eq!(window.window_start(), at(13, 14));
+        assert_eq!(window.window_end(), at(14, 14));
+    }
+}
@@ -0,0 +1,101 @@
+//! Swap transactions for the VolumeCalculator integration tests. Each protocol's fixture
+//! sells 10 APT for 50 of that protocol's USD stablecoin.
+
+pub mod transaction_builder;
+
+use aptos_indexer_processor::processors::events::{
+    cellana::constants as cellana, hyperion::constants as hyperion, liquidswap::constants as liquidswap,
+    sushiswap::constants as sushiswap, thala::constants as thala,
+};
+use aptos_indexer_processor_sdk::aptos_protos::transaction::v1::Transaction;
+use serde_json::json;
+pub use transaction_builder::TransactionBuilder;
+
+pub const TEN_APT: &str = "1000000000";
+pub const FIFTY_USD: &str = "50000000";
+
+pub fn cellana_swap_event() -> (String, String) {
+    let data = json!({
+        "amount_in": TEN_APT,
+        "amount_out": FIFTY_USD,
+        "from_token": cellana::APT_COIN_TYPE,
+        "to_token": cellana::USDC_COIN_TYPE,
+        "pool": "0xce11a",
+    });
+    (cellana::CELLANA_SWAP_EVENT_TYPE.to_string(), data.to_string())
+}
+
+pub fn thala_swap_event() -> (String, String) {
+    let data = json!({
+        "idx_in": "0",
+        "idx_out": "1",
+        "amount_in": TEN_APT,
+        "amount_out": FIFTY_USD,
+        "protocol_fee_amount": "0",
+        "pool_obj": { "inner": "0x7a1a" },
+        "metadata": [{ "inner": thala::APT_COIN_TYPE }, { "inner": thala::USDC_COIN_TYPE }],
+    });
+    (thala::THALA_SWAP_EVENT_TYPE.to_string(), data.to_string())
+}
+
+pub fn sushiswap_swap_event() -> (String, String) {
+    let type_str = format!(
+        "{}{}<{}, {}>",
+        sushiswap::SUSHISWAP_V1_CONTRACT_ADDRESS,
+        sushiswap::SUSHISWAP_SWAP_EVENT_SUFFIX,
+        sushiswap::APT_COIN_TYPE,
+        sushiswap::IZUSDT_COIN_TYPE
+    );
+    let data = json!({
+        "amount_x_in": TEN_APT,
+        "amount_x_out": "0",
+        "amount_y_in": "0",
+        "amount_y_out": FIFTY_USD,
+        "user": "0xcafe",
+    });
+    (type_str, data.to_string())
+}
+
+pub fn liquidswap_swap_event() -> (String, String) {
+    let type_str = format!(
+        "{}<{}, {}, 0x190d44266241744264b964a37b8f09863167a12d3e70cda39376cfb4e3561e12::curves::Uncorrelated>",
+        liquidswap::LIQUIDSWAP_SWAP_EVENT_TYPE,
+        liquidswap::APT_COIN_TYPE,
+        liquidswap::IZUSDC_COIN_TYPE
+    );
+    let data = json!({
+        "x_in": TEN_APT,
+        "x_out": "0",
+        "y_in": "0",
+        "y_out": FIFTY_USD,
+    });
+    (type_str, data.to_string())
+}
+
+pub fn hyperion_swap_event() -> (String, String) {
+    let data = json!({
+        "amount_in": TEN_APT,
+        "amount_out": FIFTY_USD,
+        "from_token": { "inner": hyperion::APT_COIN_TYPE },
+        "to_token": { "inner": hyperion::USDC_COIN_TYPE },
+        "pool_id": "0x4e1e",
+        "protocol_fee_amount": "0",
+    });
+    (hyperion::HYPERION_SWAP_EVENT_TYPE.to_string(), data.to_string())
+}
+
+/// One transaction holding a single swap event
+pub fn swap_transaction(version: u64, (type_str, data): (String, String)) -> Transaction {
+    TransactionBuilder::new().version(version).add_user_event(&type_str, &data).build()
+}
+
+/// One transaction routing a swap through Cellana and then Hyperion
+pub fn multi_event_transaction(version: u64) -> Transaction {
+    let (cellana_type, cellana_data) = cellana_swap_event();
+    let (hyperion_type, hyperion_data) = hyperion_swap_event();
+    TransactionBuilder::new()
+        .version(version)
+        .add_user_event(&cellana_type, &cellana_data)
+        .add_user_event(&hyperion_type, &hyperion_data)
+        .build()
+}
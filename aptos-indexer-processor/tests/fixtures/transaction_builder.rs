@@ -0,0 +1,95 @@
+use aptos_indexer_processor_sdk::aptos_protos::{
+    transaction::v1::{
+        transaction::{TransactionType, TxnData},
+        write_set_change::Change,
+        Event, Transaction, TransactionInfo, UserTransaction, UserTransactionRequest, WriteResource, WriteSetChange,
+    },
+    util::timestamp::Timestamp,
+};
+use chrono::Utc;
+
+/// Builds user `Transaction` protos with the nesting the indexer reads:
+/// `txn_data.User.events` for swap events and `info.changes` for pool resources.
+pub struct TransactionBuilder {
+    version: u64,
+    timestamp_seconds: i64,
+    sender: String,
+    events: Vec<Event>,
+    changes: Vec<WriteSetChange>,
+}
+
+impl TransactionBuilder {
+    /// A user transaction at version 1, timestamped now so it is inside the 24h window
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            timestamp_seconds: Utc::now().timestamp(),
+            sender: "0xcafe".to_string(),
+            events: Vec::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Block timestamp in seconds
+    pub fn timestamp(mut self, seconds: i64) -> Self {
+        self.timestamp_seconds = seconds;
+        self
+    }
+
+    pub fn sender(mut self, sender: &str) -> Self {
+        self.sender = sender.to_string();
+        self
+    }
+
+    /// Append an event with the given Move type and JSON data
+    pub fn add_user_event(mut self, type_str: &str, data: &str) -> Self {
+        self.events.push(Event {
+            sequence_number: self.events.len() as u64,
+            type_str: type_str.to_string(),
+            data: data.to_string(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Append a resource write, e.g. the pool resource a swap updates
+    pub fn add_write_resource(mut self, address: &str, type_str: &str, data: &str) -> Self {
+        self.changes.push(WriteSetChange {
+            change: Some(Change::WriteResource(WriteResource {
+                address: address.to_string(),
+                type_str: type_str.to_string(),
+                data: data.to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn build(self) -> Transaction {
+        Transaction {
+            version: self.version,
+            timestamp: Some(Timestamp { seconds: self.timestamp_seconds, nanos: 0 }),
+            r#type: TransactionType::User as i32,
+            info: Some(TransactionInfo {
+                success: true,
+                changes: self.changes,
+                ..Default::default()
+            }),
+            txn_data: Some(TxnData::User(UserTransaction {
+                request: Some(UserTransactionRequest {
+                    sender: self.sender,
+                    ..Default::default()
+                }),
+                events: self.events,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! VolumeCalculator run end to end over swap transactions built from `fixtures`.
+
+mod fixtures;
+
+use aptos_indexer_processor::{
+    db::common::models::apt_models::NewAptData,
+    processors::events::volume_calculator::{VolumeCalculator, VolumeData},
+};
+use aptos_indexer_processor_sdk::{
+    aptos_protos::transaction::v1::Transaction,
+    traits::Processable,
+    types::transaction_context::{TransactionContext, TransactionMetadata},
+};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use fixtures::*;
+use std::str::FromStr;
+
+async fn process(transactions: Vec<Transaction>) -> VolumeData {
+    VolumeCalculator::new()
+        .process(TransactionContext {
+            data: transactions,
+            metadata: TransactionMetadata::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+}
+
+fn record<'a>(volume_data: &'a VolumeData, protocol: &str) -> &'a NewAptData {
+    volume_data
+        .apt_data
+        .iter()
+        .find(|record| record.protocol_name == protocol)
+        .unwrap_or_else(|| panic!("no apt_data record for {}", protocol))
+}
+
+#[tokio::test]
+async fn test_one_swap_per_protocol() {
+    let volume_data = process(vec![
+        swap_transaction(1, cellana_swap_event()),
+        swap_transaction(2, thala_swap_event()),
+        swap_transaction(3, sushiswap_swap_event()),
+        swap_transaction(4, liquidswap_swap_event()),
+        swap_transaction(5, hyperion_swap_event()),
+    ])
+    .await;
+
+    assert_eq!(volume_data.apt_data.len(), 5);
+    for protocol in ["cellana", "thala", "sushiswap", "liquidswap", "hyperion"] {
+        let record = record(&volume_data, protocol);
+        assert_eq!(record.trade_count, 1, "{}", protocol);
+        assert!(record.apt_volume_24h.as_ref().is_some_and(|volume| *volume > BigDecimal::from(0)), "{}", protocol);
+    }
+}
+
+#[tokio::test]
+async fn test_multi_event_transaction_counts_each_protocol() {
+    let volume_data = process(vec![multi_event_transaction(1)]).await;
+
+    assert_eq!(volume_data.apt_data.len(), 2);
+    assert_eq!(record(&volume_data, "cellana").trade_count, 1);
+    assert_eq!(record(&volume_data, "hyperion").trade_count, 1);
+}
+
+#[tokio::test]
+async fn test_stale_and_framework_transactions_are_skipped() {
+    let (type_str, data) = cellana_swap_event();
+    let two_days_ago = Utc::now().timestamp() - 2 * 24 * 60 * 60;
+    let volume_data = process(vec![
+        TransactionBuilder::new().version(1).timestamp(two_days_ago).add_user_event(&type_str, &data).build(),
+        TransactionBuilder::new().version(2).sender("0x1").add_user_event(&type_str, &data).build(),
+    ])
+    .await;
+
+    assert!(volume_data.apt_data.is_empty());
+}
+
+#[tokio::test]
+async fn test_24h_cutoff_filtering() {
+    let (type_str, data) = cellana_swap_event();
+    let volume_data = process(vec![
+        TransactionBuilder::new()
+            .version(1)
+            .timestamp((Utc::now() - Duration::hours(23)).timestamp())
+            .add_user_event(&type_str, &data)
+            .build(),
+        TransactionBuilder::new()
+            .version(2)
+            .timestamp((Utc::now() - Duration::hours(25)).timestamp())
+            .add_user_event(&type_str, &data)
+            .build(),
+    ])
+    .await;
+
+    // Only the 23-hour-old swap of 10 APT is inside the window
+    let cellana = record(&volume_data, "cellana");
+    assert_eq!(cellana.trade_count, 1);
+    assert_eq!(cellana.apt_volume_24h, Some(BigDecimal::from(10)));
+}
+
+#[tokio::test]
+async fn test_thala_fees_come_from_the_pool_resource() {
+    let (type_str, data) = thala_swap_event();
+    let txn = TransactionBuilder::new()
+        .add_user_event(&type_str, &data)
+        .add_write_resource("0x7a1a", "0x7730::pool::WeightedPool", r#"{"lp_fee_bps":"25","protocol_fee_bps":"5"}"#)
+        .build();
+    let volume_data = process(vec![txn]).await;
+
+    // 0.025 APT LP fee and 0.005 APT protocol fee at the pool's 5 USDC/APT
+    let thala = record(&volume_data, "thala");
+    assert_eq!(thala.lp_fee_24h, Some(BigDecimal::from_str("0.125").unwrap()));
+    assert_eq!(thala.protocol_fee_24h, Some(BigDecimal::from_str("0.025").unwrap()));
+}